@@ -1,7 +1,10 @@
 #![allow(dead_code)]
+use crate::LinterContext;
 use crate::config::config_loader::ConfigLoader;
 use crate::error::InitError;
+use crate::report::enums::ViolationSeverity;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -9,6 +12,21 @@ pub struct LinterConfig {
     #[serde(rename = "rules")]
     pub rule_ids: Vec<String>,
     pub hpo_dir: Option<PathBuf>,
+    pub geno_dir: Option<PathBuf>,
+    /// Overrides the severity of a rule, e.g. `INTER001 = "error"`.
+    #[serde(default)]
+    pub severities: HashMap<String, ViolationSeverity>,
+    /// Stops linting once this many findings were collected.
+    pub max_findings: Option<usize>,
+}
+
+impl LinterConfig {
+    pub fn to_context(&self) -> LinterContext {
+        LinterContext::new(self.hpo_dir.clone())
+            .with_geno_path(self.geno_dir.clone())
+            .with_severity_overrides(self.severities.clone())
+            .with_max_findings(self.max_findings)
+    }
 }
 
 impl TryFrom<PathBuf> for LinterConfig {
@@ -18,3 +36,37 @@ impl TryFrom<PathBuf> for LinterConfig {
         Ok(ConfigLoader::load(value)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assets_dir;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_load_severities() {
+        let config =
+            LinterConfig::try_from(assets_dir().join("phenolint_severities.toml")).unwrap();
+
+        assert_eq!(
+            config.severities.get("INTER001"),
+            Some(&ViolationSeverity::Error)
+        );
+        assert_eq!(config.to_context().severity_overrides(), &config.severities);
+    }
+
+    #[rstest]
+    fn test_severities_default_to_empty() {
+        let config = LinterConfig::try_from(assets_dir().join("phenolint.toml")).unwrap();
+
+        assert!(config.severities.is_empty());
+    }
+
+    #[rstest]
+    fn test_max_findings_default_to_none() {
+        let config = LinterConfig::try_from(assets_dir().join("phenolint.toml")).unwrap();
+
+        assert_eq!(config.max_findings, None);
+        assert_eq!(config.to_context().max_findings(), None);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::config::config_loader::ConfigLoader;
+use crate::diagnostics::{LintReport, LintViolation};
+use crate::error::InitError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+/// Identifies a finding by its rule, all pointers of its violation and its kind, see `LintViolation::kind`.
+///
+/// Messages and spans are not part of the fingerprint, so it survives reformatting the phenopacket.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Fingerprint {
+    pub rule_id: String,
+    pub pointers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+}
+
+impl Fingerprint {
+    pub fn of(violation: &LintViolation) -> Self {
+        Fingerprint {
+            rule_id: violation.rule_id().to_string(),
+            pointers: violation
+                .at()
+                .iter()
+                .map(|ptr| ptr.position().to_string())
+                .collect(),
+            kind: violation.kind().map(str::to_string),
+        }
+    }
+}
+
+/// Known findings, which are not reported again when a phenopacket is re-linted.
+///
+/// Generate a baseline from the report of a legacy dataset with `Baseline::from_report`, store it
+/// with serde and load it again via `Baseline::try_from`. Only findings that are not part of the
+/// baseline show up in `LintReport::findings`, the others are moved to `LintReport::baselined`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    fingerprints: BTreeSet<Fingerprint>,
+}
+
+impl Baseline {
+    /// Creates a baseline of all findings in `report`, including the ones it already baselined.
+    pub fn from_report(report: &LintReport) -> Self {
+        Baseline {
+            fingerprints: report
+                .findings()
+                .iter()
+                .chain(report.baselined())
+                .map(|finding| Fingerprint::of(finding.violation()))
+                .collect(),
+        }
+    }
+
+    pub fn contains(&self, violation: &LintViolation) -> bool {
+        self.fingerprints.contains(&Fingerprint::of(violation))
+    }
+
+    pub fn fingerprints(&self) -> impl Iterator<Item = &Fingerprint> {
+        self.fingerprints.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fingerprints.is_empty()
+    }
+}
+
+impl TryFrom<PathBuf> for Baseline {
+    type Error = InitError;
+
+    fn try_from(value: PathBuf) -> Result<Self, Self::Error> {
+        Ok(ConfigLoader::load(value)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::LintFinding;
+    use crate::helper::non_empty_vec::NonEmptyVec;
+    use crate::report::enums::ViolationSeverity;
+    use crate::tree::pointer::Pointer;
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    fn violation(rule_id: &str, ptr: &str) -> LintViolation {
+        LintViolation::new(
+            ViolationSeverity::Error,
+            rule_id,
+            NonEmptyVec::with_single_entry(Pointer::new(ptr)),
+        )
+    }
+
+    #[rstest]
+    fn test_from_report() {
+        let mut report = LintReport::default();
+        report.push_finding(LintFinding::new(
+            violation("CURIE001", "/phenotypicFeatures/0/type"),
+            vec![],
+            None,
+        ));
+
+        let baseline = Baseline::from_report(&report);
+
+        assert!(baseline.contains(&violation("CURIE001", "/phenotypicFeatures/0/type")));
+        assert!(!baseline.contains(&violation("CURIE001", "/phenotypicFeatures/1/type")));
+        assert!(!baseline.contains(&violation("PF001", "/phenotypicFeatures/0/type")));
+    }
+
+    #[rstest]
+    fn test_shared_first_pointer() {
+        let at = |ptrs: &[&str]| {
+            NonEmptyVec::with_rest(
+                Pointer::new(ptrs[0]),
+                ptrs[1..].iter().map(|ptr| Pointer::new(ptr)).collect(),
+            )
+        };
+        let empty_value = LintViolation::new(
+            ViolationSeverity::Info,
+            "FILE001",
+            at(&[
+                "/files/0/fileAttributes",
+                "/files/0/fileAttributes/genomeAssembly",
+            ]),
+        );
+        let conflicting_keys = LintViolation::new(
+            ViolationSeverity::Warning,
+            "FILE001",
+            at(&[
+                "/files/0/fileAttributes",
+                "/files/0/fileAttributes/genome_assembly",
+                "/files/0/fileAttributes/genomeAssembly",
+            ]),
+        );
+        let exact = violation("PF008", "/phenotypicFeatures/1").with_kind("exact");
+        let descendant = violation("PF008", "/phenotypicFeatures/1").with_kind("descendant");
+
+        let mut report = LintReport::default();
+        report.push_finding(LintFinding::new(empty_value.clone(), vec![], None));
+        report.push_finding(LintFinding::new(exact.clone(), vec![], None));
+        let baseline = Baseline::from_report(&report);
+
+        assert!(baseline.contains(&empty_value));
+        assert!(!baseline.contains(&conflicting_keys));
+        assert!(baseline.contains(&exact));
+        assert!(!baseline.contains(&descendant));
+    }
+
+    #[rstest]
+    fn test_round_trip() {
+        let mut report = LintReport::default();
+        report.push_finding(LintFinding::new(violation("INTER001", "/id"), vec![], None));
+        let baseline = Baseline::from_report(&report);
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        std::fs::write(&path, serde_json::to_string(&baseline).unwrap()).unwrap();
+
+        assert_eq!(Baseline::try_from(path).unwrap(), baseline);
+    }
+}
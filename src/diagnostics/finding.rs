@@ -1,15 +1,22 @@
 use crate::diagnostics::violation::LintViolation;
 use crate::patches::patch::Patch;
+use crate::report::enums::ViolationSeverity;
+use crate::report::specs::ReportSpecs;
 
 #[derive(Debug)]
 pub struct LintFinding {
     violation: LintViolation,
     patches: Vec<Patch>,
+    report: Option<ReportSpecs>,
 }
 
 impl LintFinding {
-    pub fn new(violation: LintViolation, patches: Vec<Patch>) -> Self {
-        Self { violation, patches }
+    pub fn new(violation: LintViolation, patches: Vec<Patch>, report: Option<ReportSpecs>) -> Self {
+        Self {
+            violation,
+            patches,
+            report,
+        }
     }
 
     pub fn violation(&self) -> &LintViolation {
@@ -19,4 +26,23 @@ impl LintFinding {
     pub fn patch(&self) -> &[Patch] {
         self.patches.as_ref()
     }
+
+    pub(crate) fn set_patches(&mut self, patches: Vec<Patch>) {
+        self.patches = patches;
+    }
+
+    /// Overrides the severity of the violation and its report.
+    ///
+    /// Patches and reports are compiled with the severity of the rule, so this has to happen afterwards.
+    pub(crate) fn set_severity(&mut self, severity: ViolationSeverity) {
+        if let Some(report) = self.report.as_mut() {
+            report.set_severity(severity.clone());
+        }
+        self.violation.set_severity(severity);
+    }
+
+    /// The compiled report of the violation, if the rule registered a report.
+    pub fn report(&self) -> Option<&ReportSpecs> {
+        self.report.as_ref()
+    }
 }
@@ -1,8 +1,11 @@
+pub mod baseline;
+pub use baseline::Baseline;
 pub mod finding;
 pub use finding::LintFinding;
 pub mod violation;
 pub use violation::LintViolation;
 pub mod enums;
 pub mod report;
+mod sarif;
 
 pub use report::LintReport;
@@ -1,12 +1,34 @@
 use crate::diagnostics::LintViolation;
+use crate::diagnostics::baseline::Baseline;
 use crate::diagnostics::enums::PhenopacketData;
 use crate::diagnostics::finding::LintFinding;
+use crate::diagnostics::sarif;
+use crate::enums::InputTypes;
+use crate::patches::conflict_policy::{PatchConflictPolicy, SuppressedPatch};
+use crate::patches::enums::PatchInstruction;
+use crate::patches::error::PatchingError;
 use crate::patches::patch::Patch;
+use crate::patches::patch_engine::PatchEngine;
+use crate::patches::schema_guard::{RevertedPatch, SkippedPatch};
+use crate::tree::pointer::Pointer;
+use serde_json::{Value, json};
+
+/// Version of the structure produced by `LintReport::to_json`.
+///
+/// Bumped whenever fields are renamed or removed. Adding fields does not change the version.
+pub const JSON_REPORT_VERSION: u32 = 1;
 
 #[derive(Debug, Default)]
 pub struct LintReport {
     pub patched_phenopacket: Option<PhenopacketData>,
     findings: Vec<LintFinding>,
+    input_type: Option<InputTypes>,
+    truncated: bool,
+    suppressed_patches: Vec<SuppressedPatch>,
+    reverted_patches: Vec<RevertedPatch>,
+    skipped_patches: Vec<SkippedPatch>,
+    suppressed: Vec<LintFinding>,
+    baselined: Vec<LintFinding>,
 }
 
 impl LintReport {
@@ -14,12 +36,95 @@ impl LintReport {
         LintReport {
             patched_phenopacket: None,
             findings: Vec::new(),
+            input_type: None,
+            truncated: false,
+            suppressed_patches: Vec::new(),
+            reverted_patches: Vec::new(),
+            skipped_patches: Vec::new(),
+            suppressed: Vec::new(),
+            baselined: Vec::new(),
         }
     }
 
+    /// The format the phenopacket was detected as, if parsing got that far.
+    pub fn input_type(&self) -> Option<InputTypes> {
+        self.input_type
+    }
+
+    pub fn set_input_type(&mut self, input_type: InputTypes) {
+        self.input_type = Some(input_type);
+    }
+
+    /// Whether linting stopped early, because the maximum number of findings was reached.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    pub fn set_truncated(&mut self) {
+        self.truncated = true;
+    }
+
+    /// Patches that were dropped, because a higher priority rule patched the same field.
+    pub fn suppressed_patches(&self) -> &[SuppressedPatch] {
+        &self.suppressed_patches
+    }
+
+    pub fn extend_suppressed_patches(&mut self, suppressed_patches: Vec<SuppressedPatch>) {
+        self.suppressed_patches.extend(suppressed_patches);
+    }
+
+    /// Patches that were not applied, because the patched phenopacket failed schema validation.
+    pub fn reverted_patches(&self) -> &[RevertedPatch] {
+        &self.reverted_patches
+    }
+
+    pub fn extend_reverted_patches(&mut self, reverted_patches: Vec<RevertedPatch>) {
+        self.reverted_patches.extend(reverted_patches);
+    }
+
+    /// Patches that were not applied, because the configured `PatchSafety` does not allow them.
+    pub fn skipped_patches(&self) -> &[SkippedPatch] {
+        &self.skipped_patches
+    }
+
+    pub fn extend_skipped_patches(&mut self, skipped_patches: Vec<SkippedPatch>) {
+        self.skipped_patches.extend(skipped_patches);
+    }
+
+    /// Findings that were not reported, because the phenopacket ignores them
+    /// via a `phenolint:ignore` external reference.
+    pub fn suppressed(&self) -> &[LintFinding] {
+        &self.suppressed
+    }
+
+    pub fn push_suppressed(&mut self, finding: LintFinding) {
+        self.suppressed.push(finding);
+    }
+
+    /// Findings that were not reported, because they are part of the configured `Baseline`.
+    pub fn baselined(&self) -> &[LintFinding] {
+        &self.baselined
+    }
+
+    pub fn push_baselined(&mut self, finding: LintFinding) {
+        self.baselined.push(finding);
+    }
+
+    /// Findings that are not part of `baseline`, e.g. to compare a report against an older baseline.
+    pub fn new_findings_vs(&self, baseline: &Baseline) -> Vec<&LintFinding> {
+        self.findings
+            .iter()
+            .filter(|finding| !baseline.contains(finding.violation()))
+            .collect()
+    }
+
     pub fn findings(&self) -> &[LintFinding] {
         &self.findings
     }
+
+    /// The violations of all findings, ordered by rule ID and by pointer within a rule.
+    ///
+    /// Findings sharing a pointer with an earlier finding directly follow it.
     pub fn violations(&'_ self) -> Vec<&LintViolation> {
         self.findings.iter().map(|i| i.violation()).collect()
     }
@@ -28,6 +133,152 @@ impl LintReport {
         self.findings.iter().flat_map(|lf| lf.patch()).collect()
     }
 
+    /// Exports the patches of all findings as a single RFC 6902 JSON Patch document.
+    ///
+    /// Patches suppressed by the conflict policy, skipped by the `PatchSafety` or reverted after
+    /// failing schema validation are not part of the document. `phenopacket` has to be the linted phenopacket, because
+    /// moved and duplicated values are resolved against it.
+    pub fn to_json_patch(&self, phenopacket: &Value) -> Result<json_patch::Patch, PatchingError> {
+        PatchEngine.to_json_patch(phenopacket, self.applied_patches().iter().collect())
+    }
+
+    /// Previews what the patches change, as `(pointer, before, after)` for every instruction.
+    ///
+    /// The entries follow the instructions resolved and sorted by the `PatchEngine`, so a move
+    /// shows up as an add and a remove. Values inserted into an array have no `before`, removed
+    /// values have no `after`. Like `LintReport::to_json_patch`, only patches autofix applied are
+    /// part of the diff. `phenopacket` has to be the linted phenopacket. The diff is empty,
+    /// if the patches can not be applied to it.
+    pub fn patch_diff(&self, phenopacket: &Value) -> Vec<(Pointer, Option<Value>, Option<Value>)> {
+        let applied_patches = self.applied_patches();
+        let Ok(instructions) = PatchEngine.resolve(phenopacket, applied_patches.iter().collect())
+        else {
+            return vec![];
+        };
+
+        let mut current = phenopacket.clone();
+        let mut diff = vec![];
+
+        for instruction in instructions {
+            let (at, after, is_add) = match &instruction {
+                PatchInstruction::Add { at, value } => (at, Some(value.clone()), true),
+                PatchInstruction::Replace { at, value } => (at, Some(value.clone()), false),
+                PatchInstruction::Remove { at } => (at, None, false),
+                PatchInstruction::Move { .. } | PatchInstruction::Duplicate { .. } => continue,
+            };
+
+            let is_insertion = is_add
+                && at
+                    .parent()
+                    .and_then(|parent| current.pointer(parent.position()))
+                    .is_some_and(Value::is_array);
+            let before = if is_insertion {
+                None
+            } else {
+                current.pointer(at.position()).cloned()
+            };
+
+            if json_patch::patch(&mut current, &instruction.to_json_patch()).is_err() {
+                return vec![];
+            }
+            diff.push((at.clone(), before, after));
+        }
+
+        diff
+    }
+
+    /// Patches of all findings, except the ones skipped or reverted by autofix,
+    /// merged by rule like they are applied.
+    fn applied_patches(&self) -> Vec<Patch> {
+        let is_applied = |patch: &Patch| {
+            !self
+                .reverted_patches
+                .iter()
+                .any(|reverted| reverted.patch() == patch)
+                && !self
+                    .skipped_patches
+                    .iter()
+                    .any(|skipped| skipped.patch() == patch)
+        };
+
+        PatchConflictPolicy::merge_by_rule(self.findings.iter().flat_map(|finding| {
+            finding
+                .patch()
+                .iter()
+                .filter(|patch| is_applied(patch))
+                .map(|patch| (finding.violation().rule_id(), patch))
+        }))
+    }
+
+    /// Serializes the findings into a SARIF 2.1.0 log, e.g. for code scanning in CI.
+    ///
+    /// `phenostr` has to be the linted phenopacket, because the regions of the results
+    /// are byte ranges into it.
+    pub fn to_sarif(&self, phenostr: &str) -> Value {
+        sarif::to_sarif(&self.findings, phenostr)
+    }
+
+    /// Serializes the findings into a stable JSON structure for downstream tools.
+    ///
+    /// ```json
+    /// {
+    ///   "version": 1,
+    ///   "findings": [{
+    ///     "rule_id": "CURIE003",
+    ///     "severity": "error",
+    ///     "message": "...",
+    ///     "at": ["/phenotypicFeatures/0/type"],
+    ///     "labels": [{"priority": "primary", "message": "...", "start": 120, "end": 138}],
+    ///     "patches": [{"instructions": [{"op": "replace", "at": "/phenotypicFeatures/0/type/id", "value": "HP:0001250"}]}]
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// Severities and label priorities are lowercase snake_case. Pointers are RFC 6901 strings.
+    /// `start` and `end` are byte offsets into the linted phenopacket. `message` is `null` and
+    /// `labels` is empty for findings without a report.
+    pub fn to_json(&self) -> Value {
+        let findings: Vec<Value> = self
+            .findings
+            .iter()
+            .map(|finding| {
+                let violation = finding.violation();
+                let report = finding.report();
+
+                let labels: Vec<Value> = report
+                    .map(|report| {
+                        report
+                            .labels()
+                            .iter()
+                            .map(|label| {
+                                json!({
+                                    "priority": label.style(),
+                                    "message": label.message(),
+                                    "start": label.range().start,
+                                    "end": label.range().end,
+                                })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                json!({
+                    "rule_id": violation.rule_id(),
+                    "severity": violation.severity(),
+                    "message": report.map(|report| report.message()),
+                    "at": violation.at(),
+                    "labels": labels,
+                    "patches": finding.patch(),
+                })
+            })
+            .collect();
+
+        json!({
+            "version": JSON_REPORT_VERSION,
+            "findings": findings,
+        })
+    }
+
     pub fn ambiguous_patches(&self) -> Vec<&Patch> {
         self.findings
             .iter()
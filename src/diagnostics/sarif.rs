@@ -0,0 +1,138 @@
+use crate::diagnostics::finding::LintFinding;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use serde_json::{Value, json};
+use std::ops::Range;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Serializes findings into a SARIF 2.1.0 log with a single run.
+///
+/// Spans of the compiled reports are byte ranges into `phenostr`, which is used to derive
+/// lines and columns. Findings without a report are located by their pointers only.
+pub(crate) fn to_sarif(findings: &[LintFinding], phenostr: &str) -> Value {
+    let mut rule_ids: Vec<&str> = vec![];
+    let mut results = vec![];
+
+    for finding in findings {
+        let violation = finding.violation();
+        let rule_index = match rule_ids.iter().position(|id| *id == violation.rule_id()) {
+            Some(idx) => idx,
+            None => {
+                rule_ids.push(violation.rule_id());
+                rule_ids.len() - 1
+            }
+        };
+
+        let message = finding
+            .report()
+            .map(|report| report.message().to_string())
+            .unwrap_or_else(|| violation.rule_id().to_string());
+
+        let mut location = json!({
+            "logicalLocations": violation
+                .at()
+                .iter()
+                .map(|ptr| json!({"fullyQualifiedName": ptr.position()}))
+                .collect::<Vec<Value>>()
+        });
+
+        let primary_span = finding.report().and_then(|report| {
+            report
+                .labels()
+                .iter()
+                .find(|label| *label.style() == LabelPriority::Primary)
+                .map(|label| label.range().clone())
+        });
+        if let Some(span) = primary_span {
+            location["physicalLocation"] = json!({
+                "artifactLocation": {"index": 0},
+                "region": region(phenostr, &span),
+            });
+        }
+
+        results.push(json!({
+            "ruleId": violation.rule_id(),
+            "ruleIndex": rule_index,
+            "level": level(violation.severity()),
+            "message": {"text": message},
+            "locations": [location],
+        }));
+    }
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rule_ids
+                        .iter()
+                        .map(|id| json!({"id": id}))
+                        .collect::<Vec<Value>>(),
+                }
+            },
+            "artifacts": [{"length": phenostr.len()}],
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }]
+    })
+}
+
+fn level(severity: &ViolationSeverity) -> &'static str {
+    match severity {
+        ViolationSeverity::Error => "error",
+        ViolationSeverity::Warning => "warning",
+        ViolationSeverity::Info => "note",
+    }
+}
+
+fn region(phenostr: &str, span: &Range<usize>) -> Value {
+    let (start_line, start_column) = line_and_column(phenostr, span.start);
+    let (end_line, end_column) = line_and_column(phenostr, span.end);
+
+    json!({
+        "byteOffset": span.start,
+        "byteLength": span.end.saturating_sub(span.start),
+        "startLine": start_line,
+        "startColumn": start_column,
+        "endLine": end_line,
+        "endColumn": end_column,
+    })
+}
+
+/// 1-based line and column of a byte offset. Columns are counted in code points.
+fn line_and_column(phenostr: &str, offset: usize) -> (usize, usize) {
+    let mut offset = offset.min(phenostr.len());
+    while !phenostr.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let preceding = &phenostr[..offset];
+    let line = preceding.matches('\n').count() + 1;
+    let line_start = preceding.rfind('\n').map_or(0, |idx| idx + 1);
+    let column = preceding[line_start..].chars().count() + 1;
+
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::line_and_column;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, (1, 1))]
+    #[case(5, (1, 6))]
+    #[case(6, (2, 1))]
+    #[case(10, (2, 4))]
+    #[case(12, (2, 6))]
+    #[case(100, (2, 8))]
+    fn test_line_and_column(#[case] offset: usize, #[case] expected: (usize, usize)) {
+        let phenostr = "{\"a\":\n  \"äid\"";
+
+        assert_eq!(line_and_column(phenostr, offset), expected);
+    }
+}
@@ -2,11 +2,12 @@ use crate::helper::non_empty_vec::NonEmptyVec;
 use crate::report::enums::ViolationSeverity;
 use crate::tree::pointer::Pointer;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LintViolation {
     severity: ViolationSeverity,
     rule_id: String,
     at: Vec<Pointer>,
+    kind: Option<String>,
 }
 
 impl LintViolation {
@@ -19,13 +20,29 @@ impl LintViolation {
             severity,
             rule_id: rule_id.to_string(),
             at: at.into_vec(),
+            kind: None,
         }
     }
 
+    /// Tells apart violations of a rule that can find different problems at the same pointers,
+    /// e.g. the contradiction modes of PF008.
+    pub fn with_kind(mut self, kind: impl ToString) -> Self {
+        self.kind = Some(kind.to_string());
+        self
+    }
+
+    pub fn kind(&self) -> Option<&str> {
+        self.kind.as_deref()
+    }
+
     pub fn severity(&self) -> &ViolationSeverity {
         &self.severity
     }
 
+    pub(crate) fn set_severity(&mut self, severity: ViolationSeverity) {
+        self.severity = severity;
+    }
+
     pub fn rule_id(&self) -> &str {
         &self.rule_id
     }
@@ -1,7 +1,7 @@
 use std::fmt::{Display, Formatter};
 
-#[doc(hidden)]
-#[derive(PartialEq, Debug)]
+/// The format in which a phenopacket was handed to the linter.
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum InputTypes {
     Json,
     Yaml,
@@ -1,4 +1,5 @@
 use crate::diagnostics::LintReport;
+use crate::enums::InputTypes;
 use crate::patches::error::PatchingError;
 use config::ConfigError;
 use jsonschema::error::ValidationErrorKind;
@@ -42,6 +43,10 @@ impl LintResult {
         &self.report
     }
 
+    pub fn input_type(&self) -> Option<InputTypes> {
+        self.report.input_type()
+    }
+
     pub fn into_result(self) -> Result<LintReport, LinterError> {
         match self.error {
             Some(err) => Err(err),
@@ -202,3 +207,11 @@ pub enum FromContextError {
     )]
     NeedsOntology { rule_ids: String, ontology: String },
 }
+
+#[derive(Debug, Error, PartialEq)]
+pub enum PointerError {
+    #[error("Pointer '{0}' has to be empty or start with '/'")]
+    MissingLeadingSlash(String),
+    #[error("Pointer '{0}' contains a '~' that is not followed by '0' or '1'")]
+    InvalidEscape(String),
+}
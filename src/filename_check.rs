@@ -0,0 +1,112 @@
+use crate::diagnostics::LintViolation;
+use crate::helper::NonEmptyVec;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+
+/// Tokens of a file name, that say nothing about the phenopacket it contains.
+const GENERIC_TOKENS: [&str; 6] = [
+    "case",
+    "patient",
+    "proband",
+    "individual",
+    "phenopacket",
+    "subject",
+];
+
+/// ### NAME001
+/// ## What it does
+/// Compares the name of a linted file with the `id` of the phenopacket it contains.
+///
+/// ## Why is this bad?
+/// When a collection of phenopackets is curated file by file, a file named `BRCA1_case.json`
+/// holding the phenopacket `family7_proband` usually means a file was copied and only partially
+/// edited, or that the content was pasted into the wrong file.
+///
+/// This is not a regular rule, because rules only see the phenopacket. It runs only when
+/// linting a path and only when enabled via `LinterContext::with_filename_id_check`.
+/// Its finding can still be disabled, ignored and baselined like those of the rules.
+pub(crate) struct FilenameIdCheck;
+
+impl FilenameIdCheck {
+    pub(crate) const RULE_ID: &'static str = "NAME001";
+
+    pub(crate) fn check(file_stem: &str, root_node: &dyn Node) -> Option<LintViolation> {
+        let id_ptr = Pointer::new("/id");
+        let phenopacket_id = root_node.value_at(&id_ptr)?;
+
+        if !Self::is_mismatch(file_stem, phenopacket_id.as_str()?) {
+            return None;
+        }
+
+        Some(LintViolation::new(
+            ViolationSeverity::Warning,
+            Self::RULE_ID,
+            NonEmptyVec::with_single_entry(id_ptr),
+        ))
+    }
+
+    pub(crate) fn compile_report(
+        file_stem: &str,
+        root_node: &dyn Node,
+        violation: &LintViolation,
+    ) -> Option<ReportSpecs> {
+        let id_ptr = violation.first_at();
+        let phenopacket_id = root_node.value_at(id_ptr)?.as_str()?.to_string();
+        let span = root_node.span_at(id_ptr)?;
+
+        Some(ReportSpecs::from_violation(
+            violation,
+            format!("Phenopacket id '{phenopacket_id}' does not match the file name '{file_stem}'"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                span.clone(),
+                String::default(),
+            )],
+            vec![
+                "Check, whether the file was copied from another case or holds the wrong phenopacket."
+                    .to_string(),
+            ],
+        ))
+    }
+
+    /// A file name matches, if any of its descriptive tokens appears in the id.
+    ///
+    /// Case and separators are ignored. File names without descriptive tokens never mismatch.
+    fn is_mismatch(file_stem: &str, phenopacket_id: &str) -> bool {
+        let normalized_id = Self::normalize(phenopacket_id);
+
+        let tokens: Vec<String> = file_stem
+            .split(|c: char| !c.is_alphanumeric())
+            .map(Self::normalize)
+            .filter(|token| token.len() >= 3 && !GENERIC_TOKENS.contains(&token.as_str()))
+            .collect();
+
+        !tokens.is_empty() && !tokens.iter().any(|token| normalized_id.contains(token))
+    }
+
+    fn normalize(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FilenameIdCheck;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("BRCA1_case", "PMID_30808312_BRCA1_proband", false)]
+    #[case("brca1-case", "BRCA1", false)]
+    #[case("case_1", "family7_proband", false)]
+    #[case("BRCA1_case", "family7_proband", true)]
+    #[case("TP53_family", "BRCA2_proband", true)]
+    fn test_is_mismatch(#[case] file_stem: &str, #[case] id: &str, #[case] expected: bool) {
+        assert_eq!(FilenameIdCheck::is_mismatch(file_stem, id), expected);
+    }
+}
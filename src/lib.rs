@@ -2,6 +2,7 @@ mod config;
 pub mod diagnostics;
 pub mod enums;
 pub mod error;
+mod filename_check;
 pub mod rules;
 
 pub mod linter_context;
@@ -1,13 +1,62 @@
+use crate::diagnostics::Baseline;
+use crate::patches::enums::PatchSafety;
+use crate::report::enums::ViolationSeverity;
 use once_cell::sync::OnceCell;
 use ontolius::io::OntologyLoaderBuilder;
 use ontolius::ontology::csr::FullCsrOntology;
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
 
-#[derive(Debug, Default)]
+/// Ontologies loaded in this process, keyed by their canonical path.
+static ONTOLOGY_CACHE: OnceLock<Mutex<HashMap<PathBuf, Arc<FullCsrOntology>>>> = OnceLock::new();
+
+/// Loads the ontology at `path` once per process and shares it between all contexts.
+///
+/// Failed loads are not cached, so a later context may retry.
+fn load_cached_ontology(path: &Path) -> Option<Arc<FullCsrOntology>> {
+    let key = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mut cache = ONTOLOGY_CACHE
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if let Some(ontology) = cache.get(&key) {
+        return Some(ontology.clone());
+    }
+
+    let loader = OntologyLoaderBuilder::new().obographs_parser().build();
+    let ontology: Arc<FullCsrOntology> = Arc::new(loader.load_from_path(key.clone()).ok()?);
+    cache.insert(key, ontology.clone());
+    Some(ontology)
+}
+
+#[derive(Debug)]
 pub struct LinterContext {
     hpo_path: Option<PathBuf>,
     hpo: OnceCell<Option<Arc<FullCsrOntology>>>,
+    geno_path: Option<PathBuf>,
+    geno: OnceCell<Option<Arc<FullCsrOntology>>>,
+    fail_fast: bool,
+    max_findings: Option<usize>,
+    file_attribute_aliases: Vec<Vec<String>>,
+    filename_id_check: bool,
+    assay_namespaces: Option<Vec<String>>,
+    shared_id_kinds: Vec<Vec<String>>,
+    modifier_branches: HashMap<String, Vec<String>>,
+    resource_version_formats: HashMap<String, Vec<String>>,
+    contradiction_modes: Option<Vec<String>>,
+    severity_overrides: HashMap<String, ViolationSeverity>,
+    patch_safety: PatchSafety,
+    schema_validation: bool,
+    baseline: Baseline,
+    default_schema_version: String,
+}
+
+impl Default for LinterContext {
+    fn default() -> Self {
+        LinterContext::new(None)
+    }
 }
 
 impl LinterContext {
@@ -15,17 +64,227 @@ impl LinterContext {
         LinterContext {
             hpo_path,
             hpo: OnceCell::default(),
+            geno_path: None,
+            geno: OnceCell::default(),
+            fail_fast: false,
+            max_findings: None,
+            file_attribute_aliases: vec![],
+            filename_id_check: false,
+            assay_namespaces: None,
+            shared_id_kinds: vec![],
+            modifier_branches: HashMap::new(),
+            resource_version_formats: HashMap::new(),
+            contradiction_modes: None,
+            severity_overrides: HashMap::new(),
+            patch_safety: PatchSafety::default(),
+            schema_validation: true,
+            baseline: Baseline::default(),
+            default_schema_version: "2.0.2".to_string(),
         }
     }
-    pub fn hpo(&mut self) -> Option<Arc<FullCsrOntology>> {
+
+    /// Replaces the path of the HPO. The ontology is loaded from the new path on first use.
+    pub fn with_hpo_path(mut self, hpo_path: Option<PathBuf>) -> Self {
+        self.hpo_path = hpo_path;
+        self.hpo = OnceCell::default();
+        self
+    }
+
+    /// Sets the path of the GENO, which rules on genotype terms like allelic states need.
+    pub fn with_geno_path(mut self, geno_path: Option<PathBuf>) -> Self {
+        self.geno_path = geno_path;
+        self.geno = OnceCell::default();
+        self
+    }
+
+    /// Stop linting as soon as the first violation was found.
+    ///
+    /// Remaining rules are skipped. Rules run in order of their rule ID, so the kept finding
+    /// is stable across runs.
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    pub fn fail_fast(&self) -> bool {
+        self.fail_fast
+    }
+
+    /// Caps the number of findings collected per phenopacket.
+    ///
+    /// Once the cap is reached, linting stops and the report is marked as truncated.
+    /// Rules run in order of their rule ID, so the same findings are kept across runs.
+    pub fn with_max_findings(mut self, max_findings: Option<usize>) -> Self {
+        self.max_findings = max_findings;
+        self
+    }
+
+    pub fn max_findings(&self) -> Option<usize> {
+        self.max_findings
+    }
+
+    /// Groups of `fileAttributes` keys that express the same attribute, e.g. `["format", "fileFormat"]`.
+    ///
+    /// Keys differing only in case or separators are always treated as the same attribute.
+    pub fn with_file_attribute_aliases(mut self, aliases: Vec<Vec<String>>) -> Self {
+        self.file_attribute_aliases = aliases;
+        self
+    }
+
+    pub fn file_attribute_aliases(&self) -> &[Vec<String>] {
+        &self.file_attribute_aliases
+    }
+
+    /// Warn when the name of a linted file does not match the `id` of its phenopacket.
+    ///
+    /// Only applies when linting paths, because only then a file name is known.
+    pub fn with_filename_id_check(mut self, filename_id_check: bool) -> Self {
+        self.filename_id_check = filename_id_check;
+        self
+    }
+
+    pub fn filename_id_check(&self) -> bool {
+        self.filename_id_check
+    }
+
+    /// Namespaces accepted for the `assay` of measurements, e.g. `["LOINC", "NCIT"]`.
+    ///
+    /// When not set, the rule falls back to its own defaults.
+    pub fn with_assay_namespaces(mut self, namespaces: Vec<String>) -> Self {
+        self.assay_namespaces = Some(namespaces);
+        self
+    }
+
+    pub fn assay_namespaces(&self) -> Option<&[String]> {
+        self.assay_namespaces.as_deref()
+    }
+
+    /// Groups of entity kinds that may reuse each others ids, e.g. `["phenopacket", "subject"]`.
+    ///
+    /// Known kinds are `phenopacket`, `subject`, `interpretation` and `biosample`.
+    pub fn with_shared_id_kinds(mut self, groups: Vec<Vec<String>>) -> Self {
+        self.shared_id_kinds = groups;
+        self
+    }
+
+    pub fn shared_id_kinds(&self) -> &[Vec<String>] {
+        &self.shared_id_kinds
+    }
+
+    /// Maps HPO modifiers to the phenotype branches they are appropriate for,
+    /// e.g. `Bilateral` (HP:0012832) to `Abnormality of limbs` (HP:0040064).
+    ///
+    /// A modifier also covers its descendants. Modifiers without a mapping are never flagged.
+    pub fn with_modifier_branches(
+        mut self,
+        modifier_branches: HashMap<String, Vec<String>>,
+    ) -> Self {
+        self.modifier_branches = modifier_branches;
+        self
+    }
+
+    pub fn modifier_branches(&self) -> &HashMap<String, Vec<String>> {
+        &self.modifier_branches
+    }
+
+    /// Regexes of the versions accepted for the resources of a namespace, e.g. `NCIT` to `[r"^\d{2}\.\d{2}[a-z]$"]`.
+    ///
+    /// A configured namespace replaces the default release date formats for that namespace.
+    pub fn with_resource_version_formats(
+        mut self,
+        resource_version_formats: HashMap<String, Vec<String>>,
+    ) -> Self {
+        self.resource_version_formats = resource_version_formats;
+        self
+    }
+
+    pub fn resource_version_formats(&self) -> &HashMap<String, Vec<String>> {
+        &self.resource_version_formats
+    }
+
+    /// Relations between observed and excluded phenotypic features that are flagged as contradictions.
+    ///
+    /// Known modes are `exact`, `ancestor` and `descendant`. When not set, only `descendant` is checked.
+    pub fn with_contradiction_modes(mut self, modes: Vec<String>) -> Self {
+        self.contradiction_modes = Some(modes);
+        self
+    }
+
+    pub fn contradiction_modes(&self) -> Option<&[String]> {
+        self.contradiction_modes.as_deref()
+    }
+
+    /// Maps rule ids to the severity their findings are reported with, e.g. `INTER001` to `Error`.
+    ///
+    /// Rules without an override keep the severity they assign themselves.
+    pub fn with_severity_overrides(
+        mut self,
+        severity_overrides: HashMap<String, ViolationSeverity>,
+    ) -> Self {
+        self.severity_overrides = severity_overrides;
+        self
+    }
+
+    pub fn severity_overrides(&self) -> &HashMap<String, ViolationSeverity> {
+        &self.severity_overrides
+    }
+
+    /// Which patches are applied when linting with `patch`, e.g. `PatchSafety::SafeOnly` to skip lossy fixes.
+    pub fn with_patch_safety(mut self, patch_safety: PatchSafety) -> Self {
+        self.patch_safety = patch_safety;
+        self
+    }
+
+    pub fn patch_safety(&self) -> PatchSafety {
+        self.patch_safety
+    }
+
+    /// Validate phenopackets against the phenopacket schema before linting them. Enabled by default.
+    ///
+    /// Without validation, invalid phenopackets are linted as far as their nodes can be parsed,
+    /// and patches are applied without checking that they keep the phenopacket valid.
+    pub fn with_schema_validation(mut self, schema_validation: bool) -> Self {
+        self.schema_validation = schema_validation;
+        self
+    }
+
+    pub fn schema_validation(&self) -> bool {
+        self.schema_validation
+    }
+
+    /// Findings of the baseline are moved to `LintReport::baselined` instead of being reported.
+    pub fn with_baseline(mut self, baseline: Baseline) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    pub fn baseline(&self) -> &Baseline {
+        &self.baseline
+    }
+
+    /// The schema version `META003` sets when a phenopacket lacks a 2.x version. `2.0.2` by default.
+    pub fn with_default_schema_version(mut self, version: impl ToString) -> Self {
+        self.default_schema_version = version.to_string();
+        self
+    }
+
+    pub fn default_schema_version(&self) -> &str {
+        &self.default_schema_version
+    }
+
+    /// The HPO at the configured path.
+    ///
+    /// The ontology is loaded once per process. Contexts with the same path share the same `Arc`.
+    pub fn hpo(&self) -> Option<Arc<FullCsrOntology>> {
         let path = self.hpo_path.as_ref()?;
 
-        self.hpo
-            .get_or_init(|| {
-                let loader = OntologyLoaderBuilder::new().obographs_parser().build();
-                let ontology: Option<FullCsrOntology> = loader.load_from_path(path.clone()).ok();
-                ontology.map(Arc::new)
-            })
-            .clone()
+        self.hpo.get_or_init(|| load_cached_ontology(path)).clone()
+    }
+
+    /// The GENO at the configured path, loaded and shared like [`LinterContext::hpo`].
+    pub fn geno(&self) -> Option<Arc<FullCsrOntology>> {
+        let path = self.geno_path.as_ref()?;
+
+        self.geno.get_or_init(|| load_cached_ontology(path)).clone()
     }
 }
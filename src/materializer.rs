@@ -2,10 +2,12 @@ use crate::parsing::traits::ParsableNode;
 use crate::tree::node::{DynamicNode, MaterializedNode};
 use crate::tree::node_repository::NodeRepository;
 use crate::tree::traits::LocatableNode;
+use crate::tree::vrs_allele::VrsAllele;
 use log::error;
 use phenopackets::schema::v2::Phenopacket;
 use phenopackets::schema::v2::core::{
-    Diagnosis, Disease, OntologyClass, PhenotypicFeature, Resource, VitalStatus,
+    Biosample, Diagnosis, Disease, File, Measurement, OntologyClass, PhenotypicFeature, Resource,
+    VitalStatus,
 };
 
 pub(crate) struct NodeMaterializer;
@@ -26,6 +28,14 @@ impl NodeMaterializer {
             Self::push_to_repo(resource, dyn_node, repo);
         } else if let Some(resource) = Diagnosis::parse(dyn_node) {
             Self::push_to_repo(resource, dyn_node, repo);
+        } else if let Some(file) = File::parse(dyn_node) {
+            Self::push_to_repo(file, dyn_node, repo);
+        } else if let Some(biosample) = Biosample::parse(dyn_node) {
+            Self::push_to_repo(biosample, dyn_node, repo);
+        } else if let Some(measurement) = Measurement::parse(dyn_node) {
+            Self::push_to_repo(measurement, dyn_node, repo);
+        } else if let Some(allele) = VrsAllele::parse(dyn_node) {
+            Self::push_to_repo(allele, dyn_node, repo);
         } else {
             error!("Unable to parse node at '{}'.", dyn_node.pointer());
         };
@@ -1,4 +1,5 @@
 pub mod parseable_nodes;
 pub mod phenopacket_parser;
+pub(crate) mod stringified_booleans;
 pub(crate) mod traits;
 mod utils;
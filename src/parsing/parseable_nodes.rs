@@ -1,9 +1,11 @@
 use crate::parsing::traits::ParsableNode;
 use crate::tree::node::DynamicNode;
 use crate::tree::traits::LocatableNode;
+use crate::tree::vrs_allele::VrsAllele;
 use phenopackets::schema::v2::Phenopacket;
 use phenopackets::schema::v2::core::{
-    Diagnosis, Disease, OntologyClass, PhenotypicFeature, Resource, VitalStatus,
+    Biosample, Diagnosis, Disease, File, Measurement, OntologyClass, PhenotypicFeature, Resource,
+    VitalStatus,
 };
 use serde_json::Value;
 
@@ -114,3 +116,73 @@ impl ParsableNode<Diagnosis> for Diagnosis {
         }
     }
 }
+
+impl ParsableNode<File> for File {
+    fn parse(node: &DynamicNode) -> Option<File> {
+        if let Value::Object(map) = &node.inner
+            && node
+                .pointer()
+                .segments()
+                .into_iter()
+                .any(|seg| seg.to_lowercase() == "files")
+            && map.contains_key("uri")
+            && let Ok(file) = serde_json::from_value::<File>(node.inner.clone())
+        {
+            Some(file)
+        } else {
+            None
+        }
+    }
+}
+
+impl ParsableNode<Biosample> for Biosample {
+    fn parse(node: &DynamicNode) -> Option<Biosample> {
+        let segments: Vec<String> = node.pointer().segments().collect();
+
+        if let Value::Object(map) = &node.inner
+            && segments.len() >= 2
+            && segments[segments.len() - 2] == "biosamples"
+            && map.contains_key("id")
+            && let Ok(biosample) = serde_json::from_value::<Biosample>(node.inner.clone())
+        {
+            Some(biosample)
+        } else {
+            None
+        }
+    }
+}
+
+impl ParsableNode<Measurement> for Measurement {
+    fn parse(node: &DynamicNode) -> Option<Measurement> {
+        let segments: Vec<String> = node.pointer().segments().collect();
+
+        // Identified by its position alone, so rules on incomplete measurements still see them.
+        if node.inner.is_object()
+            && segments.len() >= 2
+            && segments[segments.len() - 2] == "measurements"
+            && let Ok(measurement) = serde_json::from_value::<Measurement>(node.inner.clone())
+        {
+            Some(measurement)
+        } else {
+            None
+        }
+    }
+}
+
+impl ParsableNode<VrsAllele> for VrsAllele {
+    fn parse(node: &DynamicNode) -> Option<VrsAllele> {
+        let segments: Vec<String> = node.pointer().segments().collect();
+
+        if node.inner.is_object()
+            && segments.ends_with(&[
+                "variationDescriptor".to_string(),
+                "variation".to_string(),
+                "allele".to_string(),
+            ])
+        {
+            Some(VrsAllele(node.inner.clone()))
+        } else {
+            None
+        }
+    }
+}
@@ -68,11 +68,18 @@ impl PhenopacketParser {
     }
 
     fn try_from_json(phenobytes: &[u8]) -> Result<String, ParsingError> {
-        Ok(serde_json::from_slice::<String>(phenobytes)?)
+        let phenostr = String::from_utf8(phenobytes.to_vec())?;
+        serde_json::from_str::<Value>(&phenostr)?;
+        Ok(phenostr)
     }
 
     fn try_from_yaml(phenobytes: &[u8]) -> Result<String, ParsingError> {
-        Ok(serde_yaml::from_slice(phenobytes)?)
+        let phenostr = String::from_utf8(phenobytes.to_vec())?;
+        if serde_yaml::from_str::<serde_yaml::Value>(&phenostr)?.is_mapping() {
+            Ok(phenostr)
+        } else {
+            Err(ParsingError::Unparseable)
+        }
     }
 
     fn try_from_protobuf(phenobytes: &[u8]) -> Result<String, ParsingError> {
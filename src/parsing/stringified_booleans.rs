@@ -0,0 +1,106 @@
+use crate::tree::pointer::Pointer;
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// Keys, whose values are booleans in the phenopacket schema.
+const BOOLEAN_KEYS: [&str; 3] = ["excluded", "reverseComplement", "consanguinousParents"];
+
+/// Parses `"true"` and `"false"`, ignoring case. Other strings are no stringified booleans.
+pub(crate) fn parse_boolean(value: &str) -> Option<bool> {
+    if value.eq_ignore_ascii_case("true") {
+        Some(true)
+    } else if value.eq_ignore_ascii_case("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Collects the pointers and parsed values of all stringified booleans stored under one of the `BOOLEAN_KEYS`.
+pub(crate) fn find_stringified_booleans(
+    value: &Value,
+    ptr: &Pointer,
+    found: &mut Vec<(Pointer, bool)>,
+) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let mut child_ptr = ptr.clone();
+                child_ptr.down(key);
+
+                if let Value::String(child) = child
+                    && BOOLEAN_KEYS.contains(&key.as_str())
+                    && let Some(boolean) = parse_boolean(child)
+                {
+                    found.push((child_ptr, boolean));
+                } else {
+                    find_stringified_booleans(child, &child_ptr, found);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for (idx, child) in values.iter().enumerate() {
+                let mut child_ptr = ptr.clone();
+                child_ptr.down(idx);
+                find_stringified_booleans(child, &child_ptr, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replaces all stringified booleans with real booleans. Returns `values` unchanged, if there are none.
+///
+/// Phenopackets are validated and materialized in this normalized form, so stringified booleans are
+/// reported by `StringifiedBooleanRule` instead of rejecting the phenopacket or hiding its nodes from other rules.
+pub(crate) fn parse_stringified_booleans(values: &Value) -> Cow<'_, Value> {
+    let mut found = vec![];
+    find_stringified_booleans(values, &Pointer::at_root(), &mut found);
+    if found.is_empty() {
+        return Cow::Borrowed(values);
+    }
+
+    let mut parsed = values.clone();
+    for (ptr, boolean) in found {
+        if let Some(value) = parsed.pointer_mut(ptr.position()) {
+            *value = Value::Bool(boolean);
+        }
+    }
+    Cow::Owned(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(json!({"phenotypicFeatures": [{"type": {"id": "HP:0001250", "label": "Seizure"}, "excluded": "true"}]}), vec![("/phenotypicFeatures/0/excluded", true)])]
+    #[case(json!({"diseases": [{"term": {"id": "MONDO:0005027", "label": "epilepsy"}, "excluded": "FALSE"}]}), vec![("/diseases/0/excluded", false)])]
+    #[case(json!({"phenotypicFeatures": [{"type": {"id": "HP:0001250", "label": "Seizure"}, "excluded": true}]}), vec![])]
+    #[case(json!({"phenotypicFeatures": [{"type": {"id": "HP:0001250", "label": "Seizure"}, "excluded": "yes"}]}), vec![])]
+    #[case(json!({"subject": {"id": "true", "description": "false"}}), vec![])]
+    fn test_find_stringified_booleans(#[case] value: Value, #[case] expected: Vec<(&str, bool)>) {
+        let mut found = vec![];
+        find_stringified_booleans(&value, &Pointer::at_root(), &mut found);
+
+        let found: Vec<(&str, bool)> = found
+            .iter()
+            .map(|(ptr, boolean)| (ptr.position(), *boolean))
+            .collect();
+        assert_eq!(found, expected);
+    }
+
+    #[rstest]
+    fn test_parse_stringified_booleans() {
+        let values = json!({"diseases": [{"excluded": "true"}, {"excluded": false}]});
+
+        let parsed = parse_stringified_booleans(&values);
+
+        assert_eq!(
+            parsed.into_owned(),
+            json!({"diseases": [{"excluded": true}, {"excluded": false}]})
+        );
+    }
+}
@@ -42,21 +42,47 @@ fn collect_json_spans_inner(
 }
 
 /// Collect all spans for a `Spanned<Value>` YAML structure
+///
+/// `saphyr` marks positions as char indices. They are converted to byte offsets,
+/// because reports slice the phenopacket string by bytes.
 pub(crate) fn collect_yaml_spans(
     yaml_str: &str,
 ) -> Result<HashMap<Pointer, Range<usize>>, ParsingError> {
     let mut spans: HashMap<Pointer, Range<usize>> = HashMap::new();
+    let byte_offsets = char_byte_offsets(yaml_str);
 
     let yaml = MarkedYaml::load_from_str(yaml_str)?;
     for yaml_node in yaml {
-        collect_yaml_spanns_inner(&yaml_node, Pointer::at_root(), &mut spans);
+        spans.entry(Pointer::at_root()).or_insert(
+            byte_offset(&byte_offsets, yaml_node.span.start.index())
+                ..byte_offset(&byte_offsets, yaml_node.span.end.index()),
+        );
+        collect_yaml_spanns_inner(&yaml_node, Pointer::at_root(), &byte_offsets, &mut spans);
     }
     Ok(spans)
 }
 
+/// Byte offset of every char in `value`, followed by the length of `value`.
+fn char_byte_offsets(value: &str) -> Vec<usize> {
+    value
+        .char_indices()
+        .map(|(byte_idx, _)| byte_idx)
+        .chain(std::iter::once(value.len()))
+        .collect()
+}
+
+fn byte_offset(byte_offsets: &[usize], char_idx: usize) -> usize {
+    byte_offsets
+        .get(char_idx)
+        .or(byte_offsets.last())
+        .copied()
+        .unwrap_or_default()
+}
+
 fn collect_yaml_spanns_inner(
     node: &MarkedYaml,
     path: Pointer,
+    byte_offsets: &[usize],
     spans: &mut HashMap<Pointer, Range<usize>>,
 ) {
     match &node.data {
@@ -67,9 +93,10 @@ fn collect_yaml_spanns_inner(
                     new_path.down(key_str.as_str().unwrap());
                     spans.insert(
                         new_path.clone(),
-                        value.span.start.index()..value.span.end.index(),
+                        byte_offset(byte_offsets, value.span.start.index())
+                            ..byte_offset(byte_offsets, value.span.end.index()),
                     );
-                    collect_yaml_spanns_inner(value, new_path, spans);
+                    collect_yaml_spanns_inner(value, new_path, byte_offsets, spans);
                 }
             }
         }
@@ -79,11 +106,28 @@ fn collect_yaml_spanns_inner(
                 new_path.down(idx.to_string());
                 spans.insert(
                     new_path.clone(),
-                    item.span.start.index()..item.span.end.index() - 1,
+                    byte_offset(byte_offsets, item.span.start.index())
+                        ..byte_offset(byte_offsets, item.span.end.index() - 1),
                 );
-                collect_yaml_spanns_inner(item, new_path, spans);
+                collect_yaml_spanns_inner(item, new_path, byte_offsets, spans);
             }
         }
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_yaml_spans_are_byte_offsets() {
+        let yaml_str = "description: Zürich\nid: patient-1\n";
+
+        let spans = collect_yaml_spans(yaml_str).unwrap();
+
+        let id_span = spans.get(&Pointer::new("/id")).unwrap();
+        assert!(yaml_str[id_span.start..].starts_with("patient-1"));
+        assert!(spans.contains_key(&Pointer::at_root()));
+    }
+}
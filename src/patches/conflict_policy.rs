@@ -0,0 +1,334 @@
+use crate::diagnostics::LintFinding;
+use crate::helper::NonEmptyVec;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::report::enums::ViolationSeverity;
+use crate::tree::pointer::Pointer;
+
+/// A patch that was dropped, because a patch of a higher priority rule writes to the same field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuppressedPatch {
+    rule_id: String,
+    patch: Patch,
+    suppressed_by: String,
+}
+
+impl SuppressedPatch {
+    /// The rule that proposed the dropped patch.
+    pub fn rule_id(&self) -> &str {
+        &self.rule_id
+    }
+
+    pub fn patch(&self) -> &Patch {
+        &self.patch
+    }
+
+    /// The rule whose patch was applied instead.
+    pub fn suppressed_by(&self) -> &str {
+        &self.suppressed_by
+    }
+}
+
+/// Decides which patch wins, when patches of different rules write to the same field.
+///
+/// Findings are prioritized by severity (errors first) and then by rule id, so the
+/// outcome does not depend on the order the rules ran in.
+pub(crate) struct PatchConflictPolicy;
+
+impl PatchConflictPolicy {
+    /// Removes all patches from the findings that conflict with a patch of a higher priority rule.
+    ///
+    /// Two patches conflict if `PatchConflictPolicy::find_overlap` finds an overlap.
+    /// Patches of the same rule never conflict with each other.
+    ///
+    /// # Returns
+    ///
+    /// The removed patches, in the order they were suppressed.
+    pub(crate) fn resolve(findings: &mut [LintFinding]) -> Vec<SuppressedPatch> {
+        let mut order: Vec<usize> = (0..findings.len()).collect();
+        order.sort_by(|a, b| {
+            let (a, b) = (findings[*a].violation(), findings[*b].violation());
+            (Self::rank(a.severity()), a.rule_id()).cmp(&(Self::rank(b.severity()), b.rule_id()))
+        });
+
+        let mut accepted: Vec<(Patch, String)> = vec![];
+        let mut suppressed = vec![];
+        let mut kept_patches: Vec<Vec<Patch>> = vec![vec![]; findings.len()];
+
+        for idx in order {
+            let rule_id = findings[idx].violation().rule_id().to_string();
+
+            for patch in findings[idx].patch() {
+                let winner = accepted.iter().find(|(accepted_patch, accepted_rule_id)| {
+                    *accepted_rule_id != rule_id
+                        && Self::find_overlap(accepted_patch, patch).is_some()
+                });
+
+                match winner {
+                    Some((_, winner_rule_id)) => suppressed.push(SuppressedPatch {
+                        rule_id: rule_id.clone(),
+                        patch: patch.clone(),
+                        suppressed_by: winner_rule_id.clone(),
+                    }),
+                    None => {
+                        accepted.push((patch.clone(), rule_id.clone()));
+                        kept_patches[idx].push(patch.clone());
+                    }
+                }
+            }
+        }
+
+        for (finding, patches) in findings.iter_mut().zip(kept_patches) {
+            finding.set_patches(patches);
+        }
+
+        suppressed
+    }
+
+    fn rank(severity: &ViolationSeverity) -> u8 {
+        match severity {
+            ViolationSeverity::Error => 0,
+            ViolationSeverity::Warning => 1,
+            ViolationSeverity::Info => 2,
+        }
+    }
+
+    /// Searches for instructions of two patches that would overwrite each other.
+    ///
+    /// Two instructions overlap, if they touch the same pointer or one touches a pointer below the
+    /// other. Sources of moves count as removals, except where a move picks up the target of another
+    /// move. Such chains and their cycles are left to the cycle detection of the `PatchEngine`.
+    /// Appends to the same array do not overwrite each other.
+    ///
+    /// This is the only definition of a conflict, so the `PatchEngine` never rejects patches
+    /// the policy kept.
+    ///
+    /// # Returns
+    ///
+    /// The pointers of the first pair of overlapping instructions, or `None` if the patches are independent.
+    pub(crate) fn find_overlap<'a>(
+        a: &'a Patch,
+        b: &'a Patch,
+    ) -> Option<(&'a Pointer, &'a Pointer)> {
+        let is_within = |inner: &Pointer, outer: &Pointer| {
+            (inner == outer && !inner.is_append()) || inner.is_descendant_of(outer)
+        };
+        let is_chain = |a: &Touch, b: &Touch| {
+            matches!(
+                (a, b),
+                (Touch::MoveSource, Touch::MoveTarget) | (Touch::MoveTarget, Touch::MoveSource)
+            )
+        };
+
+        let (touched_a, touched_b) = (Self::touched(a), Self::touched(b));
+        touched_a.iter().find_map(|(ptr_a, touch_a)| {
+            touched_b
+                .iter()
+                .find(|(ptr_b, touch_b)| {
+                    let chained = ptr_a == ptr_b && is_chain(touch_a, touch_b);
+                    !chained && (is_within(ptr_a, ptr_b) || is_within(ptr_b, ptr_a))
+                })
+                .map(|(ptr_b, _)| (*ptr_a, *ptr_b))
+        })
+    }
+
+    /// Merges the patches of each rule into a single patch, in the order the rules first appear.
+    ///
+    /// Patches of the same rule never conflict, but the `PatchEngine` only knows that for the
+    /// instructions of a single patch. Patches have to be merged with this, before they are applied.
+    pub(crate) fn merge_by_rule<'a>(
+        patches: impl IntoIterator<Item = (&'a str, &'a Patch)>,
+    ) -> Vec<Patch> {
+        let mut merged: Vec<(&str, Vec<PatchInstruction>)> = vec![];
+
+        for (rule_id, patch) in patches {
+            match merged
+                .iter_mut()
+                .find(|(merged_rule_id, _)| *merged_rule_id == rule_id)
+            {
+                Some((_, instructions)) => instructions.extend_from_slice(patch.instructions()),
+                None => merged.push((rule_id, patch.instructions().to_vec())),
+            }
+        }
+
+        merged
+            .into_iter()
+            .filter_map(|(_, mut instructions)| {
+                if instructions.is_empty() {
+                    return None;
+                }
+                let first = instructions.remove(0);
+                Some(Patch::new(NonEmptyVec::with_rest(first, instructions)))
+            })
+            .collect()
+    }
+
+    fn touched(patch: &Patch) -> Vec<(&Pointer, Touch)> {
+        patch
+            .instructions()
+            .iter()
+            .flat_map(|instruction| match instruction {
+                PatchInstruction::Add { at, .. }
+                | PatchInstruction::Remove { at }
+                | PatchInstruction::Replace { at, .. } => vec![(at, Touch::Write)],
+                PatchInstruction::Move { from, to } => {
+                    vec![(to, Touch::MoveTarget), (from, Touch::MoveSource)]
+                }
+                PatchInstruction::Duplicate { to, .. } => vec![(to, Touch::Write)],
+            })
+            .collect()
+    }
+}
+
+/// How an instruction touches a pointer, see `PatchConflictPolicy::find_overlap`.
+enum Touch {
+    Write,
+    MoveTarget,
+    MoveSource,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::LintViolation;
+    use crate::patches::patch_engine::PatchEngine;
+    use serde_json::{Value, json};
+
+    fn finding(rule_id: &str, severity: ViolationSeverity, at: &str, value: &str) -> LintFinding {
+        LintFinding::new(
+            LintViolation::new(
+                severity,
+                rule_id,
+                NonEmptyVec::with_single_entry(Pointer::new(at)),
+            ),
+            vec![Patch::new(NonEmptyVec::with_single_entry(
+                PatchInstruction::Add {
+                    at: Pointer::new(at),
+                    value: Value::String(value.to_string()),
+                },
+            ))],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_resolve_prefers_severity() {
+        let mut findings = vec![
+            finding(
+                "AAA001",
+                ViolationSeverity::Warning,
+                "/diseases/0/term",
+                "a",
+            ),
+            finding(
+                "BBB001",
+                ViolationSeverity::Error,
+                "/diseases/0/term/id",
+                "b",
+            ),
+        ];
+
+        let suppressed = PatchConflictPolicy::resolve(&mut findings);
+
+        assert_eq!(suppressed.len(), 1);
+        assert_eq!(suppressed[0].rule_id(), "AAA001");
+        assert_eq!(suppressed[0].suppressed_by(), "BBB001");
+        assert!(findings[0].patch().is_empty());
+        assert_eq!(findings[1].patch().len(), 1);
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_rule_id() {
+        let mut findings = vec![
+            finding(
+                "BBB001",
+                ViolationSeverity::Warning,
+                "/diseases/0/term",
+                "b",
+            ),
+            finding(
+                "AAA001",
+                ViolationSeverity::Warning,
+                "/diseases/0/term",
+                "a",
+            ),
+        ];
+
+        let suppressed = PatchConflictPolicy::resolve(&mut findings);
+
+        assert_eq!(suppressed.len(), 1);
+        assert_eq!(suppressed[0].rule_id(), "BBB001");
+        assert_eq!(findings[1].patch().len(), 1);
+    }
+
+    #[test]
+    fn test_resolve_keeps_unrelated_patches() {
+        let mut findings = vec![
+            finding(
+                "AAA001",
+                ViolationSeverity::Warning,
+                "/diseases/0/term",
+                "a",
+            ),
+            finding(
+                "BBB001",
+                ViolationSeverity::Warning,
+                "/diseases/1/term",
+                "b",
+            ),
+            finding(
+                "AAA001",
+                ViolationSeverity::Warning,
+                "/diseases/0/term",
+                "c",
+            ),
+        ];
+
+        assert!(PatchConflictPolicy::resolve(&mut findings).is_empty());
+    }
+
+    #[test]
+    fn test_find_overlap_counts_move_sources() {
+        let moved = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Move {
+            from: Pointer::new("/diseases/0"),
+            to: Pointer::new("/diseases/1"),
+        }));
+        let added = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Add {
+            at: Pointer::new("/diseases/0/excluded"),
+            value: Value::Bool(true),
+        }));
+        let chained = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Move {
+            from: Pointer::new("/diseases/1"),
+            to: Pointer::new("/diseases/2"),
+        }));
+
+        assert!(PatchConflictPolicy::find_overlap(&moved, &added).is_some());
+        assert!(PatchConflictPolicy::find_overlap(&moved, &chained).is_none());
+    }
+
+    #[test]
+    fn test_merge_by_rule() {
+        let findings = [
+            finding("AAA001", ViolationSeverity::Warning, "/id", "a"),
+            finding("BBB001", ViolationSeverity::Warning, "/subject/id", "b"),
+            finding("AAA001", ViolationSeverity::Warning, "/id", "c"),
+        ];
+
+        let merged = PatchConflictPolicy::merge_by_rule(findings.iter().flat_map(|finding| {
+            finding
+                .patch()
+                .iter()
+                .map(|patch| (finding.violation().rule_id(), patch))
+        }));
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].instructions().len(), 2);
+        assert_eq!(merged[1].instructions().len(), 1);
+        let phenopacket = json!({"id": "x", "subject": {"id": "y"}});
+        assert!(
+            PatchEngine
+                .resolve(&phenopacket, merged.iter().collect())
+                .is_ok()
+        );
+    }
+}
@@ -1,12 +1,16 @@
 #![allow(dead_code)]
 
+use crate::patches::patch::Patch;
 use crate::tree::pointer::Pointer;
+use serde::Serialize;
 use serde_json::{Value, from_value, json};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
 pub enum PatchInstruction {
     Add { at: Pointer, value: Value },
     Remove { at: Pointer },
+    Replace { at: Pointer, value: Value },
     Move { from: Pointer, to: Pointer },
     Duplicate { from: Pointer, to: Pointer },
 }
@@ -22,6 +26,10 @@ impl PatchInstruction {
                 from_value(json!([{ "op": "remove", "path": at.position() }]))
                     .expect("Could not parse patch")
             }
+            PatchInstruction::Replace { at, value } => {
+                from_value(json!([{ "op": "replace", "path": at.position(), "value": value }]))
+                    .expect("Could not parse patch")
+            }
             PatchInstruction::Move { from, to } => from_value(
                 json!([{ "op": "move", "path": to.position(), "from": from.position() }]),
             )
@@ -33,3 +41,24 @@ impl PatchInstruction {
         }
     }
 }
+
+/// Which patches autofix applies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PatchSafety {
+    /// Apply every patch.
+    #[default]
+    All,
+    /// Skip lossy patches, see `Patch::is_lossy`. Skipped patches stay part of their findings
+    /// and are listed in `LintReport::skipped_patches`.
+    SafeOnly,
+}
+
+impl PatchSafety {
+    /// Whether `patch` is applied to the phenopacket `values`.
+    pub fn allows(&self, patch: &Patch, values: &Value) -> bool {
+        match self {
+            PatchSafety::All => true,
+            PatchSafety::SafeOnly => !patch.is_lossy(values),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::error::InitError;
+use crate::tree::pointer::Pointer;
 use json_patch::PatchError;
 use thiserror::Error;
 
@@ -10,4 +11,24 @@ pub enum PatchingError {
     InitError(#[from] InitError),
     #[error(transparent)]
     PatchError(#[from] PatchError),
+    #[error("Move patches form a cycle: {}", format_cycle(.0))]
+    CircularMoves(Vec<Pointer>),
+    #[error("Patches conflict at {}", format_conflict(.0))]
+    ConflictingPatches(Vec<Pointer>),
+}
+
+fn format_cycle(cycle: &[Pointer]) -> String {
+    cycle
+        .iter()
+        .map(|ptr| format!("'{}'", ptr))
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+fn format_conflict(pointers: &[Pointer]) -> String {
+    pointers
+        .iter()
+        .map(|ptr| format!("'{}'", ptr))
+        .collect::<Vec<_>>()
+        .join(" and ")
 }
@@ -1,7 +1,9 @@
+pub mod conflict_policy;
 pub mod enums;
 pub mod error;
 pub mod patch;
 pub mod patch_engine;
 pub mod patch_registration;
 pub mod patch_registry;
+pub mod schema_guard;
 pub mod traits;
@@ -1,7 +1,10 @@
 use crate::helper::NonEmptyVec;
 use crate::patches::enums::PatchInstruction;
+use crate::tree::pointer::Pointer;
+use serde::Serialize;
+use serde_json::Value;
 
-#[derive(Debug, Default, PartialEq, Clone)]
+#[derive(Debug, Default, PartialEq, Clone, Serialize)]
 pub struct Patch {
     instructions: Vec<PatchInstruction>,
 }
@@ -16,4 +19,29 @@ impl Patch {
     pub fn instructions(&self) -> &[PatchInstruction] {
         &self.instructions
     }
+
+    /// Whether applying the patch to `values` removes or overwrites data, see `PatchSafety::SafeOnly`.
+    ///
+    /// Removals and replacements are always lossy. Adds, moves and duplicates are lossy, if their
+    /// target already holds a value, except for inserts into an array, which shift the existing elements.
+    pub fn is_lossy(&self, values: &Value) -> bool {
+        let overwrites = |at: &Pointer| {
+            let is_insertion = at.is_append()
+                || at
+                    .parent()
+                    .and_then(|parent| values.pointer(parent.position()))
+                    .is_some_and(Value::is_array);
+            !is_insertion && values.pointer(at.position()).is_some()
+        };
+
+        self.instructions
+            .iter()
+            .any(|instruction| match instruction {
+                PatchInstruction::Remove { .. } | PatchInstruction::Replace { .. } => true,
+                PatchInstruction::Add { at, .. } => overwrites(at),
+                PatchInstruction::Move { to, .. } | PatchInstruction::Duplicate { to, .. } => {
+                    overwrites(to)
+                }
+            })
+    }
 }
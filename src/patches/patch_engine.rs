@@ -1,17 +1,136 @@
+use crate::patches::conflict_policy::PatchConflictPolicy;
 use crate::patches::enums::PatchInstruction;
 use crate::patches::error::PatchingError;
 use crate::patches::patch::Patch;
-use serde_json::Value;
+use crate::tree::pointer::Pointer;
+use serde_json::{Value, json};
 use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Default)]
 pub struct PatchEngine;
 
 impl PatchEngine {
     pub fn patch(&self, values: &Value, patches: Vec<&Patch>) -> Result<Value, PatchingError> {
-        let patched_value = values.clone();
-        let patch_instructions = Self::resolve_patches(patches, &patched_value)?;
-        Self::apply(patched_value, patch_instructions)
+        let json_patch = self.to_json_patch(values, patches)?;
+
+        let mut patched_value = values.clone();
+        json_patch::patch(&mut patched_value, &json_patch)?;
+        Ok(patched_value)
+    }
+
+    /// Compiles the patches into a single RFC 6902 JSON Patch document.
+    ///
+    /// The patches are checked for circular moves, resolved into `add` and `remove`
+    /// operations and sorted, so that applying the document to `values` with any
+    /// standards-compliant tool gives the same result as `PatchEngine::patch`.
+    pub fn to_json_patch(
+        &self,
+        values: &Value,
+        patches: Vec<&Patch>,
+    ) -> Result<json_patch::Patch, PatchingError> {
+        let patch_instructions = self.resolve(values, patches)?;
+        Ok(json_patch::Patch(
+            patch_instructions
+                .iter()
+                .flat_map(|instruction| instruction.to_json_patch().0)
+                .collect(),
+        ))
+    }
+
+    /// Checks the patches for circular moves and conflicts and resolves them into the sorted
+    /// `Add`, `Remove` and `Replace` instructions, in the order they are applied.
+    pub fn resolve(
+        &self,
+        values: &Value,
+        patches: Vec<&Patch>,
+    ) -> Result<Vec<PatchInstruction>, PatchingError> {
+        if let Some(cycle) = Self::find_move_cycle(&patches) {
+            return Err(PatchingError::CircularMoves(cycle));
+        }
+        if let Some(conflict) = Self::find_conflict(&patches) {
+            return Err(PatchingError::ConflictingPatches(conflict));
+        }
+
+        Self::resolve_patches(patches, values)
+    }
+
+    /// Searches all `Move` instructions for a cycle, e.g. `/a -> /b` and `/b -> /a`.
+    ///
+    /// Each patch may be valid on its own, but when patches of different rules move
+    /// values in a circle, applying them would scramble the document.
+    ///
+    /// # Returns
+    ///
+    /// The pointers forming the cycle, starting and ending with the same pointer,
+    /// or `None` if the moves are free of cycles.
+    fn find_move_cycle(patches: &[&Patch]) -> Option<Vec<Pointer>> {
+        let mut moves: HashMap<&Pointer, Vec<&Pointer>> = HashMap::new();
+        for instruction in patches.iter().flat_map(|p| p.instructions()) {
+            if let PatchInstruction::Move { from, to } = instruction {
+                moves.entry(from).or_default().push(to);
+            }
+        }
+
+        let mut explored: HashSet<&Pointer> = HashSet::new();
+        let mut starts: Vec<&Pointer> = moves.keys().copied().collect();
+        starts.sort_by(|a, b| a.position().cmp(b.position()));
+
+        for start in starts {
+            let mut path = vec![];
+            if let Some(cycle) = Self::walk_moves(start, &moves, &mut path, &mut explored) {
+                return Some(cycle);
+            }
+        }
+
+        None
+    }
+
+    /// Searches for instructions of different patches that would overwrite each other,
+    /// as defined by `PatchConflictPolicy::find_overlap`.
+    ///
+    /// Instructions of the same patch never conflict, because a patch is compiled as a whole by a
+    /// single rule. Patches of the same rule have to be merged with `PatchConflictPolicy::merge_by_rule`
+    /// to be treated the same way.
+    ///
+    /// # Returns
+    ///
+    /// The pointers of the first pair of conflicting instructions, or `None` if the patches are independent.
+    fn find_conflict(patches: &[&Patch]) -> Option<Vec<Pointer>> {
+        patches.iter().enumerate().find_map(|(idx, patch)| {
+            patches.iter().skip(idx + 1).find_map(|other| {
+                PatchConflictPolicy::find_overlap(patch, other)
+                    .map(|(ptr, other_ptr)| vec![ptr.clone(), other_ptr.clone()])
+            })
+        })
+    }
+
+    fn walk_moves<'a>(
+        current: &'a Pointer,
+        moves: &HashMap<&'a Pointer, Vec<&'a Pointer>>,
+        path: &mut Vec<&'a Pointer>,
+        explored: &mut HashSet<&'a Pointer>,
+    ) -> Option<Vec<Pointer>> {
+        if let Some(pos) = path.iter().position(|ptr| *ptr == current) {
+            let mut cycle: Vec<Pointer> = path[pos..].iter().map(|ptr| (*ptr).clone()).collect();
+            cycle.push(current.clone());
+            return Some(cycle);
+        }
+
+        if explored.contains(current) {
+            return None;
+        }
+
+        path.push(current);
+        for next in moves.get(current).into_iter().flatten() {
+            if let Some(cycle) = Self::walk_moves(next, moves, path, explored) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        explored.insert(current);
+
+        None
     }
 
     /// Resolves high-level patch operations into primitive operations.
@@ -26,7 +145,7 @@ impl PatchEngine {
     ///   followed by a `Remove` operation (deleting from the source).
     /// - **`Duplicate`**: Expanded into a single `Add` operation (copying the value to
     ///   the target location).
-    /// - **Other patches** (`Add`, `Remove`): Passed through unchanged.
+    /// - **Other patches** (`Add`, `Remove`, `Replace`): Passed through unchanged.
     ///
     /// # Arguments
     ///
@@ -79,18 +198,48 @@ impl PatchEngine {
                     })
             })
             .collect();
+        Self::create_missing_arrays(&mut resolved_patches, value);
         Self::sort_patches(resolved_patches.as_mut_slice());
         Ok(resolved_patches)
     }
 
+    /// Turns the first `Add` that appends to a missing array, e.g. at `"/phenotypicFeatures/-"`,
+    /// into an `Add` of a single-element array at `"/phenotypicFeatures"`.
+    ///
+    /// Further appends to the same array are kept. They are sorted after the creation of the
+    /// array, because it is shallower.
+    fn create_missing_arrays(instructions: &mut [PatchInstruction], value: &Value) {
+        let mut created: HashSet<Pointer> = HashSet::new();
+
+        for instruction in instructions.iter_mut() {
+            let PatchInstruction::Add { at, value: added } = instruction else {
+                continue;
+            };
+            let Some(array_ptr) = at.parent().filter(|_| at.is_append()) else {
+                continue;
+            };
+            if value.pointer(array_ptr.position()).is_some() || created.contains(&array_ptr) {
+                continue;
+            }
+
+            *instruction = PatchInstruction::Add {
+                at: array_ptr.clone(),
+                value: json!([added.clone()]),
+            };
+            created.insert(array_ptr);
+        }
+    }
+
     /// Sorts patches in a specific order to ensure correct application.
     ///
     /// Sorting is performed with two priorities:
-    /// 1. **Patch type**: `Add` patches are ordered before `Remove` patches.
-    ///    This ensures additions are processed before any removals.
+    /// 1. **Patch type**: `Add` and `Replace` patches are ordered before `Remove` patches.
+    ///    This ensures additions and replacements are processed before any removals.
     /// 2. **Tree depth**: Within each patch type, patches are sorted by their
     ///    depth in the JSON tree (number of path segments). Shallower paths
     ///    come before deeper ones.
+    /// 3. **Position**: `Remove` patches of the same depth are applied back to front,
+    ///    so removing an array element does not shift the indices of the remaining removals.
     ///
     /// # Example ordering
     /// Given patches at paths:
@@ -101,31 +250,31 @@ impl PatchEngine {
     /// After sorting: `Add /a/b`, `Add /a/b/c`, `Remove /a`
     fn sort_patches(patches: &mut [PatchInstruction]) {
         patches.sort_by(|p1, p2| match (p1, p2) {
-            (PatchInstruction::Add { .. }, PatchInstruction::Remove { .. }) => Ordering::Less,
-            (PatchInstruction::Remove { .. }, PatchInstruction::Add { .. }) => Ordering::Greater,
-            (PatchInstruction::Add { at: at1, .. }, PatchInstruction::Add { at: at2, .. }) => {
-                at1.segments().count().cmp(&at2.segments().count())
-            }
+            (
+                PatchInstruction::Add { .. } | PatchInstruction::Replace { .. },
+                PatchInstruction::Remove { .. },
+            ) => Ordering::Less,
+            (
+                PatchInstruction::Remove { .. },
+                PatchInstruction::Add { .. } | PatchInstruction::Replace { .. },
+            ) => Ordering::Greater,
+            (
+                PatchInstruction::Add { at: at1, .. } | PatchInstruction::Replace { at: at1, .. },
+                PatchInstruction::Add { at: at2, .. } | PatchInstruction::Replace { at: at2, .. },
+            ) => at1.depth().cmp(&at2.depth()),
             (PatchInstruction::Remove { at: at1 }, PatchInstruction::Remove { at: at2 }) => {
-                at1.segments().count().cmp(&at2.segments().count())
+                at1.depth().cmp(&at2.depth()).then_with(|| at2.cmp(at1))
             }
             _ => Ordering::Equal,
         });
     }
-
-    fn apply(mut values: Value, patches: Vec<PatchInstruction>) -> Result<Value, PatchingError> {
-        for patch in patches {
-            let patch = patch.to_json_patch();
-            json_patch::patch(&mut values, &patch)?;
-        }
-        Ok(values)
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::helper::NonEmptyVec;
     use crate::patches::enums::PatchInstruction;
+    use crate::patches::error::PatchingError;
     use crate::patches::patch::Patch;
     use crate::patches::patch_engine::PatchEngine;
     use crate::tree::pointer::Pointer;
@@ -162,6 +311,150 @@ mod tests {
             ]
         })
     }
+    #[rstest]
+    fn test_circular_moves_are_rejected() {
+        let patcher = PatchEngine;
+        let phenostr = sample_phenopacket();
+
+        let patch_a = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Move {
+            from: Pointer::new("/id"),
+            to: Pointer::new("/subject/id"),
+        }));
+        let patch_b = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Move {
+            from: Pointer::new("/subject/id"),
+            to: Pointer::new("/id"),
+        }));
+
+        let result = patcher.patch(&phenostr, vec![&patch_a, &patch_b]);
+
+        match result {
+            Err(PatchingError::CircularMoves(cycle)) => {
+                assert_eq!(cycle.len(), 3);
+                assert_eq!(cycle.first(), cycle.last());
+            }
+            other => panic!("Expected circular move error, got {:?}", other),
+        }
+    }
+
+    #[rstest]
+    #[case("/diseases/0", "/diseases/0/excluded")]
+    #[case("/diseases/0/excluded", "/diseases/0")]
+    #[case("/diseases", "/diseases/0/term")]
+    fn test_nested_pointers_conflict(#[case] removed: &str, #[case] replaced: &str) {
+        let patch_a = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Remove {
+            at: Pointer::new(removed),
+        }));
+        let patch_b = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Replace {
+            at: Pointer::new(replaced),
+            value: json!(true),
+        }));
+
+        let result = PatchEngine.patch(&sample_phenopacket(), vec![&patch_a, &patch_b]);
+
+        assert!(matches!(result, Err(PatchingError::ConflictingPatches(_))));
+    }
+
+    #[rstest]
+    fn test_adds_to_same_pointer_conflict() {
+        let add = |label: &str| {
+            Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Add {
+                at: Pointer::new("/subject/karyotypicSex"),
+                value: json!(label),
+            }))
+        };
+        let (patch_a, patch_b) = (add("XX"), add("XY"));
+
+        let result = PatchEngine.patch(&sample_phenopacket(), vec![&patch_a, &patch_b]);
+
+        match result {
+            Err(PatchingError::ConflictingPatches(pointers)) => {
+                assert_eq!(
+                    pointers,
+                    vec![
+                        Pointer::new("/subject/karyotypicSex"),
+                        Pointer::new("/subject/karyotypicSex")
+                    ]
+                );
+            }
+            other => panic!("Expected conflicting patches error, got {:?}", other),
+        }
+    }
+
+    #[rstest]
+    fn test_independent_patches_do_not_conflict() {
+        let patch_a = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Remove {
+            at: Pointer::new("/diseases/0/onset"),
+        }));
+        let patch_b = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Replace {
+            at: Pointer::new("/diseases/0/term/label"),
+            value: json!("Other Disease"),
+        }));
+        let patch_c = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Add {
+            at: Pointer::new("/subject/karyotypicSex"),
+            value: json!("XX"),
+        }));
+
+        let result = PatchEngine
+            .patch(&sample_phenopacket(), vec![&patch_a, &patch_b, &patch_c])
+            .unwrap();
+
+        assert!(result["diseases"][0]["onset"].is_null());
+        assert_eq!(result["diseases"][0]["term"]["label"], "Other Disease");
+        assert_eq!(result["subject"]["karyotypicSex"], "XX");
+    }
+
+    #[rstest]
+    fn test_append_to_existing_array() {
+        let feature = json!({"type": {"id": "HP:0001263", "label": "Global developmental delay"}});
+        let patch = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Add {
+            at: Pointer::at_phenotypes().end_of_array(),
+            value: feature.clone(),
+        }));
+
+        let result = PatchEngine
+            .patch(&sample_phenopacket(), vec![&patch])
+            .unwrap();
+
+        assert_eq!(result["phenotypicFeatures"].as_array().unwrap().len(), 2);
+        assert_eq!(result["phenotypicFeatures"][0]["type"]["id"], "HP:0001250");
+        assert_eq!(result["phenotypicFeatures"][1], feature);
+    }
+
+    #[rstest]
+    fn test_append_creates_missing_array() {
+        let append = |id: &str| {
+            Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Add {
+                at: Pointer::new("/subject/alternateIds/-"),
+                value: json!(id),
+            }))
+        };
+        let (patch_a, patch_b) = (append("alt-1"), append("alt-2"));
+
+        let single = PatchEngine
+            .patch(&sample_phenopacket(), vec![&patch_a])
+            .unwrap();
+        let both = PatchEngine
+            .patch(&sample_phenopacket(), vec![&patch_a, &patch_b])
+            .unwrap();
+
+        assert_eq!(single["subject"]["alternateIds"], json!(["alt-1"]));
+        assert_eq!(both["subject"]["alternateIds"], json!(["alt-1", "alt-2"]));
+    }
+
+    #[rstest]
+    fn test_chained_moves_are_not_circular() {
+        let patch_a = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Move {
+            from: Pointer::new("/a"),
+            to: Pointer::new("/b"),
+        }));
+        let patch_b = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Move {
+            from: Pointer::new("/b"),
+            to: Pointer::new("/c"),
+        }));
+
+        assert!(PatchEngine::find_move_cycle(&[&patch_a, &patch_b]).is_none());
+    }
+
     #[rstest]
     fn test_add_single_field() {
         let patcher = PatchEngine;
@@ -478,6 +771,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_remove_multiple_array_elements() {
+        let patcher = PatchEngine;
+        let phenostr =
+            json!({"alternateIds": ["a", "", "b", "", "c", "d", "e", "f", "g", "h", ""]});
+
+        let patches: Vec<Patch> = [1, 10, 3]
+            .into_iter()
+            .map(|idx| {
+                Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Remove {
+                    at: Pointer::new(&format!("/alternateIds/{idx}")),
+                }))
+            })
+            .collect();
+
+        let result = patcher.patch(&phenostr, patches.iter().collect()).unwrap();
+
+        assert_eq!(
+            result["alternateIds"],
+            json!(["a", "b", "c", "d", "e", "f", "g", "h"])
+        );
+    }
+
     #[test]
     fn test_deeply_nested_add() {
         let patcher = PatchEngine;
@@ -563,6 +879,76 @@ mod tests {
         assert!(result["subject"]["sex"].is_null());
     }
 
+    #[test]
+    fn test_replace_scalar() {
+        let patcher = PatchEngine;
+        let phenostr = sample_phenopacket();
+
+        let patch = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Replace {
+            at: Pointer::new("/phenotypicFeatures/0/type/id"),
+            value: Value::String("HP:0001250".to_string()),
+        }));
+
+        let result = patcher.patch(&phenostr, vec![&patch]).unwrap();
+
+        assert_eq!(result["phenotypicFeatures"][0]["type"]["id"], "HP:0001250");
+        assert_eq!(result["phenotypicFeatures"][0]["type"]["label"], "Seizure");
+    }
+
+    #[test]
+    fn test_replace_nested_object() {
+        let patcher = PatchEngine;
+        let phenostr = sample_phenopacket();
+
+        let patch = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Replace {
+            at: Pointer::new("/diseases/0/term"),
+            value: json!({"id": "MONDO:0005027", "label": "epilepsy"}),
+        }));
+
+        let result = patcher.patch(&phenostr, vec![&patch]).unwrap();
+
+        assert_eq!(
+            result["diseases"][0]["term"],
+            json!({"id": "MONDO:0005027", "label": "epilepsy"})
+        );
+        assert_eq!(result["diseases"][0]["onset"]["age"], "P10Y");
+    }
+
+    #[test]
+    fn test_replace_missing_path() {
+        let patcher = PatchEngine;
+        let phenostr = sample_phenopacket();
+
+        let patch = Patch::new(NonEmptyVec::with_single_entry(PatchInstruction::Replace {
+            at: Pointer::new("/subject/karyotypicSex"),
+            value: Value::String("XY".to_string()),
+        }));
+
+        let result = patcher.patch(&phenostr, vec![&patch]);
+
+        assert!(matches!(result, Err(PatchingError::PatchError(_))));
+    }
+
+    #[test]
+    fn test_patch_ordering_replace_before_remove() {
+        let patcher = PatchEngine;
+        let phenostr = sample_phenopacket();
+
+        let patch = Patch::new(NonEmptyVec::with_rest(
+            PatchInstruction::Remove {
+                at: Pointer::new("/subject"),
+            },
+            vec![PatchInstruction::Replace {
+                at: Pointer::new("/subject/sex"),
+                value: Value::String("FEMALE".to_string()),
+            }],
+        ));
+
+        let result = patcher.patch(&phenostr, vec![&patch]).unwrap();
+
+        assert!(result["subject"].is_null());
+    }
+
     #[test]
     fn test_minimal_phenopacket() {
         let patcher = PatchEngine;
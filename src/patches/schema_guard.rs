@@ -0,0 +1,132 @@
+use crate::diagnostics::LintFinding;
+use crate::error::validation_error_to_string;
+use crate::patches::conflict_policy::PatchConflictPolicy;
+use crate::patches::enums::PatchSafety;
+use crate::patches::error::PatchingError;
+use crate::patches::patch::Patch;
+use crate::patches::patch_engine::PatchEngine;
+use crate::schema_validation::validator::PhenopacketSchemaValidator;
+use serde_json::Value;
+
+/// A patch that was not applied, because the patched phenopacket failed schema validation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RevertedPatch {
+    rule_id: String,
+    patch: Patch,
+    reason: String,
+}
+
+impl RevertedPatch {
+    /// The rule that proposed the reverted patch.
+    pub fn rule_id(&self) -> &str {
+        &self.rule_id
+    }
+
+    pub fn patch(&self) -> &Patch {
+        &self.patch
+    }
+
+    /// The schema violation the patch caused.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// A patch that was not applied, because `PatchSafety` does not allow it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkippedPatch {
+    rule_id: String,
+    patch: Patch,
+}
+
+impl SkippedPatch {
+    /// The rule that proposed the skipped patch.
+    pub fn rule_id(&self) -> &str {
+        &self.rule_id
+    }
+
+    pub fn patch(&self) -> &Patch {
+        &self.patch
+    }
+}
+
+/// Makes sure autofix never produces a phenopacket that fails schema validation.
+pub(crate) struct PatchSchemaGuard;
+
+impl PatchSchemaGuard {
+    /// Applies the patches of all findings, that `safety` allows, and validates the result against
+    /// the phenopacket schema. Without a `validator`, the patches are applied unchecked.
+    ///
+    /// If the result is invalid, the patches are applied one after another and every patch that
+    /// makes the phenopacket invalid is reverted. `values` has to be valid, which holds for every
+    /// phenopacket that got linted.
+    ///
+    /// # Returns
+    ///
+    /// The patched phenopacket, the reverted patches, in the order they were reverted,
+    /// and the patches `safety` skipped.
+    pub(crate) fn patch(
+        engine: &PatchEngine,
+        validator: Option<&PhenopacketSchemaValidator>,
+        values: &Value,
+        findings: &[LintFinding],
+        safety: PatchSafety,
+    ) -> Result<(Value, Vec<RevertedPatch>, Vec<SkippedPatch>), PatchingError> {
+        let mut proposed: Vec<(&str, &Patch)> = vec![];
+        let mut skipped = vec![];
+        for finding in findings {
+            let rule_id = finding.violation().rule_id();
+            for patch in finding.patch() {
+                if safety.allows(patch, values) {
+                    proposed.push((rule_id, patch));
+                } else {
+                    skipped.push(SkippedPatch {
+                        rule_id: rule_id.to_string(),
+                        patch: patch.clone(),
+                    });
+                }
+            }
+        }
+
+        let patched = Self::apply(engine, values, &proposed)?;
+        let Some(validator) = validator else {
+            return Ok((patched, vec![], skipped));
+        };
+        if validator.validate_phenopacket(&patched).is_ok() {
+            return Ok((patched, vec![], skipped));
+        }
+
+        let mut accepted: Vec<(&str, &Patch)> = vec![];
+        let mut reverted = vec![];
+
+        for (rule_id, patch) in proposed {
+            let mut candidate = accepted.clone();
+            candidate.push((rule_id, patch));
+
+            let patched = Self::apply(engine, values, &candidate)?;
+            match validator.validate_phenopacket(&patched) {
+                Ok(()) => accepted.push((rule_id, patch)),
+                Err(err) => reverted.push(RevertedPatch {
+                    rule_id: rule_id.to_string(),
+                    patch: patch.clone(),
+                    reason: format!(
+                        "{} at '{}'",
+                        validation_error_to_string(err.kind()),
+                        err.instance_path()
+                    ),
+                }),
+            }
+        }
+
+        Ok((Self::apply(engine, values, &accepted)?, reverted, skipped))
+    }
+
+    fn apply(
+        engine: &PatchEngine,
+        values: &Value,
+        patches: &[(&str, &Patch)],
+    ) -> Result<Value, PatchingError> {
+        let merged = PatchConflictPolicy::merge_by_rule(patches.iter().copied());
+        engine.patch(values, merged.iter().collect())
+    }
+}
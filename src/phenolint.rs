@@ -1,19 +1,27 @@
 use crate::LinterContext;
 use crate::diagnostics::enums::PhenopacketData;
-use crate::diagnostics::{LintFinding, LintReport};
+use crate::diagnostics::{Baseline, LintFinding, LintReport, LintViolation};
 use crate::enums::InputTypes;
 use crate::error::{InitError, LintResult, LinterError, ParsingError, validation_error_to_string};
+use crate::filename_check::FilenameIdCheck;
 use crate::materializer::NodeMaterializer;
 use crate::parsing::phenopacket_parser::PhenopacketParser;
+use crate::parsing::stringified_booleans::parse_stringified_booleans;
+use crate::patches::conflict_policy::PatchConflictPolicy;
+use crate::patches::enums::PatchSafety;
 use crate::patches::patch_engine::PatchEngine;
 use crate::patches::patch_registry::PatchRegistry;
+use crate::patches::schema_guard::PatchSchemaGuard;
+use crate::report::enums::ViolationSeverity;
 use crate::report::renderer::ReportRenderer;
 use crate::report::report_registry::ReportRegistry;
+use crate::rules::enums::RuleScope;
 use crate::rules::rule_registry::{RuleRegistry, check_duplicate_rule_ids};
+use crate::rules::traits::LintRule;
 use crate::schema_validation::validator::PhenopacketSchemaValidator;
-use crate::traits::Lint;
+use crate::traits::{Lint, RuleObserver};
 use crate::tree::abstract_pheno_tree::AbstractTreeTraversal;
-use crate::tree::node::DynamicNode;
+use crate::tree::node::{DynamicNode, MaterializedNode};
 use crate::tree::node_repository::NodeRepository;
 use crate::tree::pointer::Pointer;
 use log::{error, warn};
@@ -21,8 +29,27 @@ use phenopackets::schema::v2::Phenopacket;
 use prost::Message;
 use serde_json::Value;
 
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::io::{self, BufRead};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// `id` of the external reference in `metaData.externalReferences`, through which a phenopacket
+/// opts out of rules. Its `description` lists the rule ids, separated by commas or whitespace.
+///
+/// The phenopacket schema does not allow additional fields, so the opt-out is stored as an
+/// external reference to stay valid in all input formats.
+pub const DISABLE_RULES_REFERENCE_ID: &str = "phenolint:disable";
+
+/// `id` of the external reference in `metaData.externalReferences`, through which a phenopacket
+/// suppresses findings. Its `description` lists rule ids, separated by commas or whitespace.
+/// A rule id may be scoped to a JSON pointer prefix, e.g. `PF006@/phenotypicFeatures/2`.
+///
+/// Unlike `DISABLE_RULES_REFERENCE_ID`, the rules still run. Their findings are moved to
+/// `LintReport::suppressed` instead of being reported.
+pub const IGNORE_FINDINGS_REFERENCE_ID: &str = "phenolint:ignore";
 
 pub struct Phenolint {
     rule_registry: RuleRegistry,
@@ -31,9 +58,22 @@ pub struct Phenolint {
     node_materializer: NodeMaterializer,
     patch_engine: PatchEngine,
     validator: PhenopacketSchemaValidator,
+    fail_fast: bool,
+    max_findings: Option<usize>,
+    filename_id_check: bool,
+    severity_overrides: HashMap<String, ViolationSeverity>,
+    patch_safety: PatchSafety,
+    schema_validation: bool,
+    baseline: Baseline,
+    observer: Option<Box<dyn RuleObserver>>,
 }
 
 impl Phenolint {
+    /// Returns a `PhenolintBuilder` to configure a linter without a config file.
+    pub fn builder() -> PhenolintBuilder {
+        PhenolintBuilder::default()
+    }
+
     pub fn new(context: LinterContext, rule_ids: Vec<String>) -> Self {
         check_duplicate_rule_ids();
 
@@ -48,80 +88,453 @@ impl Phenolint {
             node_materializer: NodeMaterializer,
             patch_engine: PatchEngine,
             validator: PhenopacketSchemaValidator::default(),
+            fail_fast: context.fail_fast(),
+            max_findings: context.max_findings(),
+            filename_id_check: context.filename_id_check(),
+            severity_overrides: context
+                .severity_overrides()
+                .iter()
+                .map(|(rule_id, severity)| (rule_id.to_uppercase(), severity.clone()))
+                .collect(),
+            patch_safety: context.patch_safety(),
+            schema_validation: context.schema_validation(),
+            baseline: context.baseline().clone(),
+            observer: None,
         }
     }
-}
 
-impl Lint<str> for Phenolint {
-    fn lint(&mut self, phenostr: &str, patch: bool, quiet: bool) -> LintResult {
+    /// Reports the start and end of every rule to `observer` from now on.
+    pub fn set_observer(&mut self, observer: Box<dyn RuleObserver>) {
+        self.observer = Some(observer);
+    }
+
+    /// Lints the cohort in `cohortstr` with the enabled cohort rules, see `RuleScope::Cohort`.
+    ///
+    /// The cohort is validated against the cohort schema. Phenopacket rules do not run on the
+    /// members, lint each member with `Lint::lint` for that. Patches are not applied.
+    pub fn lint_cohort(&mut self, cohortstr: &str, quiet: bool) -> LintResult {
         let mut report = LintReport::default();
 
-        let (values, spans, input_type) = match PhenopacketParser::to_abstract_tree(phenostr) {
+        let (values, spans, input_type) = match PhenopacketParser::to_abstract_tree(cohortstr) {
             Ok(data) => data,
             Err(err) => return LintResult::err(LinterError::ParsingError(err)),
         };
+        report.set_input_type(input_type);
 
-        if let Err(err) = self.validator.validate_phenopacket(&values) {
-            return LintResult::err(LinterError::InvalidPhenopacket {
-                path: err.instance_path().to_string(),
-                reason: validation_error_to_string(err.kind()),
-            });
+        if self.schema_validation
+            && let Err(err) = self.validator.validate_cohort(&values)
+        {
+            return LintResult::partial(
+                report,
+                LinterError::InvalidPhenopacket {
+                    path: err.instance_path().to_string(),
+                    reason: validation_error_to_string(err.kind()),
+                },
+            );
         }
 
         let root_node = DynamicNode::new(&values, &spans, Pointer::at_root());
+        let node_repo = self.build_node_repository(values, spans);
+
+        let findings: Vec<LintFinding> = self
+            .rule_registry
+            .rules()
+            .filter(|rule| rule.scope() == RuleScope::Cohort)
+            .flat_map(|rule| self.run_rule(rule.as_ref(), &node_repo))
+            .map(|violation| self.compile_finding(&root_node, violation))
+            .collect();
+        report.extend_finding(findings);
+
+        if !quiet {
+            emit_reports(&report, cohortstr, &root_node);
+        }
+
+        LintResult::ok(report)
+    }
+
+    /// Returns the ids of all enabled rules that consume the node at `ptr`, sorted alphabetically.
+    ///
+    /// Meant for editor tooling, which wants to know what could be reported at the cursor.
+    /// Rules that take the whole phenopacket, e.g. as `Single<Phenopacket>`, only match the root.
+    pub fn rules_run_on(
+        &mut self,
+        phenostr: &str,
+        ptr: &Pointer,
+    ) -> Result<Vec<String>, ParsingError> {
+        let (values, spans, _) = PhenopacketParser::to_abstract_tree(phenostr)?;
+        let node_repo = self.build_node_repository(values, spans);
+        let node_types = node_repo.node_types_at(ptr);
+
+        let mut rule_ids: Vec<String> = self
+            .rule_registry
+            .rules()
+            .filter(|rule| {
+                rule.node_types()
+                    .iter()
+                    .any(|node_type| node_types.contains(node_type))
+            })
+            .map(|rule| rule.rule_id().to_string())
+            .collect();
+        rule_ids.sort();
 
-        let apt = AbstractTreeTraversal::new(values, spans);
-        let mut node_repo: NodeRepository = NodeRepository::new();
+        Ok(rule_ids)
+    }
+
+    /// Re-lints the phenopacket in `phenostr` after it was changed at the `changed` pointers,
+    /// e.g. by a fix of an editor.
+    ///
+    /// Only rules consuming nodes at, above or below a changed pointer run, so the findings of
+    /// all other rules are still valid. A selected rule runs on all of its nodes, so rules with
+    /// dependencies between nodes, like `DiseaseConsistencyRule`, still see the whole phenopacket.
+    /// Rules taking the whole phenopacket, e.g. as `Single<Phenopacket>`, always run.
+    /// For removed values, pass the pointer of their parent, e.g. the array they were removed from.
+    ///
+    /// Patches are not applied and reports are not rendered.
+    pub fn lint_incremental(&mut self, phenostr: &str, changed: &[Pointer]) -> LintResult {
+        self.lint_changed(phenostr, None, true, Some(changed), None)
+    }
+
+    /// Runs `rule`, timing it only if an observer is set.
+    fn run_rule(&self, rule: &dyn LintRule, node_repo: &NodeRepository) -> Vec<LintViolation> {
+        let Some(observer) = &self.observer else {
+            return rule.check_erased(node_repo);
+        };
+
+        observer.on_rule_start(rule.rule_id());
+        let start = Instant::now();
+        let violations = rule.check_erased(node_repo);
+        observer.on_rule_end(rule.rule_id(), violations.len(), start.elapsed());
+
+        violations
+    }
+
+    /// Compiles the patches and the report of `violation` and applies the configured severity.
+    fn compile_finding(&self, root_node: &DynamicNode, violation: LintViolation) -> LintFinding {
+        let patches =
+            self.patch_registry
+                .get_patches_for(violation.rule_id(), root_node, &violation);
+        let report_specs = self.report_registry.get_report_for(root_node, &violation);
+
+        let mut finding = LintFinding::new(violation, patches, report_specs);
+        self.override_severity(&mut finding);
+        finding
+    }
+
+    fn override_severity(&self, finding: &mut LintFinding) {
+        if let Some(severity) = self.severity_overrides.get(finding.violation().rule_id()) {
+            finding.set_severity(severity.clone());
+        }
+    }
+
+    /// Materializes the nodes of the phenopacket.
+    ///
+    /// Typed nodes are parsed with stringified booleans normalized, so e.g. a feature with
+    /// `"excluded": "true"` is not lost to the other rules. The root is kept as the raw JSON,
+    /// for rules that look at the phenopacket as it was written.
+    fn build_node_repository(
+        &mut self,
+        values: Value,
+        spans: HashMap<Pointer, Range<usize>>,
+    ) -> NodeRepository {
+        let mut node_repo = NodeRepository::new();
+        node_repo.insert(MaterializedNode::new(
+            values.clone(),
+            spans.clone(),
+            Pointer::at_root(),
+        ));
+
+        let apt =
+            AbstractTreeTraversal::new(parse_stringified_booleans(&values).into_owned(), spans);
 
         for node in apt.traverse() {
             self.node_materializer
                 .materialize_nodes(&node, &mut node_repo)
         }
 
-        let mut findings = vec![];
-        for rule in self.rule_registry.rules() {
-            let violations = rule.check_erased(&node_repo);
+        node_repo
+    }
 
-            for violation in violations {
-                let patches =
-                    self.patch_registry
-                        .get_patches_for(rule.rule_id(), &root_node, &violation);
+    /// Lints the phenopacket at `input_path` and writes it to `output_path`.
+    ///
+    /// With `patch`, the phenopacket patched with the safe fixes only, see `PatchSafety::SafeOnly`,
+    /// is written in the format of the input. Otherwise the input is copied unchanged.
+    /// The input file is never modified, so `output_path` must not point to it.
+    /// Reports are not rendered, they are part of the returned `LintResult`.
+    /// Nothing is written if linting failed.
+    pub fn lint_and_write(
+        &mut self,
+        input_path: &Path,
+        output_path: &Path,
+        patch: bool,
+    ) -> LintResult {
+        if let (Ok(canonical_input), Ok(canonical_output)) =
+            (fs::canonicalize(input_path), fs::canonicalize(output_path))
+            && canonical_input == canonical_output
+        {
+            return LintResult::err(LinterError::InitError(InitError::IO(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Refusing to overwrite the linted file '{}'",
+                    canonical_input.display()
+                ),
+            ))));
+        }
 
-                findings.push(LintFinding::new(violation, patches));
-            }
+        let mut lint_result =
+            self.lint_path(input_path, patch.then_some(PatchSafety::SafeOnly), true);
+        if lint_result.error.is_some() {
+            return lint_result;
         }
 
-        report.extend_finding(findings);
+        let write_result = match &lint_result.report.patched_phenopacket {
+            Some(PhenopacketData::Binary(phenobytes)) => fs::write(output_path, phenobytes),
+            Some(PhenopacketData::Text(phenostr)) => fs::write(output_path, phenostr),
+            None => fs::copy(input_path, output_path).map(|_| ()),
+        };
 
-        if !quiet {
-            let phenopacket_id = root_node
-                .inner
-                .get("id")
-                .expect("Phenopacket should have ID")
-                .as_str()
-                .unwrap();
-
-            for violation in report.violations() {
-                let renderable_report = self.report_registry.get_report_for(&root_node, violation);
-
-                if renderable_report.is_none() {
-                    continue;
-                }
+        if let Err(err) = write_result {
+            lint_result.error = Some(LinterError::InitError(InitError::IO(err)));
+        }
+
+        lint_result
+    }
+
+    /// Lints newline-delimited phenopackets (JSONL) from `reader`, one phenopacket per line.
+    ///
+    /// Yields the 1-based line number together with the `LintResult` of that line. Blank lines are
+    /// skipped. A line that can not be read or parsed only fails its own result, the stream continues.
+    pub fn lint_stream<R: BufRead>(
+        &mut self,
+        reader: R,
+        patch: bool,
+        quit: bool,
+    ) -> impl Iterator<Item = (usize, LintResult)> {
+        reader.lines().enumerate().filter_map(move |(idx, line)| {
+            let lint_result = match line {
+                Ok(line) if line.trim().is_empty() => return None,
+                Ok(line) => self.lint(line.as_str(), patch, quit),
+                Err(err) => LintResult::err(LinterError::InitError(InitError::IO(err))),
+            };
+            Some((idx + 1, lint_result))
+        })
+    }
+}
+
+/// Builds a `Phenolint` in code, as an alternative to a config file.
+///
+/// # Example
+/// ```ignore
+/// let linter = Phenolint::builder()
+///     .with_rule("INTER001")
+///     .with_hpo_path(PathBuf::from("hp.json"))
+///     .build();
+/// ```
+#[derive(Debug, Default)]
+pub struct PhenolintBuilder {
+    context: LinterContext,
+    rule_ids: Vec<String>,
+}
+
+impl PhenolintBuilder {
+    /// Enables the rule with the given id.
+    pub fn with_rule(mut self, rule_id: impl ToString) -> Self {
+        self.rule_ids.push(rule_id.to_string());
+        self
+    }
+
+    /// Enables all rules with the given ids.
+    pub fn with_rules<S: ToString>(mut self, rule_ids: impl IntoIterator<Item = S>) -> Self {
+        self.rule_ids
+            .extend(rule_ids.into_iter().map(|rule_id| rule_id.to_string()));
+        self
+    }
+
+    /// Path of the HPO, which rules that walk the ontology need.
+    pub fn with_hpo_path(mut self, hpo_path: PathBuf) -> Self {
+        self.context = self.context.with_hpo_path(Some(hpo_path));
+        self
+    }
+
+    /// Path of the GENO, which rules on genotype terms need.
+    pub fn with_geno_path(mut self, geno_path: PathBuf) -> Self {
+        self.context = self.context.with_geno_path(Some(geno_path));
+        self
+    }
+
+    /// See `LinterContext::with_schema_validation`.
+    pub fn with_schema_validation(mut self, schema_validation: bool) -> Self {
+        self.context = self.context.with_schema_validation(schema_validation);
+        self
+    }
+
+    /// Replaces the context, for all options without a method of their own.
+    /// The ontology paths and schema validation set before are replaced, too.
+    pub fn with_context(mut self, context: LinterContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    pub fn build(self) -> Phenolint {
+        Phenolint::new(self.context, self.rule_ids)
+    }
+}
+
+impl Lint<str> for Phenolint {
+    fn lint(&mut self, phenostr: &str, patch: bool, quiet: bool) -> LintResult {
+        self.lint_changed(
+            phenostr,
+            patch.then_some(self.patch_safety),
+            quiet,
+            None,
+            None,
+        )
+    }
+}
+
+impl Phenolint {
+    /// Lints the phenopacket in `phenostr` and applies the patches allowed by `patch`, if given.
+    /// With `changed`, only the rules consuming nodes affected by the changed pointers run,
+    /// see `Phenolint::lint_incremental`.
+    /// With `file_stem`, the name of the linted file is compared with the phenopacket id,
+    /// see `FilenameIdCheck`.
+    fn lint_changed(
+        &mut self,
+        phenostr: &str,
+        patch: Option<PatchSafety>,
+        quiet: bool,
+        changed: Option<&[Pointer]>,
+        file_stem: Option<&str>,
+    ) -> LintResult {
+        let mut report = LintReport::default();
+
+        let (values, spans, input_type) = match PhenopacketParser::to_abstract_tree(phenostr) {
+            Ok(data) => data,
+            Err(err) => return LintResult::err(LinterError::ParsingError(err)),
+        };
+        report.set_input_type(input_type);
+
+        if self.schema_validation
+            && let Err(err) = self.validator.validate_phenopacket(&values)
+        {
+            return LintResult::partial(
+                report,
+                LinterError::InvalidPhenopacket {
+                    path: err.instance_path().to_string(),
+                    reason: validation_error_to_string(err.kind()),
+                },
+            );
+        }
+
+        let root_node = DynamicNode::new(&values, &spans, Pointer::at_root());
+
+        let disabled_rules = packet_disabled_rules(&root_node.inner);
+        let ignore_directives = packet_ignore_directives(&root_node.inner);
+        let node_repo = self.build_node_repository(values, spans);
+        let affected_types = changed.map(|changed| node_repo.node_types_affected_by(changed));
+
+        let compile_finding = |violation: LintViolation| match file_stem {
+            Some(file_stem) if violation.rule_id() == FilenameIdCheck::RULE_ID => {
+                let report_specs =
+                    FilenameIdCheck::compile_report(file_stem, &root_node, &violation);
+                let mut finding = LintFinding::new(violation, vec![], report_specs);
+                self.override_severity(&mut finding);
+                finding
+            }
+            _ => self.compile_finding(&root_node, violation),
+        };
+
+        let rule_violations = self
+            .rule_registry
+            .rules()
+            .filter(|rule| {
+                rule.scope() == RuleScope::Phenopacket && !disabled_rules.contains(rule.rule_id())
+            })
+            .filter(|rule| {
+                affected_types.as_ref().is_none_or(|affected_types| {
+                    rule.node_types()
+                        .iter()
+                        .any(|node_type| affected_types.contains(node_type))
+                })
+            })
+            .map(|rule| self.run_rule(rule.as_ref(), &node_repo));
+        // NAME001 is not a regular rule, but its finding is filtered the same way.
+        let filename_violations = file_stem
+            .filter(|_| !disabled_rules.contains(FilenameIdCheck::RULE_ID))
+            .and_then(|file_stem| FilenameIdCheck::check(file_stem, &root_node))
+            .map(|violation| vec![violation]);
 
-                if ReportRenderer::emit(&renderable_report.unwrap(), phenostr, phenopacket_id)
-                    .is_err()
+        let mut findings = vec![];
+        'rules: for violations in rule_violations.chain(filename_violations) {
+            let (ignored, violations): (Vec<_>, Vec<_>) =
+                violations.into_iter().partition(|violation| {
+                    ignore_directives
+                        .iter()
+                        .any(|directive| directive.matches(violation))
+                });
+
+            for violation in ignored {
+                report.push_suppressed(compile_finding(violation));
+            }
+
+            let (baselined, mut violations): (Vec<_>, Vec<_>) = violations
+                .into_iter()
+                .partition(|violation| self.baseline.contains(violation));
+
+            for violation in baselined {
+                report.push_baselined(compile_finding(violation));
+            }
+
+            if self.fail_fast && !violations.is_empty() {
+                findings.push(compile_finding(violations.remove(0)));
+                break 'rules;
+            }
+
+            for violation in violations {
+                if self
+                    .max_findings
+                    .is_some_and(|max_findings| findings.len() >= max_findings)
                 {
                     warn!(
-                        "Unable to parse and emit report for '{}'",
-                        violation.rule_id()
+                        "Reached the maximum of {} findings. Remaining findings are skipped.",
+                        findings.len()
                     );
+                    report.set_truncated();
+                    break 'rules;
                 }
+
+                findings.push(compile_finding(violation));
             }
         }
 
-        if patch & report.has_patches() {
-            match self.patch_engine.patch(&root_node.inner, report.patches()) {
-                Ok(patched_phenopacket) => {
+        let mut findings = group_related_findings(findings);
+        report.extend_suppressed_patches(PatchConflictPolicy::resolve(&mut findings));
+        report.extend_finding(findings);
+
+        if !quiet {
+            emit_reports(&report, phenostr, &root_node);
+        }
+
+        if let Some(patch_safety) = patch
+            && report.has_patches()
+        {
+            match PatchSchemaGuard::patch(
+                &self.patch_engine,
+                self.schema_validation.then_some(&self.validator),
+                &root_node.inner,
+                report.findings(),
+                patch_safety,
+            ) {
+                Ok((patched_phenopacket, reverted_patches, skipped_patches)) => {
+                    for reverted in reverted_patches.iter() {
+                        warn!(
+                            "Reverted patch of '{}', because it breaks the phenopacket schema: {}",
+                            reverted.rule_id(),
+                            reverted.reason()
+                        );
+                    }
+                    report.extend_reverted_patches(reverted_patches);
+                    report.extend_skipped_patches(skipped_patches);
+
                     match convert_phenopacket_to_input_type_str(&patched_phenopacket, input_type) {
                         Ok(phenostr) => {
                             report.patched_phenopacket = Some(phenostr);
@@ -143,6 +556,23 @@ impl Lint<str> for Phenolint {
 
 impl Lint<PathBuf> for Phenolint {
     fn lint(&mut self, phenopath: &PathBuf, patch: bool, quit: bool) -> LintResult {
+        self.lint_path(phenopath, patch.then_some(self.patch_safety), quit)
+    }
+}
+
+impl Lint<[u8]> for Phenolint {
+    fn lint(&mut self, phenodata: &[u8], patch: bool, quit: bool) -> LintResult {
+        self.lint_bytes(phenodata, patch.then_some(self.patch_safety), quit, None)
+    }
+}
+
+impl Phenolint {
+    fn lint_path(
+        &mut self,
+        phenopath: &Path,
+        patch: Option<PatchSafety>,
+        quit: bool,
+    ) -> LintResult {
         let phenodata = match fs::read(phenopath) {
             Ok(phenodata) => phenodata,
             Err(err) => {
@@ -150,12 +580,21 @@ impl Lint<PathBuf> for Phenolint {
             }
         };
 
-        self.lint(phenodata.as_slice(), patch, quit)
+        let file_stem = phenopath
+            .file_stem()
+            .filter(|_| self.filename_id_check)
+            .map(|stem| stem.to_string_lossy().to_string());
+
+        self.lint_bytes(phenodata.as_slice(), patch, quit, file_stem.as_deref())
     }
-}
 
-impl Lint<[u8]> for Phenolint {
-    fn lint(&mut self, phenodata: &[u8], patch: bool, quit: bool) -> LintResult {
+    fn lint_bytes(
+        &mut self,
+        phenodata: &[u8],
+        patch: Option<PatchSafety>,
+        quit: bool,
+        file_stem: Option<&str>,
+    ) -> LintResult {
         let (phenostr, input_type) = match PhenopacketParser::to_string(phenodata) {
             Ok(phenostr) => phenostr,
             Err(err) => {
@@ -163,7 +602,8 @@ impl Lint<[u8]> for Phenolint {
             }
         };
 
-        let mut lint_result = self.lint(phenostr.as_str(), patch, quit);
+        let mut lint_result = self.lint_changed(phenostr.as_str(), patch, quit, None, file_stem);
+        lint_result.report.set_input_type(input_type);
 
         convert_phenopacket_to_input_type_u8(&mut lint_result, input_type);
 
@@ -171,18 +611,172 @@ impl Lint<[u8]> for Phenolint {
     }
 }
 
+/// Lints a phenopacket that was already parsed, without a detour through the input formats.
+///
+/// The phenopacket is serialized to JSON once, which is what spans of reports refer to
+/// and what a patched phenopacket is returned as.
+impl Lint<Phenopacket> for Phenolint {
+    fn lint(&mut self, phenopacket: &Phenopacket, patch: bool, quit: bool) -> LintResult {
+        let phenostr = match serde_json::to_string_pretty(phenopacket) {
+            Ok(phenostr) => phenostr,
+            Err(err) => {
+                return LintResult::err(LinterError::ParsingError(ParsingError::JsonError(err)));
+            }
+        };
+
+        self.lint(phenostr.as_str(), patch, quit)
+    }
+}
+
+/// Renders the reports of all findings for the phenopacket, or cohort, in `phenostr`.
+fn emit_reports(report: &LintReport, phenostr: &str, root_node: &DynamicNode) {
+    // Without schema validation, the phenopacket may lack an id.
+    let phenopacket_id = root_node
+        .inner
+        .get("id")
+        .and_then(Value::as_str)
+        .unwrap_or("<phenopacket without id>");
+
+    for finding in report.findings() {
+        let Some(renderable_report) = finding.report() else {
+            continue;
+        };
+
+        if ReportRenderer::emit(renderable_report, phenostr, phenopacket_id).is_err() {
+            warn!(
+                "Unable to parse and emit report for '{}'",
+                finding.violation().rule_id()
+            );
+        }
+    }
+}
+
+/// Collects the rule ids a phenopacket opted out of via `DISABLE_RULES_REFERENCE_ID`.
+fn packet_disabled_rules(phenopacket: &Value) -> HashSet<String> {
+    let Some(references) = phenopacket
+        .pointer("/metaData/externalReferences")
+        .and_then(Value::as_array)
+    else {
+        return HashSet::new();
+    };
+
+    references
+        .iter()
+        .filter(|reference| {
+            reference.get("id").and_then(Value::as_str) == Some(DISABLE_RULES_REFERENCE_ID)
+        })
+        .filter_map(|reference| reference.get("description").and_then(Value::as_str))
+        .flat_map(|description| description.split(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|rule_id| !rule_id.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// A rule id from `IGNORE_FINDINGS_REFERENCE_ID`, optionally scoped to a pointer prefix.
+#[derive(Debug, Clone, PartialEq)]
+struct IgnoreDirective {
+    rule_id: String,
+    prefix: Option<Pointer>,
+}
+
+impl IgnoreDirective {
+    fn parse(entry: &str) -> Self {
+        match entry.split_once('@') {
+            Some((rule_id, prefix)) => IgnoreDirective {
+                rule_id: rule_id.to_string(),
+                prefix: Some(Pointer::new(prefix)),
+            },
+            None => IgnoreDirective {
+                rule_id: entry.to_string(),
+                prefix: None,
+            },
+        }
+    }
+
+    /// A scoped directive matches, if any pointer of the violation is located at or below its prefix.
+    fn matches(&self, violation: &LintViolation) -> bool {
+        violation.rule_id() == self.rule_id
+            && self.prefix.as_ref().is_none_or(|prefix| {
+                violation
+                    .at()
+                    .iter()
+                    .any(|ptr| ptr.relative_to(prefix).is_some())
+            })
+    }
+}
+
+/// Collects the findings a phenopacket suppresses via `IGNORE_FINDINGS_REFERENCE_ID`.
+fn packet_ignore_directives(phenopacket: &Value) -> Vec<IgnoreDirective> {
+    let Some(references) = phenopacket
+        .pointer("/metaData/externalReferences")
+        .and_then(Value::as_array)
+    else {
+        return vec![];
+    };
+
+    references
+        .iter()
+        .filter(|reference| {
+            reference.get("id").and_then(Value::as_str) == Some(IGNORE_FINDINGS_REFERENCE_ID)
+        })
+        .filter_map(|reference| reference.get("description").and_then(Value::as_str))
+        .flat_map(|description| description.split(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|entry| !entry.is_empty())
+        .map(IgnoreDirective::parse)
+        .collect()
+}
+
+/// Reorders findings, so that findings sharing a pointer are placed next to each other.
+///
+/// Rules run independently of each other. Without grouping, e.g. a duplicated phenotype that
+/// is also a redundant ancestor would be reported as two unrelated findings far apart.
+/// The order of unrelated findings is preserved.
+fn group_related_findings(findings: Vec<LintFinding>) -> Vec<LintFinding> {
+    let mut remaining: Vec<Option<LintFinding>> = findings.into_iter().map(Some).collect();
+    let mut grouped = Vec::with_capacity(remaining.len());
+
+    for idx in 0..remaining.len() {
+        let Some(finding) = remaining[idx].take() else {
+            continue;
+        };
+        let mut group_ptrs: Vec<Pointer> = finding.violation().at().to_vec();
+        grouped.push(finding);
+
+        for candidate in remaining.iter_mut().skip(idx + 1) {
+            let is_related = candidate.as_ref().is_some_and(|candidate| {
+                candidate
+                    .violation()
+                    .at()
+                    .iter()
+                    .any(|ptr| group_ptrs.contains(ptr))
+            });
+
+            if is_related && let Some(related) = candidate.take() {
+                group_ptrs.extend(related.violation().at().iter().cloned());
+                grouped.push(related);
+            }
+        }
+    }
+
+    grouped
+}
+
+/// Serializes the patched phenopacket in the input format.
+///
+/// The output is stable across runs, as `serde_json` is used without `preserve_order`,
+/// so objects serialize with their keys in lexicographic order.
 fn convert_phenopacket_to_input_type_str(
     patched_phenopacket: &Value,
     input_type: InputTypes,
 ) -> Result<PhenopacketData, ParsingError> {
     match input_type {
         InputTypes::Json | InputTypes::Protobuf => {
-            match serde_json::to_string_pretty(&patched_phenopacket) {
+            match serde_json::to_string_pretty(patched_phenopacket) {
                 Ok(patched_phenostr) => Ok(PhenopacketData::Text(patched_phenostr)),
                 Err(err) => Err(ParsingError::JsonError(err)),
             }
         }
-        InputTypes::Yaml => match serde_yaml::to_string(&patched_phenopacket) {
+        InputTypes::Yaml => match serde_yaml::to_string(patched_phenopacket) {
             Ok(patched_phenostr) => Ok(PhenopacketData::Text(patched_phenostr)),
             Err(err) => Err(ParsingError::YamlError(err)),
         },
@@ -224,3 +818,96 @@ fn convert_phenopacket_to_input_type_u8(lint_result: &mut LintResult, input_type
         lint_result.report.patched_phenopacket = Some(new_data);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        IgnoreDirective, group_related_findings, packet_disabled_rules, packet_ignore_directives,
+    };
+    use crate::diagnostics::{LintFinding, LintViolation};
+    use crate::helper::NonEmptyVec;
+    use crate::report::enums::ViolationSeverity;
+    use crate::tree::pointer::Pointer;
+    use rstest::rstest;
+    use serde_json::json;
+
+    #[test]
+    fn test_packet_disabled_rules() {
+        let phenopacket = json!({"metaData": {"externalReferences": [
+            {"id": "PMID:1234", "description": "PF006"},
+            {"id": "phenolint:disable", "description": "PF006, CURIE001 INTER002"}
+        ]}});
+
+        let mut disabled: Vec<String> = packet_disabled_rules(&phenopacket).into_iter().collect();
+        disabled.sort();
+
+        assert_eq!(disabled, vec!["CURIE001", "INTER002", "PF006"]);
+    }
+
+    #[test]
+    fn test_packet_ignore_directives() {
+        let phenopacket = json!({"metaData": {"externalReferences": [
+            {"id": "phenolint:ignore", "description": "CURIE001, PF006@/phenotypicFeatures/2"}
+        ]}});
+
+        let directives = packet_ignore_directives(&phenopacket);
+
+        assert_eq!(
+            directives,
+            vec![
+                IgnoreDirective {
+                    rule_id: "CURIE001".to_string(),
+                    prefix: None,
+                },
+                IgnoreDirective {
+                    rule_id: "PF006".to_string(),
+                    prefix: Some(Pointer::new("/phenotypicFeatures/2")),
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("PF006", "/phenotypicFeatures/2/type", true)]
+    #[case("PF006@/phenotypicFeatures/2", "/phenotypicFeatures/2/type", true)]
+    #[case("PF006@/phenotypicFeatures/2", "/phenotypicFeatures/20/type", false)]
+    #[case("PF007@/phenotypicFeatures/2", "/phenotypicFeatures/2/type", false)]
+    fn test_ignore_directive_matches(#[case] entry: &str, #[case] at: &str, #[case] matches: bool) {
+        let violation = LintViolation::new(
+            ViolationSeverity::Warning,
+            "PF006",
+            NonEmptyVec::with_single_entry(Pointer::new(at)),
+        );
+
+        assert_eq!(IgnoreDirective::parse(entry).matches(&violation), matches);
+    }
+
+    #[test]
+    fn test_group_related_findings() {
+        let finding = |rule_id: &str, ptrs: &[&str]| {
+            let ptrs: Vec<Pointer> = ptrs.iter().map(|ptr| Pointer::new(ptr)).collect();
+            LintFinding::new(
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    rule_id,
+                    NonEmptyVec::with_rest(ptrs[0].clone(), ptrs[1..].to_vec()),
+                ),
+                vec![],
+                None,
+            )
+        };
+
+        let findings = vec![
+            finding("PF006", &["/phenotypicFeatures/2", "/phenotypicFeatures/0"]),
+            finding("CURIE001", &["/diseases/0/term/id"]),
+            finding("PF007", &["/phenotypicFeatures/0", "/phenotypicFeatures/1"]),
+        ];
+
+        let rule_ids: Vec<String> = group_related_findings(findings)
+            .iter()
+            .map(|finding| finding.violation().rule_id().to_string())
+            .collect();
+
+        assert_eq!(rule_ids, vec!["PF006", "PF007", "CURIE001"]);
+    }
+}
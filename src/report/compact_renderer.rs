@@ -0,0 +1,84 @@
+use crate::diagnostics::{LintFinding, LintReport};
+
+/// Renders findings in a grep-friendly format with one line per finding:
+///
+/// `path:rule_id:severity:message`
+///
+/// Findings without a compiled report fall back to the location of the violation as message.
+#[derive(Default)]
+pub struct CompactRenderer;
+
+impl CompactRenderer {
+    /// Renders all findings of the report, separated by newlines.
+    ///
+    /// # Arguments
+    /// * `report` - The report whose findings should be rendered
+    /// * `path` - The path of the linted phenopacket, used as first column
+    pub fn render(report: &LintReport, path: &str) -> String {
+        report
+            .findings()
+            .iter()
+            .map(|finding| Self::render_finding(finding, path))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn render_finding(finding: &LintFinding, path: &str) -> String {
+        let violation = finding.violation();
+        let message = match finding.report() {
+            Some(report_specs) => report_specs.message().replace(['\n', '\r'], " "),
+            None => format!("at {}", violation.first_at()),
+        };
+
+        format!(
+            "{}:{}:{}:{}",
+            path,
+            violation.rule_id(),
+            violation.severity(),
+            message
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::LintViolation;
+    use crate::helper::NonEmptyVec;
+    use crate::report::enums::ViolationSeverity;
+    use crate::report::specs::ReportSpecs;
+    use crate::tree::pointer::Pointer;
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_render() {
+        let violation = LintViolation::new(
+            ViolationSeverity::Warning,
+            "INTER001",
+            NonEmptyVec::with_single_entry(Pointer::new("/interpretations/0/diagnosis/disease")),
+        );
+        let report_specs = ReportSpecs::from_violation(
+            &violation,
+            "Found disease in interpretation".to_string(),
+            vec![],
+            vec![],
+        );
+
+        let mut report = LintReport::new();
+        report.push_finding(LintFinding::new(violation, vec![], Some(report_specs)));
+        report.push_finding(LintFinding::new(
+            LintViolation::new(
+                ViolationSeverity::Info,
+                "CUST001",
+                NonEmptyVec::with_single_entry(Pointer::new("id")),
+            ),
+            vec![],
+            None,
+        ));
+
+        assert_eq!(
+            CompactRenderer::render(&report, "pp.json"),
+            "pp.json:INTER001:warning:Found disease in interpretation\npp.json:CUST001:info:at /id"
+        );
+    }
+}
@@ -1,7 +1,11 @@
+use annotate_snippets::{AnnotationKind, Level};
 use codespan_reporting::diagnostic::{Diagnostic, Label};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 use std::ops::Range;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ViolationSeverity {
     /// Critical issues that will cause failures (e.g. runtime crashes,
     /// or contract violations that break dependent code)
@@ -20,9 +24,28 @@ impl ViolationSeverity {
             ViolationSeverity::Info => Diagnostic::help(),
         }
     }
+
+    pub(crate) fn as_annotate_level(&self) -> Level<'static> {
+        match self {
+            ViolationSeverity::Error => Level::ERROR,
+            ViolationSeverity::Warning => Level::WARNING,
+            ViolationSeverity::Info => Level::HELP,
+        }
+    }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+impl Display for ViolationSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ViolationSeverity::Error => write!(f, "error"),
+            ViolationSeverity::Warning => write!(f, "warning"),
+            ViolationSeverity::Info => write!(f, "info"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum LabelPriority {
     /// Primary message of the report
     Primary,
@@ -41,4 +64,11 @@ impl LabelPriority {
             LabelPriority::Secondary => Label::secondary(file_id, range.clone()),
         }
     }
+
+    pub(crate) fn as_annotate_kind(&self) -> AnnotationKind {
+        match self {
+            LabelPriority::Primary => AnnotationKind::Primary,
+            LabelPriority::Secondary => AnnotationKind::Context,
+        }
+    }
 }
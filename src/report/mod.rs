@@ -1,3 +1,4 @@
+pub mod compact_renderer;
 pub mod enums;
 pub mod error;
 pub mod renderer;
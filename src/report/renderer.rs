@@ -1,5 +1,7 @@
+use crate::diagnostics::LintReport;
 use crate::report::error::ReportParseError;
 use crate::report::specs::ReportSpecs;
+use annotate_snippets::{Group, Level, Snippet};
 use codespan_reporting::diagnostic::Diagnostic;
 use codespan_reporting::files::SimpleFiles;
 use codespan_reporting::term;
@@ -43,6 +45,76 @@ impl ReportRenderer {
             .map_err(ReportParseError::Emit)
     }
 
+    /// Converts the compiled reports of all findings into codespan `Diagnostic`s.
+    ///
+    /// This allows callers to render the diagnostics themselves, e.g. with their own
+    /// `term::Config` or writer, instead of relying on `emit`.
+    ///
+    /// # Arguments
+    /// * `report` - The report whose findings should be converted
+    /// * `file_id` - The id of the phenopacket source in the caller's `Files` database
+    pub fn to_diagnostics(report: &LintReport, file_id: usize) -> Vec<Diagnostic<usize>> {
+        report
+            .findings()
+            .iter()
+            .filter_map(|finding| finding.report())
+            .map(|report_specs| Self::parse_specs(report_specs, file_id))
+            .collect()
+    }
+
+    /// Converts the compiled reports of all findings into annotate-snippets `Group`s.
+    ///
+    /// Each group is one report, so callers can render them with their own `Renderer`,
+    /// e.g. `Renderer::styled().render(&groups)`.
+    ///
+    /// # Arguments
+    /// * `report` - The report whose findings should be converted
+    /// * `phenostr` - The phenopacket source the label ranges point into
+    /// * `path` - The name under which the source is shown
+    pub fn to_annotate_groups<'a>(
+        report: &'a LintReport,
+        phenostr: &'a str,
+        path: &'a str,
+    ) -> Vec<Group<'a>> {
+        report
+            .findings()
+            .iter()
+            .filter_map(|finding| finding.report())
+            .map(|report_specs| Self::specs_to_group(report_specs, phenostr, path))
+            .collect()
+    }
+
+    fn specs_to_group<'a>(
+        report_specs: &'a ReportSpecs,
+        phenostr: &'a str,
+        path: &'a str,
+    ) -> Group<'a> {
+        let annotations = report_specs.labels().iter().map(|label_spec| {
+            label_spec
+                .style()
+                .as_annotate_kind()
+                .span(label_spec.range().clone())
+                .label(label_spec.message())
+        });
+
+        report_specs
+            .severity()
+            .as_annotate_level()
+            .primary_title(report_specs.message())
+            .id(report_specs.code())
+            .element(
+                Snippet::source(phenostr)
+                    .path(path)
+                    .annotations(annotations),
+            )
+            .elements(
+                report_specs
+                    .notes()
+                    .iter()
+                    .map(|note| Level::NOTE.message(note.as_str())),
+            )
+    }
+
     pub(crate) fn parse_specs(report_specs: &ReportSpecs, file_id: usize) -> Diagnostic<usize> {
         let mut diagnostic = report_specs.severity().as_codespan_diagnostic();
         diagnostic = diagnostic.with_message(report_specs.message());
@@ -69,3 +141,105 @@ impl ReportRenderer {
         diagnostic
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::{LintFinding, LintViolation};
+    use crate::helper::NonEmptyVec;
+    use crate::report::enums::{LabelPriority, ViolationSeverity};
+    use crate::report::specs::LabelSpecs;
+    use crate::tree::pointer::Pointer;
+    use annotate_snippets::Renderer;
+    use codespan_reporting::diagnostic::{LabelStyle, Severity};
+    use rstest::rstest;
+
+    #[rstest]
+    fn test_to_diagnostics() {
+        let violation = LintViolation::new(
+            ViolationSeverity::Warning,
+            "INTER001",
+            NonEmptyVec::with_single_entry(Pointer::new("/interpretations/0/diagnosis/disease")),
+        );
+        let report_specs = ReportSpecs::from_violation(
+            &violation,
+            "Found disease in interpretation".to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                10..20,
+                "disease".to_string(),
+            )],
+            vec!["Some note".to_string()],
+        );
+
+        let mut report = LintReport::new();
+        report.push_finding(LintFinding::new(violation, vec![], Some(report_specs)));
+
+        let diagnostics = ReportRenderer::to_diagnostics(&report, 0);
+
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = diagnostics.first().unwrap();
+        assert_eq!(diagnostic.severity, Severity::Warning);
+        assert_eq!(diagnostic.code.as_deref(), Some("INTER001"));
+        assert_eq!(diagnostic.message, "Found disease in interpretation");
+        assert_eq!(diagnostic.labels.len(), 1);
+        assert_eq!(diagnostic.labels[0].style, LabelStyle::Primary);
+        assert_eq!(diagnostic.labels[0].range, 10..20);
+        assert_eq!(diagnostic.notes, vec!["Some note".to_string()]);
+    }
+
+    #[rstest]
+    fn test_to_diagnostics_skips_findings_without_report() {
+        let violation = LintViolation::new(
+            ViolationSeverity::Info,
+            "CUST001",
+            NonEmptyVec::with_single_entry(Pointer::new("/id")),
+        );
+
+        let mut report = LintReport::new();
+        report.push_finding(LintFinding::new(violation, vec![], None));
+
+        assert!(ReportRenderer::to_diagnostics(&report, 0).is_empty());
+    }
+
+    #[rstest]
+    fn test_to_annotate_groups() {
+        let phenostr = "{\"id\": \"pp-1\"}";
+        let violation = LintViolation::new(
+            ViolationSeverity::Warning,
+            "CUST001",
+            NonEmptyVec::with_single_entry(Pointer::new("/id")),
+        );
+        let report_specs = ReportSpecs::from_violation(
+            &violation,
+            "Unexpected id".to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                7..13,
+                "this id".to_string(),
+            )],
+            vec!["Some note".to_string()],
+        );
+
+        let mut report = LintReport::new();
+        report.push_finding(LintFinding::new(violation, vec![], Some(report_specs)));
+        report.push_finding(LintFinding::new(
+            LintViolation::new(
+                ViolationSeverity::Info,
+                "CUST002",
+                NonEmptyVec::with_single_entry(Pointer::new("/id")),
+            ),
+            vec![],
+            None,
+        ));
+
+        let groups = ReportRenderer::to_annotate_groups(&report, phenostr, "pp-1.json");
+        assert_eq!(groups.len(), 1);
+
+        let rendered = Renderer::plain().render(&groups);
+        assert!(rendered.contains("warning[CUST001]: Unexpected id"));
+        assert!(rendered.contains("pp-1.json"));
+        assert!(rendered.contains("this id"));
+        assert!(rendered.contains("note: Some note"));
+    }
+}
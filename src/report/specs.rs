@@ -74,6 +74,10 @@ impl ReportSpecs {
         &self.severity
     }
 
+    pub(crate) fn set_severity(&mut self, severity: ViolationSeverity) {
+        self.severity = severity;
+    }
+
     pub fn code(&self) -> &str {
         &self.rule_id
     }
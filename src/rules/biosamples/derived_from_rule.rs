@@ -0,0 +1,92 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::Biosample;
+use std::collections::HashSet;
+
+/// ### BIO001
+/// ## What it does
+/// Flags biosamples whose `derivedFromId` does not reference the id of another biosample in the phenopacket.
+///
+/// ## Why is this bad?
+/// `derivedFromId` documents the lineage of a sample, e.g. a cell line derived from a biopsy.
+/// A dangling reference breaks that lineage and usually points to a typo or a removed biosample.
+#[derive(Debug, Default)]
+#[register_rule(id = "BIO001")]
+pub struct DerivedFromRule;
+
+impl RuleFromContext for DerivedFromRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(DerivedFromRule))
+    }
+}
+
+impl RuleCheck for DerivedFromRule {
+    type Data<'a> = List<'a, Biosample>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let biosample_ids: HashSet<&str> = data.iter().map(|node| node.inner.id.as_str()).collect();
+
+        data.iter()
+            .filter(|node| {
+                let derived_from_id = node.inner.derived_from_id.as_str();
+                !derived_from_id.is_empty() && !biosample_ids.contains(derived_from_id)
+            })
+            .map(|node| {
+                let mut ptr = node.pointer().clone();
+                ptr.down("derivedFromId");
+
+                LintViolation::new(
+                    ViolationSeverity::Error,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "BIO001")]
+struct DerivedFromReport;
+
+impl ReportFromContext for DerivedFromReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(DerivedFromReport))
+    }
+}
+
+impl CompileReport for DerivedFromReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let derived_from_ptr = lint_violation.first_at();
+        let derived_from_id = full_node
+            .value_at(derived_from_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Biosample is derived from the unknown biosample '{derived_from_id}'"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(derived_from_ptr).unwrap().clone(),
+                "No biosample has this id".to_string(),
+            )],
+            vec![
+                "`derivedFromId` has to reference the id of another biosample in the phenopacket."
+                    .to_string(),
+            ],
+        )
+    }
+}
@@ -0,0 +1,2 @@
+pub mod derived_from_rule;
+pub mod taxonomy_mismatch_rule;
@@ -0,0 +1,188 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::{List, Single};
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Biosample;
+
+/// ### BIO002
+/// ## What it does
+/// Flags biosamples whose `taxonomy` differs from the `taxonomy` of the subject,
+/// e.g. a mouse sample (`NCBITaxon:10090`) for a human subject (`NCBITaxon:9606`).
+///
+/// Nothing is flagged if the subject or the biosample does not declare a taxonomy.
+///
+/// ## Why is this bad?
+/// The biosamples of a phenopacket are taken from its subject, so they share its taxonomy.
+/// A mismatch usually points to a mix-up of samples or a copy-paste error.
+#[derive(Debug, Default)]
+#[register_rule(id = "BIO002")]
+pub struct TaxonomyMismatchRule;
+
+impl RuleFromContext for TaxonomyMismatchRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(TaxonomyMismatchRule))
+    }
+}
+
+impl RuleCheck for TaxonomyMismatchRule {
+    type Data<'a> = (Single<'a, Phenopacket>, List<'a, Biosample>);
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(phenopacket) = data.0.0 else {
+            return vec![];
+        };
+        let Some(subject_taxonomy) = phenopacket
+            .inner
+            .subject
+            .as_ref()
+            .and_then(|subject| subject.taxonomy.as_ref())
+        else {
+            return vec![];
+        };
+
+        let mut subject_taxonomy_ptr = phenopacket.pointer().clone();
+        subject_taxonomy_ptr.down("subject").down("taxonomy");
+
+        data.1
+            .iter()
+            .filter(|node| {
+                node.inner
+                    .taxonomy
+                    .as_ref()
+                    .is_some_and(|taxonomy| taxonomy.id != subject_taxonomy.id)
+            })
+            .map(|node| {
+                let mut ptr = node.pointer().clone();
+                ptr.down("taxonomy");
+
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(ptr, vec![subject_taxonomy_ptr.clone()]),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "BIO002")]
+struct TaxonomyMismatchReport;
+
+impl ReportFromContext for TaxonomyMismatchReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(TaxonomyMismatchReport))
+    }
+}
+
+fn taxonomy_id(full_node: &dyn Node, taxonomy_ptr: &Pointer) -> String {
+    let mut id_ptr = taxonomy_ptr.clone();
+    id_ptr.down("id");
+
+    full_node
+        .value_at(&id_ptr)
+        .and_then(|id| id.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+impl CompileReport for TaxonomyMismatchReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let biosample_taxonomy_ptr = lint_violation.first_at();
+        let subject_taxonomy_ptr = &lint_violation.at()[1];
+        let biosample_taxonomy = taxonomy_id(full_node, biosample_taxonomy_ptr);
+        let subject_taxonomy = taxonomy_id(full_node, subject_taxonomy_ptr);
+
+        let labels = vec![
+            LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(biosample_taxonomy_ptr).unwrap().clone(),
+                "Taxonomy of the biosample".to_string(),
+            ),
+            LabelSpecs::new(
+                LabelPriority::Secondary,
+                full_node.span_at(subject_taxonomy_ptr).unwrap().clone(),
+                "Taxonomy of the subject".to_string(),
+            ),
+        ];
+        let message = format!(
+            "Biosample taxonomy '{biosample_taxonomy}' differs from subject taxonomy '{subject_taxonomy}'"
+        );
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message,
+            labels,
+            vec!["Biosamples are expected to share the taxonomy of the subject.".to_string()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use phenopackets::schema::v2::core::{Individual, OntologyClass};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn taxonomy(id: &str) -> Option<OntologyClass> {
+        Some(OntologyClass {
+            id: id.to_string(),
+            label: "taxon".to_string(),
+        })
+    }
+
+    #[rstest]
+    #[case(taxonomy("NCBITaxon:9606"), taxonomy("NCBITaxon:9606"), 0)]
+    #[case(taxonomy("NCBITaxon:9606"), taxonomy("NCBITaxon:10090"), 1)]
+    #[case(None, taxonomy("NCBITaxon:10090"), 0)]
+    #[case(taxonomy("NCBITaxon:9606"), None, 0)]
+    fn test_find_taxonomy_mismatches(
+        #[case] subject_taxonomy: Option<OntologyClass>,
+        #[case] biosample_taxonomy: Option<OntologyClass>,
+        #[case] n_violations: usize,
+    ) {
+        let phenopacket = MaterializedNode::new(
+            Phenopacket {
+                subject: Some(Individual {
+                    id: "patient-1".to_string(),
+                    taxonomy: subject_taxonomy,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::at_root(),
+        );
+        let biosamples = [MaterializedNode::new(
+            Biosample {
+                id: "biopsy-1".to_string(),
+                taxonomy: biosample_taxonomy,
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new("/biosamples/0"),
+        )];
+
+        let violations =
+            TaxonomyMismatchRule.check((Single(Some(&phenopacket)), List(&biosamples)));
+
+        assert_eq!(violations.len(), n_violations);
+        if let Some(violation) = violations.first() {
+            let at: Vec<&str> = violation.at().iter().map(Pointer::position).collect();
+            assert_eq!(at, vec!["/biosamples/0/taxonomy", "/subject/taxonomy"]);
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::enums::RuleScope;
+use crate::rules::resources::find_prefix;
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::{OntologyClass, Resource};
+use std::collections::HashSet;
+
+/// ### COH001
+/// ## What it does
+/// Checks that the `metaData.resources` of a cohort cover the CURIE prefix of every ontology class
+/// used by any of its members. Resources of the members themselves do not count.
+/// Each uncovered prefix is reported once, together with the first ontology class using it.
+///
+/// Runs on cohorts only, see `Phenolint::lint_cohort`.
+///
+/// ## Why is this bad?
+/// Members of a cohort often omit their own resources and rely on the ones of the cohort.
+/// A prefix without a resource can neither be expanded to an IRI, nor is its version documented.
+#[derive(Debug, Default)]
+#[register_rule(id = "COH001")]
+pub struct MemberResourcesRule;
+
+impl RuleFromContext for MemberResourcesRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(MemberResourcesRule))
+    }
+}
+
+impl RuleCheck for MemberResourcesRule {
+    type Data<'a> = (List<'a, OntologyClass>, List<'a, Resource>);
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut covered: HashSet<&str> = data
+            .1
+            .iter()
+            .filter(|node| node.pointer().is_descendant_of(&Pointer::at_resources()))
+            .map(|node| node.inner.namespace_prefix.as_str())
+            .collect();
+
+        let mut violations = vec![];
+
+        for node in data.0.iter() {
+            if !node.pointer().is_descendant_of(&Pointer::new("members")) {
+                continue;
+            }
+
+            if let Some(prefix) = find_prefix(node.inner.id.as_str())
+                && covered.insert(prefix)
+            {
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Error,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(Pointer::at_meta_data(), vec![node.pointer().clone()]),
+                ));
+            }
+        }
+
+        violations
+    }
+
+    fn scope(&self) -> RuleScope {
+        RuleScope::Cohort
+    }
+}
+
+#[register_report(id = "COH001")]
+struct MemberResourcesReport;
+
+impl ReportFromContext for MemberResourcesReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(MemberResourcesReport))
+    }
+}
+
+impl CompileReport for MemberResourcesReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let meta_data_ptr = lint_violation.first_at();
+        let member_oc_ptr = &lint_violation.at()[1];
+
+        let mut id_ptr = member_oc_ptr.clone();
+        id_ptr.down("id");
+        let curie = full_node
+            .value_at(&id_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let prefix = find_prefix(&curie).unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Cohort has no resource for prefix '{prefix}' used by its members"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(meta_data_ptr).unwrap().clone(),
+                    format!("Missing resource for '{prefix}'"),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(member_oc_ptr).unwrap().clone(),
+                    format!("'{curie}' is used here"),
+                ),
+            ],
+            vec!["Add a resource for the prefix to the metaData of the cohort.".to_string()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn oc_node(id: &str, ptr: &str) -> MaterializedNode<OntologyClass> {
+        MaterializedNode::new(
+            OntologyClass {
+                id: id.to_string(),
+                label: "some label".to_string(),
+            },
+            HashMap::new(),
+            Pointer::new(ptr),
+        )
+    }
+
+    fn resource_node(prefix: &str, ptr: &str) -> MaterializedNode<Resource> {
+        MaterializedNode::new(
+            Resource {
+                namespace_prefix: prefix.to_string(),
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new(ptr),
+        )
+    }
+
+    #[rstest]
+    fn test_find_uncovered_prefixes() {
+        let ocs = [
+            oc_node("HP:0001250", "/members/0/phenotypicFeatures/0/type"),
+            oc_node("MONDO:0007947", "/members/0/diseases/0/term"),
+            oc_node("MONDO:0000252", "/members/1/diseases/0/term"),
+            oc_node("NCBITaxon:9606", "/members/1/subject/taxonomy"),
+        ];
+        let resources = [
+            resource_node("HP", "/metaData/resources/0"),
+            resource_node("NCBITaxon", "/members/1/metaData/resources/0"),
+        ];
+
+        let violations = MemberResourcesRule.check((List(&ocs), List(&resources)));
+
+        let ptrs: Vec<&str> = violations
+            .iter()
+            .map(|violation| violation.at()[1].position())
+            .collect();
+        assert_eq!(
+            ptrs,
+            vec!["/members/0/diseases/0/term", "/members/1/subject/taxonomy"]
+        );
+        assert!(
+            violations
+                .iter()
+                .all(|violation| violation.first_at() == &Pointer::at_meta_data())
+        );
+    }
+}
@@ -0,0 +1 @@
+pub mod member_resources_rule;
@@ -0,0 +1,194 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::OntologyClass;
+use serde_json::Value;
+
+/// Namespaces whose local ids are zero-padded to a fixed number of digits.
+const FIXED_WIDTH_NAMESPACES: [(&str, usize); 11] = [
+    ("HP", 7),
+    ("MONDO", 7),
+    ("MP", 7),
+    ("GO", 7),
+    ("UBERON", 7),
+    ("CL", 7),
+    ("SO", 7),
+    ("PATO", 7),
+    ("MAXO", 7),
+    ("ECO", 7),
+    ("UO", 7),
+];
+
+/// Returns the width the local id of `curie` should have, if it is numeric but has a different width.
+fn expected_width(curie: &str) -> Option<usize> {
+    let (prefix, local_id) = curie.split_once(':')?;
+    let (_, width) = FIXED_WIDTH_NAMESPACES
+        .iter()
+        .find(|(namespace, _)| *namespace == prefix)?;
+
+    if !local_id.is_empty()
+        && local_id.chars().all(|c| c.is_ascii_digit())
+        && local_id.len() != *width
+    {
+        Some(*width)
+    } else {
+        None
+    }
+}
+
+/// Pads the local id of an under-padded CURIE with leading zeros.
+///
+/// Returns `None` if the CURIE is not under-padded. Over-long ids can not be fixed by padding.
+fn pad_curie(curie: &str) -> Option<String> {
+    let width = expected_width(curie)?;
+    let (prefix, local_id) = curie.split_once(':')?;
+
+    if local_id.len() < width {
+        Some(format!("{prefix}:{local_id:0>width$}"))
+    } else {
+        None
+    }
+}
+
+/// ### CURIE004
+/// ## What it does
+/// Identifies CURIE IDs of fixed-width namespaces whose local ID has the wrong number of digits,
+/// e.g. `HP:001250` instead of `HP:0001250`.
+///
+/// ## Why is this bad?
+/// IDs of namespaces like HPO or MONDO are zero-padded to seven digits. An ID with a different
+/// width does not exist in the ontology and can not be resolved.
+#[derive(Debug, Default)]
+#[register_rule(id = "CURIE004")]
+pub struct CuriePaddingRule;
+
+impl RuleFromContext for CuriePaddingRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(CuriePaddingRule))
+    }
+}
+
+impl RuleCheck for CuriePaddingRule {
+    type Data<'a> = List<'a, OntologyClass>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| expected_width(&node.inner.id).is_some())
+            .map(|node| {
+                let mut ptr = node.pointer().clone();
+                ptr.down("id");
+
+                LintViolation::new(
+                    ViolationSeverity::Error,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "CURIE004")]
+struct CuriePaddingReport;
+
+impl ReportFromContext for CuriePaddingReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(CuriePaddingReport))
+    }
+}
+
+impl CompileReport for CuriePaddingReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let violation_ptr = lint_violation.first_at().clone();
+        let curie = full_node
+            .value_at(&violation_ptr)
+            .and_then(|curie| curie.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let width = expected_width(&curie).unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("CURIE has a local ID of the wrong width: {curie}"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(&violation_ptr).unwrap().clone(),
+                format!("Expected {width} digits"),
+            )],
+            vec![
+                "Local IDs of this namespace are zero-padded to a fixed number of digits."
+                    .to_string(),
+            ],
+        )
+    }
+}
+
+#[register_patch(id = "CURIE004")]
+struct CuriePaddingPatch;
+
+impl PatchFromContext for CuriePaddingPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(Self))
+    }
+}
+
+impl CompilePatches for CuriePaddingPatch {
+    fn compile_patches(&self, value: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        let violation_ptr = lint_violation.first_at();
+
+        let Some(padded) = value
+            .value_at(violation_ptr)
+            .and_then(|curie| curie.as_str().and_then(pad_curie))
+        else {
+            return vec![];
+        };
+
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Replace {
+                at: violation_ptr.clone(),
+                value: Value::String(padded),
+            },
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expected_width, pad_curie};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("HP:001250", Some("HP:0001250"))]
+    #[case("MONDO:7947", Some("MONDO:0007947"))]
+    #[case("HP:0001250", None)]
+    #[case("HP:00001250", None)]
+    #[case("NCIT:C3117", None)]
+    #[case("OMIM:154700", None)]
+    fn test_pad_curie(#[case] curie: &str, #[case] expected: Option<&str>) {
+        assert_eq!(pad_curie(curie).as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case("HP:00001250", Some(7))]
+    #[case("HP:0001250", None)]
+    #[case("HP:0001250_2023", None)]
+    fn test_expected_width(#[case] curie: &str, #[case] expected: Option<usize>) {
+        assert_eq!(expected_width(curie), expected);
+    }
+}
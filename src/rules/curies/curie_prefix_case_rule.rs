@@ -0,0 +1,183 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::resources::find_prefix;
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::{OntologyClass, Resource};
+use serde_json::Value;
+
+/// Replaces the prefix of `curie` with `prefix`, keeping the separator and the local id.
+fn with_prefix(curie: &str, prefix: &str) -> Option<String> {
+    let current = find_prefix(curie)?;
+    Some(format!("{prefix}{}", &curie[current.len()..]))
+}
+
+/// ### CURIE005
+/// ## What it does
+/// Flags CURIEs whose prefix only matches the `namespacePrefix` of a resource when ignoring case,
+/// e.g. `hp:0001250` when the resource declares `HP`.
+///
+/// CURIEs without any matching resource are left to INTER002.
+///
+/// ## Why is this bad?
+/// CURIE prefixes are case-sensitive. A prefix in the wrong case can not be expanded with the
+/// resource and is not recognized by tools that resolve the ontology class.
+#[derive(Debug, Default)]
+#[register_rule(id = "CURIE005")]
+pub struct CuriePrefixCaseRule;
+
+impl RuleFromContext for CuriePrefixCaseRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(CuriePrefixCaseRule))
+    }
+}
+
+impl RuleCheck for CuriePrefixCaseRule {
+    type Data<'a> = (List<'a, OntologyClass>, List<'a, Resource>);
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.0.iter() {
+            let Some(prefix) = find_prefix(node.inner.id.as_str()) else {
+                continue;
+            };
+            if data
+                .1
+                .iter()
+                .any(|resource| resource.inner.namespace_prefix == prefix)
+            {
+                continue;
+            }
+            let Some(resource) = data
+                .1
+                .iter()
+                .find(|resource| resource.inner.namespace_prefix.eq_ignore_ascii_case(prefix))
+            else {
+                continue;
+            };
+
+            let mut id_ptr = node.pointer().clone();
+            id_ptr.down("id");
+            let mut prefix_ptr = resource.pointer().clone();
+            prefix_ptr.down("namespacePrefix");
+
+            violations.push(LintViolation::new(
+                ViolationSeverity::Warning,
+                LintRule::rule_id(self),
+                NonEmptyVec::with_rest(id_ptr, vec![prefix_ptr]),
+            ));
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "CURIE005")]
+struct CuriePrefixCaseReport;
+
+impl ReportFromContext for CuriePrefixCaseReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(CuriePrefixCaseReport))
+    }
+}
+
+impl CompileReport for CuriePrefixCaseReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let id_ptr = lint_violation.first_at();
+        let prefix_ptr = &lint_violation.at()[1];
+
+        let curie = full_node
+            .value_at(id_ptr)
+            .and_then(|curie| curie.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let prefix = full_node
+            .value_at(prefix_ptr)
+            .and_then(|prefix| prefix.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("CURIE prefix of {curie} does not match the casing of resource '{prefix}'"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(id_ptr).unwrap().clone(),
+                    "Prefix in the wrong case".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(prefix_ptr).unwrap().clone(),
+                    "Resource declared here".to_string(),
+                ),
+            ],
+            vec!["CURIE prefixes are case-sensitive.".to_string()],
+        )
+    }
+}
+
+#[register_patch(id = "CURIE005")]
+struct CuriePrefixCasePatch;
+
+impl PatchFromContext for CuriePrefixCasePatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(Self))
+    }
+}
+
+impl CompilePatches for CuriePrefixCasePatch {
+    fn compile_patches(&self, value: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        let id_ptr = lint_violation.first_at();
+
+        let Some(prefix) = value
+            .value_at(&lint_violation.at()[1])
+            .and_then(|prefix| prefix.as_str().map(str::to_string))
+        else {
+            return vec![];
+        };
+        let Some(fixed) = value
+            .value_at(id_ptr)
+            .and_then(|curie| curie.as_str().and_then(|curie| with_prefix(curie, &prefix)))
+        else {
+            return vec![];
+        };
+
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Replace {
+                at: id_ptr.clone(),
+                value: Value::String(fixed),
+            },
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::with_prefix;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("hp:0001250", "HP", Some("HP:0001250"))]
+    #[case("Mondo:0007947", "MONDO", Some("MONDO:0007947"))]
+    #[case("hp_0001250", "HP", Some("HP_0001250"))]
+    #[case("0001250", "HP", None)]
+    fn test_with_prefix(#[case] curie: &str, #[case] prefix: &str, #[case] expected: Option<&str>) {
+        assert_eq!(with_prefix(curie, prefix).as_deref(), expected);
+    }
+}
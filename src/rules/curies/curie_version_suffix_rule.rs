@@ -0,0 +1,163 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use once_cell::sync::Lazy;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::OntologyClass;
+use regex::Regex;
+use serde_json::Value;
+
+/// Namespaces whose local ids consist of digits only.
+const NUMERIC_NAMESPACES: [&str; 15] = [
+    "HP", "MONDO", "OMIM", "ORPHA", "ECO", "GENO", "SO", "UO", "HGNC", "MP", "CHEBI", "UBERON",
+    "CL", "PATO", "MAXO",
+];
+
+static SUFFIXED_CURIE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^([A-Za-z]+):([0-9]+)([^0-9].*)$").expect("Invalid regex"));
+
+/// Strips a trailing suffix (e.g. `_2023` or `.2`) from the local part of a CURIE.
+///
+/// Returns `None` if the CURIE does not belong to a numeric namespace or has no suffix.
+fn strip_version_suffix(curie: &str) -> Option<String> {
+    let captures = SUFFIXED_CURIE.captures(curie)?;
+    let prefix = captures.get(1)?.as_str();
+    let local_id = captures.get(2)?.as_str();
+
+    if NUMERIC_NAMESPACES.contains(&prefix) {
+        Some(format!("{prefix}:{local_id}"))
+    } else {
+        None
+    }
+}
+
+/// ### CURIE002
+/// ## What it does
+/// Identifies CURIE IDs of numeric namespaces that carry a trailing version suffix, e.g. `HP:0001250_2023`.
+///
+/// ## Why is this bad?
+/// Some tools append version information to the local part of an ID. These IDs do not exist in
+/// the ontology and can not be resolved. The ontology version belongs into the resources.
+#[derive(Debug, Default)]
+#[register_rule(id = "CURIE002")]
+pub struct CurieVersionSuffixRule;
+
+impl RuleFromContext for CurieVersionSuffixRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(CurieVersionSuffixRule))
+    }
+}
+
+impl RuleCheck for CurieVersionSuffixRule {
+    type Data<'a> = List<'a, OntologyClass>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.0.iter() {
+            if strip_version_suffix(&node.inner.id).is_some() {
+                let mut ptr = node.pointer().clone();
+                ptr.down("id");
+
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                ))
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "CURIE002")]
+struct CurieVersionSuffixReport;
+
+impl ReportFromContext for CurieVersionSuffixReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(CurieVersionSuffixReport))
+    }
+}
+
+impl CompileReport for CurieVersionSuffixReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let violation_ptr = lint_violation.first_at().clone();
+        let curie = full_node
+            .value_at(&violation_ptr)
+            .expect("CURIE should exist");
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("CURIE carries a trailing suffix: {}", curie),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(&violation_ptr).unwrap().clone(),
+                String::default(),
+            )],
+            vec!["The local ID of this namespace consists of digits only. The ontology version should be documented in the resource.".to_string()],
+        )
+    }
+}
+
+#[register_patch(id = "CURIE002")]
+struct CurieVersionSuffixPatch;
+
+impl PatchFromContext for CurieVersionSuffixPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(Self))
+    }
+}
+
+impl CompilePatches for CurieVersionSuffixPatch {
+    fn compile_patches(&self, value: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        let violation_ptr = lint_violation.first_at();
+
+        let Some(stripped) = value
+            .value_at(violation_ptr)
+            .and_then(|curie| curie.as_str().and_then(strip_version_suffix))
+        else {
+            return vec![];
+        };
+
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Replace {
+                at: violation_ptr.clone(),
+                value: Value::String(stripped),
+            },
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_version_suffix;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("HP:0001250_2023", Some("HP:0001250"))]
+    #[case("MONDO:0000252.2", Some("MONDO:0000252"))]
+    #[case("HP:0001250v2", Some("HP:0001250"))]
+    #[case("HP:0001250", None)]
+    #[case("NCIT:C3117", None)]
+    #[case("LOINC:1234-5", None)]
+    fn test_strip_version_suffix(#[case] curie: &str, #[case] expected: Option<&str>) {
+        assert_eq!(strip_version_suffix(curie).as_deref(), expected);
+    }
+}
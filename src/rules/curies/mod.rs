@@ -1 +1,5 @@
 pub mod curie_format_rule;
+pub mod curie_padding_rule;
+pub mod curie_prefix_case_rule;
+pub mod curie_version_suffix_rule;
+pub mod swapped_id_label_rule;
@@ -0,0 +1,159 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use once_cell::sync::Lazy;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::OntologyClass;
+use regex::Regex;
+
+static CURIE: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^[A-Za-z][A-Za-z0-9_.]*:[A-Za-z0-9_.-]+$").expect("Invalid regex"));
+
+/// Returns true if the `id` of an ontology class looks like a label, while its `label` looks like a CURIE.
+fn is_swapped(id: &str, label: &str) -> bool {
+    !CURIE.is_match(id.trim()) && CURIE.is_match(label.trim())
+}
+
+/// ### CURIE003
+/// ## What it does
+/// Identifies ontology classes where the `id` holds the human readable label and the `label`
+/// holds the CURIE.
+///
+/// ## Why is this bad?
+/// The term can not be resolved by its ID, so every tool processing the phenopacket
+/// will fail to interpret it, even though all information is present.
+#[derive(Debug, Default)]
+#[register_rule(id = "CURIE003")]
+pub struct SwappedIdLabelRule;
+
+impl RuleFromContext for SwappedIdLabelRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(SwappedIdLabelRule))
+    }
+}
+
+impl RuleCheck for SwappedIdLabelRule {
+    type Data<'a> = List<'a, OntologyClass>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            if is_swapped(&node.inner.id, &node.inner.label) {
+                let mut id_ptr = node.pointer().clone();
+                id_ptr.down("id");
+                let mut label_ptr = node.pointer().clone();
+                label_ptr.down("label");
+
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Error,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(id_ptr, vec![label_ptr]),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "CURIE003")]
+struct SwappedIdLabelReport;
+
+impl ReportFromContext for SwappedIdLabelReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(SwappedIdLabelReport))
+    }
+}
+
+impl CompileReport for SwappedIdLabelReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let id_ptr = lint_violation.first_at();
+        let label_ptr = &lint_violation.at()[1];
+
+        let label = full_node
+            .value_at(label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("ID and label of ontology class '{label}' appear to be swapped"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(id_ptr).unwrap().clone(),
+                    "Expected a CURIE".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(label_ptr).unwrap().clone(),
+                    "This looks like a CURIE".to_string(),
+                ),
+            ],
+            vec![],
+        )
+    }
+}
+
+#[register_patch(id = "CURIE003")]
+struct SwappedIdLabelPatch;
+
+impl PatchFromContext for SwappedIdLabelPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(SwappedIdLabelPatch))
+    }
+}
+
+impl CompilePatches for SwappedIdLabelPatch {
+    fn compile_patches(&self, value: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        let id_ptr = lint_violation.first_at();
+        let label_ptr = &lint_violation.at()[1];
+
+        let (Some(id), Some(label)) = (value.value_at(id_ptr), value.value_at(label_ptr)) else {
+            return vec![];
+        };
+
+        vec![Patch::new(NonEmptyVec::with_rest(
+            PatchInstruction::Replace {
+                at: id_ptr.clone(),
+                value: label.into_owned(),
+            },
+            vec![PatchInstruction::Replace {
+                at: label_ptr.clone(),
+                value: id.into_owned(),
+            }],
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_swapped;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Seizure", "HP:0001250", true)]
+    #[case("HP:0001250", "Seizure", false)]
+    #[case("Seizure", "Seizure", false)]
+    #[case("HP:0001250", "HP:0001250", false)]
+    fn test_is_swapped(#[case] id: &str, #[case] label: &str, #[case] expected: bool) {
+        assert_eq!(is_swapped(id, label), expected);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::Disease;
+
+/// ### DIS001
+/// ## What it does
+/// Flags diseases that specify a `laterality` without a `primarySite`.
+///
+/// ## Why is this bad?
+/// A laterality like `left` describes the side of an anatomical site. Without the site,
+/// it is unclear what the laterality refers to.
+#[derive(Debug, Default)]
+#[register_rule(id = "DIS001")]
+pub struct LateralityWithoutSiteRule;
+
+impl RuleFromContext for LateralityWithoutSiteRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(LateralityWithoutSiteRule))
+    }
+}
+
+impl RuleCheck for LateralityWithoutSiteRule {
+    type Data<'a> = List<'a, Disease>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| node.inner.laterality.is_some() && node.inner.primary_site.is_none())
+            .map(|node| {
+                LintViolation::new(
+                    ViolationSeverity::Info,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(node.pointer().clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "DIS001")]
+struct LateralityWithoutSiteReport;
+
+impl ReportFromContext for LateralityWithoutSiteReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(LateralityWithoutSiteReport))
+    }
+}
+
+impl CompileReport for LateralityWithoutSiteReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let disease_ptr = lint_violation.first_at().clone();
+        let mut laterality_ptr = disease_ptr.clone();
+        laterality_ptr.down("laterality");
+        let mut label_ptr = disease_ptr.clone();
+        label_ptr.down("term").down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Disease '{label}' has a laterality but no primary site"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(&disease_ptr).unwrap().clone(),
+                    String::default(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(&laterality_ptr).unwrap().clone(),
+                    "Laterality of an unknown site".to_string(),
+                ),
+            ],
+            vec!["Add a primarySite the laterality refers to.".to_string()],
+        )
+    }
+}
@@ -0,0 +1,104 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::phenolint::DISABLE_RULES_REFERENCE_ID;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::{List, Single};
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Disease;
+
+/// ### EVID003
+/// ## What it does
+/// Flags diseases of phenopackets that do not document any provenance.
+/// Diseases have no evidence field of their own, so the `externalReferences` in the `metaData` are
+/// accepted as provenance. This rule is meant for curation standards that require evidence and is
+/// therefore not enabled by default.
+///
+/// ## Why is this bad?
+/// Without a reference, it can not be traced back how a disease was diagnosed.
+#[derive(Debug, Default)]
+#[register_rule(id = "EVID003")]
+pub struct MissingDiseaseEvidenceRule;
+
+impl RuleFromContext for MissingDiseaseEvidenceRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(MissingDiseaseEvidenceRule))
+    }
+}
+
+impl RuleCheck for MissingDiseaseEvidenceRule {
+    type Data<'a> = (List<'a, Disease>, Single<'a, Phenopacket>);
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let has_references = data.1.0.is_some_and(|pp| {
+            pp.inner.meta_data.as_ref().is_some_and(|meta_data| {
+                meta_data
+                    .external_references
+                    .iter()
+                    .any(|reference| reference.id != DISABLE_RULES_REFERENCE_ID)
+            })
+        });
+
+        if has_references {
+            return vec![];
+        }
+
+        data.0
+            .iter()
+            .filter(|node| !node.inner.excluded)
+            .map(|node| {
+                LintViolation::new(
+                    ViolationSeverity::Info,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(node.pointer().clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "EVID003")]
+struct MissingDiseaseEvidenceReport;
+
+impl ReportFromContext for MissingDiseaseEvidenceReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(MissingDiseaseEvidenceReport))
+    }
+}
+
+impl CompileReport for MissingDiseaseEvidenceReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let disease_ptr = lint_violation.first_at().clone();
+        let mut label_ptr = disease_ptr.clone();
+        label_ptr.down("term").down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Disease '{label}' has no evidence"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(&disease_ptr).unwrap().clone(),
+                String::default(),
+            )],
+            vec![
+                "Add an external reference to the metaData to document how the disease was diagnosed."
+                    .to_string(),
+            ],
+        )
+    }
+}
@@ -0,0 +1,4 @@
+pub mod laterality_without_site_rule;
+pub mod missing_disease_evidence_rule;
+pub mod onset_after_resolution_rule;
+pub mod staged_without_onset_rule;
@@ -0,0 +1,164 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::parse_iso8601_duration;
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::time_element::Element;
+use phenopackets::schema::v2::core::{Disease, TimeElement};
+
+/// Whether `later` lies before `earlier`. Only ages and timestamps can be compared with each other.
+fn is_before(later: &TimeElement, earlier: &TimeElement) -> bool {
+    match (&later.element, &earlier.element) {
+        (Some(Element::Age(later)), Some(Element::Age(earlier))) => {
+            match (
+                parse_iso8601_duration(&later.iso8601duration),
+                parse_iso8601_duration(&earlier.iso8601duration),
+            ) {
+                (Some(later), Some(earlier)) => later < earlier,
+                _ => false,
+            }
+        }
+        (Some(Element::Timestamp(later)), Some(Element::Timestamp(earlier))) => {
+            (later.seconds, later.nanos) < (earlier.seconds, earlier.nanos)
+        }
+        _ => false,
+    }
+}
+
+/// ### DIS003
+/// ## What it does
+/// Flags diseases whose `resolution` lies before their `onset`.
+/// Ages are compared with ages and timestamps with timestamps.
+///
+/// ## Why is this bad?
+/// A disease can not resolve before it started. One of the two times was most likely mistyped.
+///
+/// The phenopacket schema does not record when a diagnosis was made, so the resolution is the
+/// only other point in the course of a disease the onset can be checked against.
+#[derive(Debug, Default)]
+#[register_rule(id = "DIS003")]
+pub struct OnsetAfterResolutionRule;
+
+impl RuleFromContext for OnsetAfterResolutionRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(OnsetAfterResolutionRule))
+    }
+}
+
+impl RuleCheck for OnsetAfterResolutionRule {
+    type Data<'a> = List<'a, Disease>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| match (&node.inner.onset, &node.inner.resolution) {
+                (Some(onset), Some(resolution)) => is_before(resolution, onset),
+                _ => false,
+            })
+            .map(|node| {
+                let mut onset_ptr = node.pointer().clone();
+                onset_ptr.down("onset");
+                let mut resolution_ptr = node.pointer().clone();
+                resolution_ptr.down("resolution");
+
+                LintViolation::new(
+                    ViolationSeverity::Error,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(onset_ptr, vec![resolution_ptr]),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "DIS003")]
+struct OnsetAfterResolutionReport;
+
+impl ReportFromContext for OnsetAfterResolutionReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(OnsetAfterResolutionReport))
+    }
+}
+
+impl CompileReport for OnsetAfterResolutionReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let onset_ptr = lint_violation.first_at();
+        let resolution_ptr = &lint_violation.at()[1];
+        let mut label_ptr = onset_ptr.clone();
+        label_ptr.up().down("term").down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Disease '{label}' resolved before its onset"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(onset_ptr).unwrap().clone(),
+                    "Onset".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(resolution_ptr).unwrap().clone(),
+                    "Resolution before the onset".to_string(),
+                ),
+            ],
+            vec![],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_before;
+    use phenopackets::schema::v2::core::time_element::Element;
+    use phenopackets::schema::v2::core::{Age, OntologyClass, TimeElement};
+    use rstest::rstest;
+
+    fn age(iso8601duration: &str) -> TimeElement {
+        TimeElement {
+            element: Some(Element::Age(Age {
+                iso8601duration: iso8601duration.to_string(),
+            })),
+        }
+    }
+
+    fn timestamp(seconds: i64) -> TimeElement {
+        TimeElement {
+            element: Some(Element::Timestamp(prost_types::Timestamp {
+                seconds,
+                nanos: 0,
+            })),
+        }
+    }
+
+    #[rstest]
+    #[case(age("P5Y"), age("P10Y"), true)]
+    #[case(age("P10Y"), age("P5Y"), false)]
+    #[case(age("P1Y"), age("P11M"), false)]
+    #[case(timestamp(100), timestamp(200), true)]
+    #[case(timestamp(200), timestamp(100), false)]
+    #[case(age("P5Y"), timestamp(100), false)]
+    #[case(TimeElement { element: Some(Element::OntologyClass(OntologyClass::default())) }, age("P10Y"), false)]
+    fn test_is_before(
+        #[case] later: TimeElement,
+        #[case] earlier: TimeElement,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(is_before(&later, &earlier), expected);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::Disease;
+
+/// ### DIS002
+/// ## What it does
+/// Flags diseases with a `diseaseStage` or `clinicalTnmFinding`, but without an `onset`.
+///
+/// ## Why is this bad?
+/// A stage describes the disease at a point in time. Without an onset, there is no temporal
+/// anchor and it is unclear how far the disease had progressed when it was staged.
+#[derive(Debug, Default)]
+#[register_rule(id = "DIS002")]
+pub struct StagedWithoutOnsetRule;
+
+impl RuleFromContext for StagedWithoutOnsetRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(StagedWithoutOnsetRule))
+    }
+}
+
+impl RuleCheck for StagedWithoutOnsetRule {
+    type Data<'a> = List<'a, Disease>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| node.inner.onset.is_none())
+            .filter_map(|node| {
+                let stage_field = if !node.inner.disease_stage.is_empty() {
+                    "diseaseStage"
+                } else if !node.inner.clinical_tnm_finding.is_empty() {
+                    "clinicalTnmFinding"
+                } else {
+                    return None;
+                };
+
+                let mut stage_ptr = node.pointer().clone();
+                stage_ptr.down(stage_field);
+
+                Some(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(node.pointer().clone(), vec![stage_ptr]),
+                ))
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "DIS002")]
+struct StagedWithoutOnsetReport;
+
+impl ReportFromContext for StagedWithoutOnsetReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(StagedWithoutOnsetReport))
+    }
+}
+
+impl CompileReport for StagedWithoutOnsetReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let disease_ptr = lint_violation.first_at();
+        let stage_ptr = &lint_violation.at()[1];
+        let mut label_ptr = disease_ptr.clone();
+        label_ptr.down("term").down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Disease '{label}' is staged but has no onset"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(disease_ptr).unwrap().clone(),
+                    String::default(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(stage_ptr).unwrap().clone(),
+                    "Stage without a point in time".to_string(),
+                ),
+            ],
+            vec!["Add the onset of the disease.".to_string()],
+        )
+    }
+}
@@ -0,0 +1,9 @@
+/// What a rule lints, see `RuleCheck::scope`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RuleScope {
+    /// Runs on single phenopackets, with `Lint::lint`.
+    #[default]
+    Phenopacket,
+    /// Runs on cohorts, with `Phenolint::lint_cohort`. The nodes of all members are part of the cohort.
+    Cohort,
+}
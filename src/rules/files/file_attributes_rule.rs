@@ -0,0 +1,198 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::File;
+use std::collections::HashMap;
+
+/// Lowercases the key and drops all separators, so `file_format` and `fileFormat` are the same key.
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// ### FILE001
+/// ## What it does
+/// Flags `fileAttributes` of files with empty values (info) and keys expressing the same attribute,
+/// e.g. `genomeAssembly` and `genome_assembly` (warning). Additional aliases can be configured in the `LinterContext`.
+///
+/// ## Why is this bad?
+/// Empty attributes carry no information. Several keys for the same attribute are ambiguous,
+/// because it is unclear which value tools should use.
+#[derive(Debug, Default)]
+#[register_rule(id = "FILE001")]
+pub struct FileAttributesRule {
+    aliases: HashMap<String, String>,
+}
+
+impl FileAttributesRule {
+    fn canonical_key(&self, key: &str) -> String {
+        let normalized = normalize_key(key);
+        self.aliases.get(&normalized).cloned().unwrap_or(normalized)
+    }
+}
+
+impl RuleFromContext for FileAttributesRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let mut aliases = HashMap::new();
+
+        for group in context.file_attribute_aliases() {
+            let Some(canonical) = group.first().map(|key| normalize_key(key)) else {
+                continue;
+            };
+            for key in group {
+                aliases.insert(normalize_key(key), canonical.clone());
+            }
+        }
+
+        Ok(Box::new(FileAttributesRule { aliases }))
+    }
+}
+
+/// Kind of the violations for an attribute with an empty value.
+const EMPTY_VALUE: &str = "empty_value";
+/// Kind of the violations for keys describing the same attribute.
+const SAME_ATTRIBUTE: &str = "same_attribute";
+
+impl RuleCheck for FileAttributesRule {
+    type Data<'a> = List<'a, File>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            let mut attributes_ptr = node.pointer().clone();
+            attributes_ptr.down("fileAttributes");
+
+            let mut keys: Vec<&String> = node.inner.file_attributes.keys().collect();
+            keys.sort();
+
+            let mut by_intent: HashMap<String, Vec<&String>> = HashMap::new();
+            for key in keys.iter() {
+                by_intent
+                    .entry(self.canonical_key(key))
+                    .or_default()
+                    .push(*key);
+            }
+
+            for key in keys.iter() {
+                if node.inner.file_attributes[*key].trim().is_empty() {
+                    let mut key_ptr = attributes_ptr.clone();
+                    key_ptr.down(key);
+
+                    violations.push(
+                        LintViolation::new(
+                            ViolationSeverity::Info,
+                            LintRule::rule_id(self),
+                            NonEmptyVec::with_rest(attributes_ptr.clone(), vec![key_ptr]),
+                        )
+                        .with_kind(EMPTY_VALUE),
+                    );
+                }
+
+                let conflicting = &by_intent[&self.canonical_key(key)];
+                if conflicting.len() > 1 && conflicting.first() == Some(key) {
+                    let key_ptrs = conflicting
+                        .iter()
+                        .map(|key| {
+                            let mut key_ptr = attributes_ptr.clone();
+                            key_ptr.down(key);
+                            key_ptr
+                        })
+                        .collect();
+
+                    violations.push(
+                        LintViolation::new(
+                            ViolationSeverity::Warning,
+                            LintRule::rule_id(self),
+                            NonEmptyVec::with_rest(attributes_ptr.clone(), key_ptrs),
+                        )
+                        .with_kind(SAME_ATTRIBUTE),
+                    );
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "FILE001")]
+struct FileAttributesReport;
+
+impl ReportFromContext for FileAttributesReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(FileAttributesReport))
+    }
+}
+
+impl CompileReport for FileAttributesReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let key_ptrs = &lint_violation.at()[1..];
+        let keys: Vec<String> = key_ptrs
+            .iter()
+            .map(|ptr| format!("'{}'", ptr.segments().last().unwrap_or_default()))
+            .collect();
+
+        let (message, label_message) = if lint_violation.kind() == Some(EMPTY_VALUE) {
+            (
+                format!("File attribute {} has an empty value", keys.join(", ")),
+                "Empty value",
+            )
+        } else {
+            (
+                format!(
+                    "File attributes {} describe the same attribute",
+                    keys.join(", ")
+                ),
+                "Same attribute",
+            )
+        };
+
+        let labels = key_ptrs
+            .iter()
+            .enumerate()
+            .map(|(idx, ptr)| {
+                let priority = if idx == 0 {
+                    LabelPriority::Primary
+                } else {
+                    LabelPriority::Secondary
+                };
+                LabelSpecs::new(
+                    priority,
+                    full_node.span_at(ptr).unwrap().clone(),
+                    label_message.to_string(),
+                )
+            })
+            .collect();
+
+        ReportSpecs::from_violation(lint_violation, message, labels, vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_key;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("genomeAssembly", "genomeassembly")]
+    #[case("genome_assembly", "genomeassembly")]
+    #[case("Genome-Assembly", "genomeassembly")]
+    fn test_normalize_key(#[case] key: &str, #[case] expected: &str) {
+        assert_eq!(normalize_key(key), expected);
+    }
+}
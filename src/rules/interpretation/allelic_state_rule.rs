@@ -0,0 +1,249 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::no_ontology_message;
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node, RetrievableNode};
+use ontolius::TermId;
+use ontolius::ontology::HierarchyQueries;
+use ontolius::ontology::csr::FullCsrOntology;
+use phenolint_macros::{register_report, register_rule};
+use serde_json::Value;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The GENO term all allelic states descend from.
+const ZYGOSITY: &str = "GENO:0000133";
+
+fn is_geno_curie(curie: &str) -> bool {
+    curie
+        .split_once(':')
+        .is_some_and(|(prefix, _)| prefix == "GENO")
+}
+
+/// ### VAR001
+/// ## What it does
+/// Validates that the `allelicState` of variation descriptors is a descendant of `zygosity` (GENO:0000133).
+/// Terms of namespaces without a loaded ontology can not be validated and are reported as info.
+///
+/// ## Why is this bad?
+/// The allelic state describes the zygosity of a variant. A GENO term like `variant allele`
+/// is no zygosity, whereas `heterozygous` or `homozygous` are valid allelic states.
+#[derive(Debug)]
+#[register_rule(id = "VAR001")]
+pub struct AllelicStateRule {
+    geno: Arc<FullCsrOntology>,
+    zygosity: TermId,
+}
+
+impl RuleFromContext for AllelicStateRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let Some(geno) = context.geno() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "VAR001".to_string(),
+                ontology: "GENO".to_string(),
+            });
+        };
+
+        Ok(Box::new(AllelicStateRule {
+            geno,
+            zygosity: TermId::from_str(ZYGOSITY).expect("Invalid term id"),
+        }))
+    }
+}
+
+impl AllelicStateRule {
+    fn severity_of(&self, allelic_state: &str) -> Option<ViolationSeverity> {
+        if !is_geno_curie(allelic_state) {
+            return Some(ViolationSeverity::Info);
+        }
+
+        let term_id = TermId::from_str(allelic_state).ok()?;
+        if term_id == self.zygosity || self.geno.is_descendant_of(&term_id, &self.zygosity) {
+            None
+        } else {
+            Some(ViolationSeverity::Error)
+        }
+    }
+}
+
+impl RuleCheck for AllelicStateRule {
+    // The VRS structs of the phenopackets crate do not match the schema, so descriptors are read from the raw tree.
+    type Data<'a> = Single<'a, Value>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        let Some(node) = data.0 else {
+            return violations;
+        };
+        let Some(interpretations) = node.value_at(&Pointer::new("interpretations")) else {
+            return violations;
+        };
+
+        for (interpretation_idx, interpretation) in
+            interpretations.as_array().into_iter().flatten().enumerate()
+        {
+            let Some(genomic_interpretations) = interpretation
+                .pointer("/diagnosis/genomicInterpretations")
+                .and_then(Value::as_array)
+            else {
+                continue;
+            };
+
+            for (idx, genomic_interpretation) in genomic_interpretations.iter().enumerate() {
+                let Some(allelic_state) = genomic_interpretation
+                    .pointer("/variantInterpretation/variationDescriptor/allelicState/id")
+                    .and_then(Value::as_str)
+                else {
+                    continue;
+                };
+
+                let Some(severity) = self.severity_of(allelic_state) else {
+                    continue;
+                };
+
+                let mut allelic_state_ptr = node.pointer().clone();
+                allelic_state_ptr
+                    .down("interpretations")
+                    .down(interpretation_idx)
+                    .down("diagnosis")
+                    .down("genomicInterpretations")
+                    .down(idx)
+                    .down("variantInterpretation")
+                    .down("variationDescriptor")
+                    .down("allelicState");
+
+                violations.push(LintViolation::new(
+                    severity,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(allelic_state_ptr),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "VAR001")]
+struct AllelicStateReport;
+
+impl ReportFromContext for AllelicStateReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(AllelicStateReport))
+    }
+}
+
+impl CompileReport for AllelicStateReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let allelic_state_ptr = lint_violation.first_at();
+        let mut label_ptr = allelic_state_ptr.clone();
+        label_ptr.down("label");
+
+        let mut id_ptr = allelic_state_ptr.clone();
+        id_ptr.down("id");
+
+        let value_of = |ptr: &Pointer| {
+            full_node
+                .value_at(ptr)
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_default()
+        };
+
+        let (message, notes) = if lint_violation.severity() == &ViolationSeverity::Info {
+            (no_ontology_message(&value_of(&id_ptr)), vec![])
+        } else {
+            (
+                format!("'{}' is not an allelic state", value_of(&label_ptr)),
+                vec![format!(
+                    "Allelic states must be descendants of 'zygosity' ({ZYGOSITY})."
+                )],
+            )
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message,
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(allelic_state_ptr).unwrap().clone(),
+                String::default(),
+            )],
+            notes,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::GENO;
+    use crate::tree::node::MaterializedNode;
+    use rstest::rstest;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn phenopacket_with_allelic_state(id: &str) -> MaterializedNode<Value> {
+        let pp = json!({
+            "interpretations": [{
+                "diagnosis": {
+                    "genomicInterpretations": [{
+                        "variantInterpretation": {
+                            "variationDescriptor": {
+                                "allelicState": {"id": id, "label": "label"}
+                            }
+                        }
+                    }]
+                }
+            }]
+        });
+
+        MaterializedNode::new(pp, HashMap::new(), Pointer::at_root())
+    }
+
+    #[rstest]
+    #[case("GENO:0000135", None)]
+    #[case("GENO:0000133", None)]
+    #[case("GENO:0000536", Some(ViolationSeverity::Error))]
+    #[case("HP:0001250", Some(ViolationSeverity::Info))]
+    fn test_find_invalid_allelic_states(
+        #[case] id: &str,
+        #[case] expected: Option<ViolationSeverity>,
+    ) {
+        let rule = AllelicStateRule {
+            geno: GENO.clone(),
+            zygosity: TermId::from_str(ZYGOSITY).unwrap(),
+        };
+        let node = phenopacket_with_allelic_state(id);
+
+        let violations = rule.check(Single(Some(&node)));
+
+        assert_eq!(
+            violations
+                .first()
+                .map(|violation| violation.severity().clone()),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_needs_geno() {
+        let result = AllelicStateRule::from_context(&LinterContext::default());
+
+        assert!(matches!(
+            result,
+            Err(FromContextError::NeedsOntology { .. })
+        ));
+    }
+}
@@ -25,6 +25,7 @@ use serde_json::Value;
 /// ### INTER001
 /// ## What it does
 /// Checks if all diseases found in the interpretation section are also present in the diseases section.
+/// Diagnosis diseases without an ID are reported as errors, because they can not be matched at all.
 ///
 /// ## Why is this bad?
 /// It is expected that the disease section contains all diseases associated with a patient.
@@ -56,17 +57,23 @@ impl RuleCheck for DiseaseConsistencyRule {
             .collect();
 
         for diagnosis in data.0.iter() {
-            if let Some(oc) = &diagnosis.inner.disease
-                && !disease_terms.contains(&(oc.id.as_str(), oc.label.as_str()))
-            {
-                violations.push(LintViolation::new(
-                    ViolationSeverity::Warning,
-                    LintRule::rule_id(self),
-                    NonEmptyVec::with_single_entry(
-                        diagnosis.pointer().clone().down("disease").clone(),
-                    ),
-                ))
-            }
+            let Some(oc) = &diagnosis.inner.disease else {
+                continue;
+            };
+
+            let severity = if oc.id.trim().is_empty() {
+                ViolationSeverity::Error
+            } else if !disease_terms.contains(&(oc.id.as_str(), oc.label.as_str())) {
+                ViolationSeverity::Warning
+            } else {
+                continue;
+            };
+
+            violations.push(LintViolation::new(
+                severity,
+                LintRule::rule_id(self),
+                NonEmptyVec::with_single_entry(diagnosis.pointer().clone().down("disease").clone()),
+            ))
         }
 
         violations
@@ -94,16 +101,25 @@ impl CompileReport for DiseaseConsistencyReport {
             .expect("Interpretation ID should have been there")
             .clone();
 
+        let message = if lint_violation.severity() == &ViolationSeverity::Error {
+            format!(
+                "Disease in interpretation {interpretation_id} has no ID and can not be matched to the diseases section"
+            )
+        } else {
+            format!(
+                "Found disease in interpretation {interpretation_id} that is not present in diseases section"
+            )
+        };
+
         ReportSpecs::from_violation(
-             lint_violation,
-             format!("Found disease in interpretation {interpretation_id} that is not present in diseases section")
-                .to_string(),
-             vec![LabelSpecs::new(
-                 LabelPriority::Primary,
-                 full_node.span_at(&violation_ptr).unwrap().clone(),
+            lint_violation,
+            message,
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(&violation_ptr).unwrap().clone(),
                 String::default(),
-             )],
-             vec![],
+            )],
+            vec![],
         )
     }
 }
@@ -119,6 +135,10 @@ impl PatchFromContext for DiseaseConsistencyPatch {
 
 impl CompilePatches for DiseaseConsistencyPatch {
     fn compile_patches(&self, value: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        if lint_violation.severity() == &ViolationSeverity::Error {
+            return vec![];
+        }
+
         let oc: OntologyClass = serde_json::from_value(
             value
                 .value_at(lint_violation.first_at())
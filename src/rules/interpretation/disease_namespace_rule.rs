@@ -0,0 +1,137 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::resources::find_prefix;
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::{Diagnosis, Disease};
+
+fn normalize_label(label: &str) -> String {
+    label.trim().to_lowercase()
+}
+
+/// ### INTER005
+/// ## What it does
+/// Finds diagnosis diseases in interpretations that describe the same concept as a disease of the
+/// diseases section, but use a different namespace, e.g. `OMIM` in the interpretation and `MONDO`
+/// in the diseases section. Concepts are correlated by their label.
+///
+/// ## Why is this bad?
+/// Tools matching diagnoses to diseases need to cross-map between the namespaces, which is
+/// error-prone. Using a single namespace for a concept keeps the phenopacket consistent.
+#[derive(Debug, Default)]
+#[register_rule(id = "INTER005")]
+pub struct DiseaseNamespaceRule;
+
+impl RuleFromContext for DiseaseNamespaceRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(Self))
+    }
+}
+
+impl RuleCheck for DiseaseNamespaceRule {
+    type Data<'a> = (List<'a, Diagnosis>, List<'a, Disease>);
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for diagnosis in data.0.iter() {
+            let Some(oc) = &diagnosis.inner.disease else {
+                continue;
+            };
+            if oc.label.trim().is_empty()
+                || data.1.iter().any(|disease| {
+                    disease
+                        .inner
+                        .term
+                        .as_ref()
+                        .is_some_and(|term| term.id == oc.id)
+                })
+            {
+                continue;
+            }
+
+            let correlated = data.1.iter().find(|disease| {
+                disease.inner.term.as_ref().is_some_and(|term| {
+                    normalize_label(&term.label) == normalize_label(&oc.label)
+                        && find_prefix(&term.id) != find_prefix(&oc.id)
+                })
+            });
+
+            if let Some(disease) = correlated {
+                let mut diagnosis_ptr = diagnosis.pointer().clone();
+                diagnosis_ptr.down("disease");
+                let mut disease_ptr = disease.pointer().clone();
+                disease_ptr.down("term");
+
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Info,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(diagnosis_ptr, vec![disease_ptr]),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "INTER005")]
+struct DiseaseNamespaceReport;
+
+impl ReportFromContext for DiseaseNamespaceReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(Self))
+    }
+}
+
+impl CompileReport for DiseaseNamespaceReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let diagnosis_ptr = lint_violation.first_at();
+        let disease_ptr = &lint_violation.at()[1];
+
+        let id_at = |ptr: &Pointer| {
+            let mut id_ptr = ptr.clone();
+            id_ptr.down("id");
+            full_node
+                .value_at(&id_ptr)
+                .and_then(|id| id.as_str().map(str::to_string))
+                .unwrap_or_default()
+        };
+        let diagnosis_id = id_at(diagnosis_ptr);
+        let disease_id = id_at(disease_ptr);
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!(
+                "Diagnosis '{diagnosis_id}' uses namespace '{}', while the diseases section uses '{}' for the same concept",
+                find_prefix(&diagnosis_id).unwrap_or(&diagnosis_id),
+                find_prefix(&disease_id).unwrap_or(&disease_id)
+            ),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(diagnosis_ptr).unwrap().clone(),
+                    String::default(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(disease_ptr).unwrap().clone(),
+                    format!("Same concept as '{disease_id}'"),
+                ),
+            ],
+            vec!["Consider harmonizing both sections to a single namespace.".to_string()],
+        )
+    }
+}
@@ -0,0 +1,127 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use std::collections::HashSet;
+
+/// ### INTER007
+/// ## What it does
+/// Flags genomic interpretations whose `subjectOrBiosampleId` references neither the subject
+/// nor any biosample of the phenopacket.
+///
+/// Phenopackets without a subject are skipped. Their references are reported by `SUBJ001`.
+///
+/// ## Why is this bad?
+/// A variant found in a tumor sample has to be traceable to that sample. A dangling reference
+/// usually points to a typo or a biosample that was removed from the phenopacket.
+#[derive(Debug, Default)]
+#[register_rule(id = "INTER007")]
+pub struct GenomicInterpretationBiosampleRule;
+
+impl RuleFromContext for GenomicInterpretationBiosampleRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(GenomicInterpretationBiosampleRule))
+    }
+}
+
+impl RuleCheck for GenomicInterpretationBiosampleRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(node) = data.0 else {
+            return vec![];
+        };
+        let pp = &node.inner;
+
+        let Some(subject) = &pp.subject else {
+            return vec![];
+        };
+
+        let biosample_ids: HashSet<&str> = pp
+            .biosamples
+            .iter()
+            .map(|biosample| biosample.id.as_str())
+            .collect();
+
+        let mut violations = vec![];
+
+        for (i_idx, interpretation) in pp.interpretations.iter().enumerate() {
+            let Some(diagnosis) = &interpretation.diagnosis else {
+                continue;
+            };
+
+            for (g_idx, genomic_interpretation) in
+                diagnosis.genomic_interpretations.iter().enumerate()
+            {
+                let reference = genomic_interpretation.subject_or_biosample_id.as_str();
+                if reference.is_empty()
+                    || reference == subject.id
+                    || biosample_ids.contains(reference)
+                {
+                    continue;
+                }
+
+                let mut ptr = Pointer::new("/interpretations");
+                ptr.down(i_idx)
+                    .down("diagnosis")
+                    .down("genomicInterpretations")
+                    .down(g_idx)
+                    .down("subjectOrBiosampleId");
+
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Error,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "INTER007")]
+struct GenomicInterpretationBiosampleReport;
+
+impl ReportFromContext for GenomicInterpretationBiosampleReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(GenomicInterpretationBiosampleReport))
+    }
+}
+
+impl CompileReport for GenomicInterpretationBiosampleReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let reference_ptr = lint_violation.first_at();
+        let reference = full_node
+            .value_at(reference_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Genomic interpretation references the unknown biosample '{reference}'"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(reference_ptr).unwrap().clone(),
+                "Neither the subject nor a biosample has this id".to_string(),
+            )],
+            vec![
+                "`subjectOrBiosampleId` has to reference the subject or a biosample in the phenopacket."
+                    .to_string(),
+            ],
+        )
+    }
+}
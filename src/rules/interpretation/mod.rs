@@ -1 +1,6 @@
+pub mod allelic_state_rule;
 pub mod disease_consistency_rule;
+pub mod disease_namespace_rule;
+pub mod genomic_interpretation_biosample_rule;
+pub mod progress_status_rule;
+pub mod vrs_allele_interval_rule;
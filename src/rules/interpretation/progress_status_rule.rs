@@ -0,0 +1,107 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::interpretation::ProgressStatus;
+
+/// ### INTER004
+/// ## What it does
+/// Flags interpretations whose `progressStatus` contradicts the presence of a `diagnosis`:
+/// `SOLVED` interpretations without a diagnosis and `UNSOLVED` interpretations with one.
+///
+/// ## Why is this bad?
+/// A solved case is expected to name its diagnosis, while an unsolved case has none yet.
+/// Either the status or the diagnosis is outdated.
+#[derive(Debug, Default)]
+#[register_rule(id = "INTER004")]
+pub struct ProgressStatusRule;
+
+impl RuleFromContext for ProgressStatusRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(ProgressStatusRule))
+    }
+}
+
+impl RuleCheck for ProgressStatusRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(node) = data.0 else {
+            return vec![];
+        };
+
+        node.inner
+            .interpretations
+            .iter()
+            .enumerate()
+            .filter(
+                |(_, interpretation)| match interpretation.progress_status() {
+                    ProgressStatus::Solved => interpretation.diagnosis.is_none(),
+                    ProgressStatus::Unsolved => interpretation.diagnosis.is_some(),
+                    _ => false,
+                },
+            )
+            .map(|(idx, _)| {
+                let mut ptr = Pointer::new("interpretations");
+                ptr.down(idx).down("progressStatus");
+
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "INTER004")]
+struct ProgressStatusReport;
+
+impl ReportFromContext for ProgressStatusReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ProgressStatusReport))
+    }
+}
+
+impl CompileReport for ProgressStatusReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let status_ptr = lint_violation.first_at();
+        let mut diagnosis_ptr = status_ptr.parent().unwrap_or_else(Pointer::at_root);
+        diagnosis_ptr.down("diagnosis");
+
+        let (message, label) = match full_node.span_at(&diagnosis_ptr) {
+            Some(_) => (
+                "Interpretation is UNSOLVED, but has a diagnosis",
+                "Status contradicts the diagnosis",
+            ),
+            None => (
+                "Interpretation is SOLVED, but has no diagnosis",
+                "Solved without a diagnosis",
+            ),
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message.to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(status_ptr).unwrap().clone(),
+                label.to_string(),
+            )],
+            vec!["Update the progressStatus or the diagnosis of the interpretation.".to_string()],
+        )
+    }
+}
@@ -0,0 +1,148 @@
+use crate::LinterContext;
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use crate::tree::vrs_allele::VrsAllele;
+use phenolint_macros::{register_report, register_rule};
+use serde_json::Value;
+
+/// Reads a protobuf `uint64`, which is serialized either as a JSON string or number.
+fn as_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::String(s) => s.parse().ok(),
+        Value::Number(n) => n.as_u64(),
+        _ => None,
+    }
+}
+
+/// Collects all inconsistencies found in the sequence location of a VRS allele.
+fn find_allele_issues(allele: &Value) -> Vec<String> {
+    let mut issues = vec![];
+
+    let Some(location) = allele.get("sequenceLocation") else {
+        return issues;
+    };
+
+    let sequence_id = location
+        .get("sequenceId")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    if sequence_id.trim().is_empty() {
+        issues.push("The sequence location does not reference a sequence id.".to_string());
+    }
+
+    let (start, end) = if let Some(interval) = location.get("sequenceInterval") {
+        (
+            interval.pointer("/startNumber/value").and_then(as_u64),
+            interval.pointer("/endNumber/value").and_then(as_u64),
+        )
+    } else if let Some(interval) = location.get("simpleInterval") {
+        (
+            interval.get("start").and_then(as_u64),
+            interval.get("end").and_then(as_u64),
+        )
+    } else {
+        (None, None)
+    };
+
+    if let (Some(start), Some(end)) = (start, end)
+        && start > end
+    {
+        issues.push(format!(
+            "The interval starts at {start}, which is after its end at {end}."
+        ));
+    }
+
+    issues
+}
+
+/// ### VRS001
+/// ## What it does
+/// Checks that the sequence location of VRS alleles in genomic interpretations is internally consistent.
+/// The interval start must not be greater than its end and the referenced sequence id must not be empty.
+///
+/// ## Why is this bad?
+/// An allele with an inverted interval or without a sequence can not be placed on a reference
+/// and is most likely the result of a conversion error.
+#[derive(Debug, Default)]
+#[register_rule(id = "VRS001")]
+pub struct VrsAlleleIntervalRule;
+
+impl RuleFromContext for VrsAlleleIntervalRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(VrsAlleleIntervalRule))
+    }
+}
+
+impl RuleCheck for VrsAlleleIntervalRule {
+    type Data<'a> = List<'a, VrsAllele>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| !find_allele_issues(&node.inner.0).is_empty())
+            .map(|node| {
+                LintViolation::new(
+                    ViolationSeverity::Error,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(node.pointer().clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "VRS001")]
+struct VrsAlleleIntervalReport;
+
+impl ReportFromContext for VrsAlleleIntervalReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(VrsAlleleIntervalReport))
+    }
+}
+
+impl CompileReport for VrsAlleleIntervalReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let violation_ptr = lint_violation.first_at().clone();
+        let issues = full_node
+            .value_at(&violation_ptr)
+            .map(|allele| find_allele_issues(&allele))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            "VRS allele has an inconsistent sequence location".to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(&violation_ptr).unwrap().clone(),
+                String::default(),
+            )],
+            issues,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_allele_issues;
+    use rstest::rstest;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(json!({"sequenceLocation": {"sequenceId": "refseq:NC_000010.11", "sequenceInterval": {"startNumber": {"value": "100"}, "endNumber": {"value": "101"}}}}), 0)]
+    #[case(json!({"sequenceLocation": {"sequenceId": "refseq:NC_000010.11", "sequenceInterval": {"startNumber": {"value": "101"}, "endNumber": {"value": "100"}}}}), 1)]
+    #[case(json!({"sequenceLocation": {"sequenceId": "refseq:NC_000010.11", "simpleInterval": {"start": 7, "end": 3}}}), 1)]
+    #[case(json!({"sequenceLocation": {"sequenceId": "", "simpleInterval": {"start": "7", "end": "3"}}}), 2)]
+    #[case(json!({"curie": "ga4gh:VA.123"}), 0)]
+    fn test_find_allele_issues(#[case] allele: serde_json::Value, #[case] n_issues: usize) {
+        assert_eq!(find_allele_issues(&allele).len(), n_issues);
+    }
+}
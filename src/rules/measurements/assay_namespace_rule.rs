@@ -0,0 +1,180 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::resources::find_prefix;
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::Measurement;
+
+/// Namespaces accepted for assays, when none are configured in the `LinterContext`.
+const DEFAULT_ASSAY_NAMESPACES: [&str; 2] = ["LOINC", "NCIT"];
+
+/// ### MEAS003
+/// ## What it does
+/// Flags measurements whose `assay` is not from one of the accepted namespaces.
+/// By default `LOINC` and `NCIT` are accepted. The namespaces can be configured in the `LinterContext`.
+///
+/// ## Why is this bad?
+/// An assay describes what was measured, e.g. `LOINC:2345-7 Glucose`. A term like `HP:0003074 Hyperglycemia`
+/// describes an interpretation of the result and belongs to the phenotypic features instead.
+#[derive(Debug)]
+#[register_rule(id = "MEAS003")]
+pub struct AssayNamespaceRule {
+    namespaces: Vec<String>,
+}
+
+impl Default for AssayNamespaceRule {
+    fn default() -> Self {
+        AssayNamespaceRule {
+            namespaces: DEFAULT_ASSAY_NAMESPACES
+                .iter()
+                .map(|namespace| namespace.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl AssayNamespaceRule {
+    fn is_accepted(&self, curie: &str) -> bool {
+        find_prefix(curie).is_some_and(|prefix| {
+            self.namespaces
+                .iter()
+                .any(|namespace| namespace.eq_ignore_ascii_case(prefix))
+        })
+    }
+}
+
+impl RuleFromContext for AssayNamespaceRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let rule = match context.assay_namespaces() {
+            Some(namespaces) => AssayNamespaceRule {
+                namespaces: namespaces.to_vec(),
+            },
+            None => AssayNamespaceRule::default(),
+        };
+
+        Ok(Box::new(rule))
+    }
+}
+
+impl RuleCheck for AssayNamespaceRule {
+    type Data<'a> = List<'a, Measurement>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| {
+                node.inner
+                    .assay
+                    .as_ref()
+                    .is_some_and(|assay| !self.is_accepted(&assay.id))
+            })
+            .map(|node| {
+                let mut assay_ptr = node.pointer().clone();
+                assay_ptr.down("assay");
+
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(assay_ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "MEAS003")]
+struct AssayNamespaceReport;
+
+impl ReportFromContext for AssayNamespaceReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(AssayNamespaceReport))
+    }
+}
+
+impl CompileReport for AssayNamespaceReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let assay_ptr = lint_violation.first_at();
+        let mut id_ptr = assay_ptr.clone();
+        id_ptr.down("id");
+
+        let assay_id = full_node
+            .value_at(&id_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Assay '{assay_id}' is not from an expected namespace"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(assay_ptr).unwrap().clone(),
+                "Unexpected namespace".to_string(),
+            )],
+            vec![
+                "Assays describe what was measured and are usually LOINC or NCIT terms."
+                    .to_string(),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use crate::tree::pointer::Pointer;
+    use phenopackets::schema::v2::core::OntologyClass;
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn measurement(assay_id: &str) -> MaterializedNode<Measurement> {
+        MaterializedNode::new(
+            Measurement {
+                assay: Some(OntologyClass {
+                    id: assay_id.to_string(),
+                    label: "assay".to_string(),
+                }),
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new("/measurements/0"),
+        )
+    }
+
+    #[rstest]
+    #[case("LOINC:2345-7", 0)]
+    #[case("NCIT:C64796", 0)]
+    #[case("loinc:2345-7", 0)]
+    #[case("HP:0003074", 1)]
+    #[case("2345-7", 1)]
+    fn test_find_unexpected_assay_namespace(#[case] assay_id: &str, #[case] n_violations: usize) {
+        let nodes = [measurement(assay_id)];
+
+        let violations = AssayNamespaceRule::default().check(List(&nodes));
+
+        assert_eq!(violations.len(), n_violations);
+    }
+
+    #[test]
+    fn test_configured_assay_namespaces() {
+        let rule = AssayNamespaceRule {
+            namespaces: vec!["HP".to_string()],
+        };
+        let nodes = [measurement("HP:0003074"), measurement("LOINC:2345-7")];
+
+        let violations = rule.check(List(&nodes));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].first_at().position(), "/measurements/0/assay");
+    }
+}
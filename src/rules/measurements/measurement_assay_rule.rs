@@ -0,0 +1,87 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::Measurement;
+
+/// ### MEAS002
+/// ## What it does
+/// Flags measurements with a `value` or `complexValue`, but without an `assay` or with an assay
+/// without an id.
+///
+/// ## Why is this bad?
+/// A measured value can not be interpreted without the assay that produced it. `180 mg/dL` could
+/// be the glucose as well as the cholesterol level of the subject.
+#[derive(Debug, Default)]
+#[register_rule(id = "MEAS002")]
+pub struct MeasurementAssayRule;
+
+impl RuleFromContext for MeasurementAssayRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(MeasurementAssayRule))
+    }
+}
+
+impl RuleCheck for MeasurementAssayRule {
+    type Data<'a> = List<'a, Measurement>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| {
+                node.inner.measurement_value.is_some()
+                    && node
+                        .inner
+                        .assay
+                        .as_ref()
+                        .is_none_or(|assay| assay.id.trim().is_empty())
+            })
+            .map(|node| {
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(node.pointer().clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "MEAS002")]
+struct MeasurementAssayReport;
+
+impl ReportFromContext for MeasurementAssayReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(MeasurementAssayReport))
+    }
+}
+
+impl CompileReport for MeasurementAssayReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let measurement_ptr = lint_violation.first_at();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            "Measurement has a value, but no assay".to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(measurement_ptr).unwrap().clone(),
+                "Missing assay".to_string(),
+            )],
+            vec![
+                "By convention, every measurement names the assay that produced its value as an ontology class, e.g. a LOINC code."
+                    .to_string(),
+            ],
+        )
+    }
+}
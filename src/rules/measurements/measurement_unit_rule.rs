@@ -0,0 +1,233 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::resources::find_prefix;
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::measurement::MeasurementValue;
+use phenopackets::schema::v2::core::value::Value as MeasuredValue;
+use phenopackets::schema::v2::core::{Measurement, Quantity};
+
+/// Namespaces of the ontologies that describe units.
+const UNIT_NAMESPACES: [&str; 3] = ["UO", "UCUM", "NCIT"];
+
+fn is_unit_curie(curie: &str) -> bool {
+    find_prefix(curie).is_some_and(|prefix| {
+        UNIT_NAMESPACES
+            .iter()
+            .any(|namespace| namespace.eq_ignore_ascii_case(prefix))
+    })
+}
+
+/// ### MEAS001
+/// ## What it does
+/// Flags quantities of measurements without a unit, or with a unit that is not an ontology class
+/// from `UO`, `UCUM` or `NCIT`, e.g. `{"id": "mg/dL", "label": "mg/dL"}`.
+///
+/// ## Why is this bad?
+/// Measurements can only be compared, if their units can be converted into each other.
+/// Free text units have to be interpreted by a human first.
+#[derive(Debug, Default)]
+#[register_rule(id = "MEAS001")]
+pub struct MeasurementUnitRule;
+
+impl RuleFromContext for MeasurementUnitRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(MeasurementUnitRule))
+    }
+}
+
+impl MeasurementUnitRule {
+    /// Returns the pointer of the unit, or of the quantity if the unit is missing.
+    fn find_invalid_unit(quantity: &Quantity, quantity_ptr: &Pointer) -> Option<Pointer> {
+        let mut ptr = quantity_ptr.clone();
+        match &quantity.unit {
+            Some(unit) if is_unit_curie(&unit.id) => None,
+            Some(_) => {
+                ptr.down("unit");
+                Some(ptr)
+            }
+            None => Some(ptr),
+        }
+    }
+}
+
+impl RuleCheck for MeasurementUnitRule {
+    type Data<'a> = List<'a, Measurement>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            let mut quantities = vec![];
+            match &node.inner.measurement_value {
+                Some(MeasurementValue::Value(value)) => {
+                    if let Some(MeasuredValue::Quantity(quantity)) = &value.value {
+                        let mut quantity_ptr = node.pointer().clone();
+                        quantity_ptr.down("value").down("quantity");
+                        quantities.push((quantity, quantity_ptr));
+                    }
+                }
+                Some(MeasurementValue::ComplexValue(complex_value)) => {
+                    for (idx, typed_quantity) in complex_value.typed_quantities.iter().enumerate() {
+                        if let Some(quantity) = &typed_quantity.quantity {
+                            let mut quantity_ptr = node.pointer().clone();
+                            quantity_ptr
+                                .down("complexValue")
+                                .down("typedQuantities")
+                                .down(idx)
+                                .down("quantity");
+                            quantities.push((quantity, quantity_ptr));
+                        }
+                    }
+                }
+                None => {}
+            }
+
+            for (quantity, quantity_ptr) in quantities {
+                if let Some(violation_ptr) = Self::find_invalid_unit(quantity, &quantity_ptr) {
+                    violations.push(LintViolation::new(
+                        ViolationSeverity::Warning,
+                        LintRule::rule_id(self),
+                        NonEmptyVec::with_single_entry(violation_ptr),
+                    ));
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "MEAS001")]
+struct MeasurementUnitReport;
+
+impl ReportFromContext for MeasurementUnitReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(MeasurementUnitReport))
+    }
+}
+
+impl CompileReport for MeasurementUnitReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let ptr = lint_violation.first_at();
+
+        let (message, label) = if ptr.get_tip() == "unit" {
+            let mut id_ptr = ptr.clone();
+            id_ptr.down("id");
+            let unit_id = full_node
+                .value_at(&id_ptr)
+                .and_then(|id| id.as_str().map(str::to_string))
+                .unwrap_or_default();
+
+            if unit_id.trim().is_empty() {
+                ("Unit of measurement has no id".to_string(), "Empty id")
+            } else {
+                (
+                    format!("Unit '{unit_id}' is not an ontology class"),
+                    "Expected a unit ontology class",
+                )
+            }
+        } else {
+            (
+                "Quantity of measurement has no unit".to_string(),
+                "Missing unit",
+            )
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message,
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(ptr).unwrap().clone(),
+                label.to_string(),
+            )],
+            vec![format!(
+                "Units are expected to be ontology classes with one of the prefixes: {}.",
+                UNIT_NAMESPACES.join(", ")
+            )],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use phenopackets::schema::v2::core::{ComplexValue, OntologyClass, TypedQuantity, Value};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn quantity(unit_id: Option<&str>) -> Quantity {
+        Quantity {
+            unit: unit_id.map(|id| OntologyClass {
+                id: id.to_string(),
+                label: "unit".to_string(),
+            }),
+            value: 180.0,
+            ..Default::default()
+        }
+    }
+
+    fn measurement(measurement_value: MeasurementValue) -> MaterializedNode<Measurement> {
+        MaterializedNode::new(
+            Measurement {
+                measurement_value: Some(measurement_value),
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new("/measurements/0"),
+        )
+    }
+
+    #[rstest]
+    #[case(Some("UCUM:mg/dL"), None)]
+    #[case(Some("UO:0000022"), None)]
+    #[case(Some("mg/dL"), Some("/measurements/0/value/quantity/unit"))]
+    #[case(Some(""), Some("/measurements/0/value/quantity/unit"))]
+    #[case(None, Some("/measurements/0/value/quantity"))]
+    fn test_find_invalid_unit(#[case] unit_id: Option<&str>, #[case] expected: Option<&str>) {
+        let nodes = [measurement(MeasurementValue::Value(Value {
+            value: Some(MeasuredValue::Quantity(quantity(unit_id))),
+        }))];
+
+        let violations = MeasurementUnitRule.check(List(&nodes));
+
+        let found: Vec<&str> = violations
+            .iter()
+            .map(|violation| violation.first_at().position())
+            .collect();
+        assert_eq!(found, expected.into_iter().collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    fn test_find_invalid_unit_in_complex_value() {
+        let typed_quantity = |unit_id: &str| TypedQuantity {
+            quantity: Some(quantity(Some(unit_id))),
+            ..Default::default()
+        };
+        let nodes = [measurement(MeasurementValue::ComplexValue(ComplexValue {
+            typed_quantities: vec![typed_quantity("UCUM:mm[Hg]"), typed_quantity("mmHg")],
+        }))];
+
+        let violations = MeasurementUnitRule.check(List(&nodes));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].first_at().position(),
+            "/measurements/0/complexValue/typedQuantities/1/quantity/unit"
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod assay_namespace_rule;
+pub mod measurement_assay_rule;
+pub mod measurement_unit_rule;
+pub mod ontology_class_time_observed_rule;
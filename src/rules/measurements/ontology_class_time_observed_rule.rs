@@ -0,0 +1,166 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use serde_json::Value;
+
+/// Time elements that describe a point in time. `*` matches every index of an array.
+const TIMESTAMP_ONLY_POSITIONS: [&[&str]; 2] = [
+    &["measurements", "*", "timeObserved"],
+    &["biosamples", "*", "measurements", "*", "timeObserved"],
+];
+
+/// Collects the pointers of all values matching `segments`, starting at `value`.
+fn collect_matches(value: &Value, ptr: Pointer, segments: &[&str], matches: &mut Vec<Pointer>) {
+    let Some((segment, rest)) = segments.split_first() else {
+        matches.push(ptr);
+        return;
+    };
+
+    match (*segment, value) {
+        ("*", Value::Array(entries)) => {
+            for (idx, entry) in entries.iter().enumerate() {
+                let mut entry_ptr = ptr.clone();
+                entry_ptr.down(idx);
+                collect_matches(entry, entry_ptr, rest, matches);
+            }
+        }
+        (key, Value::Object(map)) => {
+            if let Some(child) = map.get(key) {
+                let mut child_ptr = ptr.clone();
+                child_ptr.down(key);
+                collect_matches(child, child_ptr, rest, matches);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// ### TIME001
+/// ## What it does
+/// Flags time elements given as ontology class in positions that describe a point in time,
+/// like the `timeObserved` of measurements.
+///
+/// ## Why is this bad?
+/// An onset term like `Adult onset` spans decades. A measurement is taken at a specific time,
+/// so its time should be a timestamp, or at least an age.
+#[derive(Debug, Default)]
+#[register_rule(id = "TIME001")]
+pub struct OntologyClassTimeObservedRule;
+
+impl RuleFromContext for OntologyClassTimeObservedRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(OntologyClassTimeObservedRule))
+    }
+}
+
+impl RuleCheck for OntologyClassTimeObservedRule {
+    type Data<'a> = Single<'a, Value>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(root) = data.0 else {
+            return vec![];
+        };
+
+        let mut time_elements = vec![];
+        for position in TIMESTAMP_ONLY_POSITIONS {
+            collect_matches(
+                &root.inner,
+                Pointer::at_root(),
+                position,
+                &mut time_elements,
+            );
+        }
+
+        time_elements
+            .into_iter()
+            .filter(|ptr| {
+                root.inner
+                    .pointer(ptr.position())
+                    .is_some_and(|time_element| time_element.get("ontologyClass").is_some())
+            })
+            .map(|mut ptr| {
+                ptr.down("ontologyClass");
+
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "TIME001")]
+struct OntologyClassTimeObservedReport;
+
+impl ReportFromContext for OntologyClassTimeObservedReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(OntologyClassTimeObservedReport))
+    }
+}
+
+impl CompileReport for OntologyClassTimeObservedReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let oc_ptr = lint_violation.first_at();
+        let mut label_ptr = oc_ptr.clone();
+        label_ptr.down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Ontology class '{label}' used where a point in time is expected"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(oc_ptr).unwrap().clone(),
+                "Expected a timestamp".to_string(),
+            )],
+            vec!["Use a timestamp, or an age if the exact date is unknown.".to_string()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use rstest::rstest;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[rstest]
+    #[case(json!({"measurements": [{"timeObserved": {"ontologyClass": {"id": "HP:0003581", "label": "Adult onset"}}}]}), vec!["/measurements/0/timeObserved/ontologyClass"])]
+    #[case(json!({"biosamples": [{"measurements": [{"timeObserved": {"timestamp": "2020-01-01T00:00:00Z"}}, {"timeObserved": {"ontologyClass": {"id": "HP:0003581", "label": "Adult onset"}}}]}]}), vec!["/biosamples/0/measurements/1/timeObserved/ontologyClass"])]
+    #[case(json!({"phenotypicFeatures": [{"onset": {"ontologyClass": {"id": "HP:0003581", "label": "Adult onset"}}}]}), vec![])]
+    fn test_find_ontology_class_time_observed(
+        #[case] phenopacket: Value,
+        #[case] expected: Vec<&str>,
+    ) {
+        let node = MaterializedNode::new(phenopacket, HashMap::new(), Pointer::at_root());
+
+        let violations = OntologyClassTimeObservedRule.check(Single(Some(&node)));
+
+        let positions: Vec<&str> = violations
+            .iter()
+            .map(|violation| violation.first_at().position())
+            .collect();
+        assert_eq!(positions, expected);
+    }
+}
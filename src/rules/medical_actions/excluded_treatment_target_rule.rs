@@ -0,0 +1,116 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+
+/// ### MA001
+/// ## What it does
+/// Flags medical actions whose `treatmentTarget` is a disease that is marked as excluded.
+///
+/// ## Why is this bad?
+/// Treating a disease the patient was explicitly found not to have is contradictory.
+/// Either the target or the `excluded` flag of the disease is wrong.
+#[derive(Debug, Default)]
+#[register_rule(id = "MA001")]
+pub struct ExcludedTreatmentTargetRule;
+
+impl RuleFromContext for ExcludedTreatmentTargetRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(ExcludedTreatmentTargetRule))
+    }
+}
+
+impl RuleCheck for ExcludedTreatmentTargetRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(phenopacket) = data.0.map(|node| &node.inner) else {
+            return vec![];
+        };
+
+        let mut violations = vec![];
+
+        for (action_idx, medical_action) in phenopacket.medical_actions.iter().enumerate() {
+            let Some(target) = &medical_action.treatment_target else {
+                continue;
+            };
+
+            let excluded_disease = phenopacket.diseases.iter().position(|disease| {
+                disease.excluded
+                    && disease
+                        .term
+                        .as_ref()
+                        .is_some_and(|term| term.id == target.id)
+            });
+
+            if let Some(disease_idx) = excluded_disease {
+                let mut target_ptr = Pointer::new("/medicalActions");
+                target_ptr.down(action_idx).down("treatmentTarget");
+                let mut disease_ptr = Pointer::new("/diseases");
+                disease_ptr.down(disease_idx);
+
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(target_ptr, vec![disease_ptr]),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "MA001")]
+struct ExcludedTreatmentTargetReport;
+
+impl ReportFromContext for ExcludedTreatmentTargetReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ExcludedTreatmentTargetReport))
+    }
+}
+
+impl CompileReport for ExcludedTreatmentTargetReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let target_ptr = lint_violation.first_at();
+        let disease_ptr = &lint_violation.at()[1];
+        let mut label_ptr = target_ptr.clone();
+        label_ptr.down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Medical action targets the excluded disease '{label}'"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(target_ptr).unwrap().clone(),
+                    "Treatment target".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(disease_ptr).unwrap().clone(),
+                    "Disease is excluded here".to_string(),
+                ),
+            ],
+            vec!["Check whether the disease was excluded by mistake or the treatment targets another disease.".to_string()],
+        )
+    }
+}
@@ -0,0 +1 @@
+pub mod excluded_treatment_target_rule;
@@ -0,0 +1,185 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::Resource;
+use serde_json::Value;
+
+/// ### RES002
+/// ## What it does
+/// Flags resources whose namespace prefix only differs in case from the prefix of another resource,
+/// e.g. `HP` and `hp`.
+///
+/// The canonical resource is the one with the most upper case letters in its prefix. On a tie, the
+/// first declared one is kept.
+///
+/// ## Why is this bad?
+/// CURIE prefixes are matched case-sensitively, but both resources describe the same ontology.
+/// Declaring it twice leaves it unclear which version was used.
+#[derive(Debug, Default)]
+#[register_rule(id = "RES002")]
+pub struct CaseDuplicateResourceRule;
+
+impl RuleFromContext for CaseDuplicateResourceRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(CaseDuplicateResourceRule))
+    }
+}
+
+impl RuleCheck for CaseDuplicateResourceRule {
+    type Data<'a> = List<'a, Resource>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let n_upper = |prefix: &str| prefix.chars().filter(|c| c.is_uppercase()).count();
+        let mut violations = vec![];
+
+        for (idx, node) in data.iter().enumerate() {
+            let prefix = node.inner.namespace_prefix.as_str();
+            let canonical = data
+                .iter()
+                .enumerate()
+                .filter(|(_, other)| {
+                    other.inner.namespace_prefix.to_lowercase() == prefix.to_lowercase()
+                })
+                .max_by(|(idx1, res1), (idx2, res2)| {
+                    n_upper(&res1.inner.namespace_prefix)
+                        .cmp(&n_upper(&res2.inner.namespace_prefix))
+                        .then_with(|| idx2.cmp(idx1))
+                });
+
+            if let Some((canonical_idx, canonical)) = canonical
+                && canonical_idx != idx
+                && canonical.inner.namespace_prefix != prefix
+            {
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(
+                        node.pointer().clone(),
+                        vec![canonical.pointer().clone()],
+                    ),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "RES002")]
+struct CaseDuplicateResourceReport;
+
+impl ReportFromContext for CaseDuplicateResourceReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(CaseDuplicateResourceReport))
+    }
+}
+
+impl CaseDuplicateResourceReport {
+    fn prefix_at(full_node: &dyn Node, resource_ptr: &Pointer) -> String {
+        let mut ptr = resource_ptr.clone();
+        ptr.down("namespacePrefix");
+
+        full_node
+            .value_at(&ptr)
+            .and_then(|prefix| prefix.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+}
+
+impl CompileReport for CaseDuplicateResourceReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let duplicate_ptr = lint_violation.first_at();
+        let canonical_ptr = &lint_violation.at()[1];
+
+        let duplicate_prefix = Self::prefix_at(full_node, duplicate_ptr);
+        let canonical_prefix = Self::prefix_at(full_node, canonical_ptr);
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!(
+                "Resource '{duplicate_prefix}' duplicates resource '{canonical_prefix}' up to case"
+            ),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(duplicate_ptr).unwrap().clone(),
+                    "Duplicated resource".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(canonical_ptr).unwrap().clone(),
+                    "Canonical resource".to_string(),
+                ),
+            ],
+            vec![format!(
+                "Declare each namespace once and use '{canonical_prefix}' as prefix for all of its CURIEs."
+            )],
+        )
+    }
+}
+
+#[register_patch(id = "RES002")]
+struct CaseDuplicateResourcePatch;
+
+impl PatchFromContext for CaseDuplicateResourcePatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(CaseDuplicateResourcePatch))
+    }
+}
+
+impl CompilePatches for CaseDuplicateResourcePatch {
+    fn compile_patches(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        let duplicate_ptr = lint_violation.first_at();
+        let canonical_ptr = &lint_violation.at()[1];
+
+        let duplicate = full_node
+            .value_at(duplicate_ptr)
+            .map(|value| value.into_owned());
+        let canonical = full_node
+            .value_at(canonical_ptr)
+            .map(|value| value.into_owned());
+
+        // Fields only the duplicate knows, e.g. its version, are merged into the canonical resource.
+        let mut merged_fields = vec![];
+        if let (Some(Value::Object(duplicate)), Some(Value::Object(canonical))) =
+            (duplicate, canonical)
+        {
+            for (key, value) in duplicate {
+                let is_missing = canonical
+                    .get(&key)
+                    .is_none_or(|canonical_value| canonical_value.as_str() == Some(""));
+
+                if is_missing && value.as_str() != Some("") {
+                    let mut at = canonical_ptr.clone();
+                    at.down(&key);
+                    merged_fields.push(PatchInstruction::Add { at, value });
+                }
+            }
+        }
+
+        vec![Patch::new(NonEmptyVec::with_rest(
+            PatchInstruction::Remove {
+                at: duplicate_ptr.clone(),
+            },
+            merged_fields,
+        ))]
+    }
+}
@@ -0,0 +1,134 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::is_rfc3339_timestamp;
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use serde_json::Value;
+
+/// ### META002
+/// ## What it does
+/// Checks that `metaData.created` is a valid RFC 3339 timestamp, e.g. `2021-05-14T10:35:00Z`.
+/// Empty, missing and otherwise formatted values, e.g. `14.05.2021`, are flagged.
+///
+/// ## Why is this bad?
+/// Phenopackets are exchanged as protobuf messages, where `created` is a timestamp.
+/// A blank or locale formatted value can not be converted and breaks downstream tooling.
+#[derive(Debug, Default)]
+#[register_rule(id = "META002")]
+pub struct CreatedTimestampRule;
+
+impl RuleFromContext for CreatedTimestampRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(CreatedTimestampRule))
+    }
+}
+
+impl RuleCheck for CreatedTimestampRule {
+    type Data<'a> = Single<'a, Value>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let meta_data_ptr = Pointer::at_meta_data();
+        let Some(meta_data) = data
+            .0
+            .and_then(|root| root.inner.pointer(meta_data_ptr.position()))
+        else {
+            return vec![];
+        };
+
+        let mut created_ptr = meta_data_ptr.clone();
+        created_ptr.down("created");
+
+        let violation_ptr = match meta_data.get("created") {
+            Some(Value::String(created)) if is_rfc3339_timestamp(created) => return vec![],
+            Some(_) => created_ptr,
+            None => meta_data_ptr,
+        };
+
+        vec![LintViolation::new(
+            ViolationSeverity::Error,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_single_entry(violation_ptr),
+        )]
+    }
+}
+
+#[register_report(id = "META002")]
+struct CreatedTimestampReport;
+
+impl ReportFromContext for CreatedTimestampReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(CreatedTimestampReport))
+    }
+}
+
+impl CompileReport for CreatedTimestampReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let ptr = lint_violation.first_at();
+
+        let message = if ptr.get_tip() != "created" {
+            "Phenopacket has no creation timestamp".to_string()
+        } else {
+            match full_node.value_at(ptr).as_deref() {
+                Some(Value::String(created)) if created.trim().is_empty() => {
+                    "Creation timestamp is empty".to_string()
+                }
+                Some(Value::String(created)) => {
+                    format!("Creation timestamp '{created}' is not a valid RFC 3339 timestamp")
+                }
+                _ => "Creation timestamp is not a string".to_string(),
+            }
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message,
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(ptr).unwrap().clone(),
+                String::default(),
+            )],
+            vec!["Expected an RFC 3339 timestamp, e.g. '2021-05-14T10:35:00Z'.".to_string()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use rstest::rstest;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[rstest]
+    #[case(json!({"metaData": {"created": "2021-05-14T10:35:00Z"}}), Some("/metaData/created"), 0)]
+    #[case(json!({"metaData": {"created": ""}}), Some("/metaData/created"), 1)]
+    #[case(json!({"metaData": {"created": "14.05.2021 10:35"}}), Some("/metaData/created"), 1)]
+    #[case(json!({"metaData": {"createdBy": "Peter R."}}), Some("/metaData"), 1)]
+    #[case(json!({"id": "no-meta-data"}), None, 0)]
+    fn test_check(
+        #[case] root: Value,
+        #[case] expected_ptr: Option<&str>,
+        #[case] n_violations: usize,
+    ) {
+        let root = MaterializedNode::new(root, HashMap::new(), Pointer::at_root());
+
+        let violations = CreatedTimestampRule.check(Single(Some(&root)));
+
+        assert_eq!(violations.len(), n_violations);
+        if let Some(violation) = violations.first() {
+            assert_eq!(Some(violation.first_at().position()), expected_ptr);
+        }
+    }
+}
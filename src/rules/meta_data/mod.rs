@@ -0,0 +1,6 @@
+pub mod case_duplicate_resource_rule;
+pub mod created_timestamp_rule;
+pub mod resource_shape_rule;
+pub mod resource_version_format_rule;
+pub mod schema_version_rule;
+pub mod updates_chronology_rule;
@@ -0,0 +1,185 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use serde_json::{Map, Value};
+
+const REQUIRED_RESOURCE_KEYS: [&str; 4] = ["id", "name", "url", "namespacePrefix"];
+
+/// An ontology class has an `id` and a `label`, but none of the keys that make up a resource.
+fn is_ontology_class_shaped(entry: &Map<String, Value>) -> bool {
+    entry.contains_key("id")
+        && entry.contains_key("label")
+        && !REQUIRED_RESOURCE_KEYS[1..]
+            .iter()
+            .any(|key| entry.contains_key(*key))
+}
+
+fn missing_resource_keys(entry: &Map<String, Value>) -> Vec<&'static str> {
+    REQUIRED_RESOURCE_KEYS
+        .into_iter()
+        .filter(|key| !entry.contains_key(*key))
+        .collect()
+}
+
+/// ### RES001
+/// ## What it does
+/// Validates that every entry of `metaData.resources` has the keys of a resource
+/// (`id`, `name`, `url` and `namespacePrefix`). Ontology class shaped entries are reported as misplaced.
+///
+/// ## Why is this bad?
+/// Resources document the ontologies used in the phenopacket. An ontology class in their place
+/// usually means that a term was added to the wrong section, while the resource is still missing.
+#[derive(Debug, Default)]
+#[register_rule(id = "RES001")]
+pub struct ResourceShapeRule;
+
+impl RuleFromContext for ResourceShapeRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(ResourceShapeRule))
+    }
+}
+
+impl RuleCheck for ResourceShapeRule {
+    type Data<'a> = Single<'a, Value>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut resources_ptr = Pointer::at_meta_data();
+        resources_ptr.down("resources");
+
+        let Some(resources) = data
+            .0
+            .and_then(|root| root.inner.pointer(resources_ptr.position()))
+            .and_then(Value::as_array)
+        else {
+            return vec![];
+        };
+
+        resources
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
+                entry
+                    .as_object()
+                    .is_none_or(|entry| !missing_resource_keys(entry).is_empty())
+            })
+            .map(|(idx, _)| {
+                let mut entry_ptr = resources_ptr.clone();
+                entry_ptr.down(idx);
+
+                LintViolation::new(
+                    ViolationSeverity::Error,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(entry_ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "RES001")]
+struct ResourceShapeReport;
+
+impl ReportFromContext for ResourceShapeReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ResourceShapeReport))
+    }
+}
+
+impl CompileReport for ResourceShapeReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let entry_ptr = lint_violation.first_at();
+        let entry = full_node
+            .value_at(entry_ptr)
+            .and_then(|entry| entry.as_object().cloned())
+            .unwrap_or_default();
+
+        let (message, notes) = if is_ontology_class_shaped(&entry) {
+            (
+                "Found an ontology class in the resources".to_string(),
+                vec![
+                    "Move the term to its section and add a resource for its ontology instead."
+                        .to_string(),
+                ],
+            )
+        } else {
+            (
+                format!(
+                    "Resource is missing the keys: {}",
+                    missing_resource_keys(&entry).join(", ")
+                ),
+                vec![],
+            )
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message,
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(entry_ptr).unwrap().clone(),
+                String::default(),
+            )],
+            notes,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use rstest::rstest;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn hpo_resource() -> Value {
+        json!({
+            "id": "hp",
+            "name": "human phenotype ontology",
+            "url": "http://purl.obolibrary.org/obo/hp.owl",
+            "version": "2024-04-26",
+            "namespacePrefix": "HP",
+            "iriPrefix": "http://purl.obolibrary.org/obo/HP_"
+        })
+    }
+
+    #[rstest]
+    #[case(json!({"id": "HP:0001250", "label": "Seizure"}), true)]
+    #[case(hpo_resource(), false)]
+    #[case(json!({"id": "hp", "label": "Seizure", "name": "human phenotype ontology"}), false)]
+    fn test_is_ontology_class_shaped(#[case] entry: Value, #[case] expected: bool) {
+        assert_eq!(
+            is_ontology_class_shaped(entry.as_object().unwrap()),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(json!([hpo_resource(), {"id": "HP:0001250", "label": "Seizure"}]), 1)]
+    #[case(json!([{"id": "hp", "name": "human phenotype ontology"}]), 1)]
+    #[case(json!([hpo_resource()]), 0)]
+    fn test_check(#[case] resources: Value, #[case] n_violations: usize) {
+        let root = MaterializedNode::new(
+            json!({"metaData": {"resources": resources}}),
+            HashMap::new(),
+            Pointer::at_root(),
+        );
+
+        let violations = ResourceShapeRule.check(Single(Some(&root)));
+
+        assert_eq!(violations.len(), n_violations);
+    }
+}
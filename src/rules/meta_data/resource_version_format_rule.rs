@@ -0,0 +1,210 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use log::warn;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::Resource;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Ontologies, that are released with a date as version, e.g. `2023-10-09`.
+const DATE_VERSIONED_NAMESPACES: [&str; 9] = [
+    "HP", "MONDO", "UBERON", "GENO", "SO", "PATO", "CL", "MAXO", "ECO",
+];
+
+/// Accepts `2023-10-09` and `v2023-10-09`.
+const DATE_VERSION_FORMAT: &str = r"^v?\d{4}-\d{2}-\d{2}$";
+
+/// ### RES003
+/// ## What it does
+/// Flags resources of date versioned ontologies, e.g. HPO or MONDO, whose `version` is not a release date
+/// like `2023-10-09` or `v2023-10-09`. The accepted formats per namespace can be configured in the `LinterContext`.
+///
+/// ## Why is this bad?
+/// A version like `latest` changes its meaning with every release. The terms of the phenopacket can
+/// no longer be checked against the ontology they were curated with.
+#[derive(Debug)]
+#[register_rule(id = "RES003")]
+pub struct ResourceVersionFormatRule {
+    formats: HashMap<String, Vec<Regex>>,
+}
+
+impl Default for ResourceVersionFormatRule {
+    fn default() -> Self {
+        let date_format = Regex::new(DATE_VERSION_FORMAT).expect("Invalid regex");
+
+        ResourceVersionFormatRule {
+            formats: DATE_VERSIONED_NAMESPACES
+                .iter()
+                .map(|namespace| (namespace.to_string(), vec![date_format.clone()]))
+                .collect(),
+        }
+    }
+}
+
+impl ResourceVersionFormatRule {
+    fn is_accepted(&self, namespace_prefix: &str, version: &str) -> bool {
+        self.formats
+            .get(&namespace_prefix.to_uppercase())
+            .is_none_or(|formats| formats.iter().any(|format| format.is_match(version)))
+    }
+}
+
+impl RuleFromContext for ResourceVersionFormatRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let mut rule = ResourceVersionFormatRule::default();
+
+        for (namespace, formats) in context.resource_version_formats() {
+            let formats = formats
+                .iter()
+                .filter_map(|format| {
+                    let regex = Regex::new(format).ok();
+                    if regex.is_none() {
+                        warn!("Ignoring version format '{format}' of namespace '{namespace}'. It is no valid regex.");
+                    }
+                    regex
+                })
+                .collect();
+            rule.formats.insert(namespace.to_uppercase(), formats);
+        }
+
+        Ok(Box::new(rule))
+    }
+}
+
+impl RuleCheck for ResourceVersionFormatRule {
+    type Data<'a> = List<'a, Resource>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| {
+                !node.inner.version.is_empty()
+                    && !self.is_accepted(&node.inner.namespace_prefix, &node.inner.version)
+            })
+            .map(|node| {
+                let mut version_ptr = node.pointer().clone();
+                version_ptr.down("version");
+
+                LintViolation::new(
+                    ViolationSeverity::Info,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(version_ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "RES003")]
+struct ResourceVersionFormatReport;
+
+impl ReportFromContext for ResourceVersionFormatReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ResourceVersionFormatReport))
+    }
+}
+
+impl CompileReport for ResourceVersionFormatReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let version_ptr = lint_violation.first_at();
+        let mut prefix_ptr = version_ptr.clone();
+        prefix_ptr.up().down("namespacePrefix");
+
+        let string_at = |ptr: &Pointer| {
+            full_node
+                .value_at(ptr)
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_default()
+        };
+        let version = string_at(version_ptr);
+        let namespace_prefix = string_at(&prefix_ptr);
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Version '{version}' of resource '{namespace_prefix}' is not a release"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(version_ptr).unwrap().clone(),
+                "Expected a release date".to_string(),
+            )],
+            vec![
+                "Use the release the terms were curated with, e.g. '2023-10-09', to keep the phenopacket reproducible."
+                    .to_string(),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use rstest::rstest;
+
+    fn resource(idx: usize, namespace_prefix: &str, version: &str) -> MaterializedNode<Resource> {
+        MaterializedNode::new(
+            Resource {
+                id: namespace_prefix.to_lowercase(),
+                name: format!("{namespace_prefix} ontology"),
+                url: format!(
+                    "http://purl.obolibrary.org/obo/{}.owl",
+                    namespace_prefix.to_lowercase()
+                ),
+                version: version.to_string(),
+                namespace_prefix: namespace_prefix.to_string(),
+                iri_prefix: format!("http://purl.obolibrary.org/obo/{namespace_prefix}_"),
+            },
+            HashMap::new(),
+            Pointer::new(&format!("/metaData/resources/{idx}")),
+        )
+    }
+
+    #[rstest]
+    #[case("HP", "2023-10-09", 0)]
+    #[case("HP", "v2023-10-09", 0)]
+    #[case("hp", "latest", 1)]
+    #[case("MONDO", "2023", 1)]
+    #[case("NCIT", "23.09d", 0)]
+    #[case("HP", "", 0)]
+    fn test_find_non_release_versions(
+        #[case] namespace_prefix: &str,
+        #[case] version: &str,
+        #[case] n_violations: usize,
+    ) {
+        let nodes = [resource(0, namespace_prefix, version)];
+
+        let violations = ResourceVersionFormatRule::default().check(List(&nodes));
+
+        assert_eq!(violations.len(), n_violations);
+    }
+
+    #[test]
+    fn test_configured_version_formats() {
+        let mut rule = ResourceVersionFormatRule::default();
+        rule.formats.insert(
+            "NCIT".to_string(),
+            vec![Regex::new(r"^\d{2}\.\d{2}[a-z]$").unwrap()],
+        );
+        let nodes = [resource(0, "NCIT", "23.09d"), resource(1, "NCIT", "latest")];
+
+        let violations = rule.check(List(&nodes));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].first_at().position(),
+            "/metaData/resources/1/version"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use serde_json::Value;
+
+const SCHEMA_VERSION_FIELD: &str = "phenopacketSchemaVersion";
+
+/// Whether the version is `2` or a `2.x` version.
+fn is_v2(version: &str) -> bool {
+    version == "2" || version.starts_with("2.")
+}
+
+/// ### META003
+/// ## What it does
+/// Checks that `metaData.phenopacketSchemaVersion` exists and declares a 2.x version, e.g. `2.0.2`.
+/// Missing, empty and v1 versions like `1.0.0` are flagged.
+///
+/// ## Why is this bad?
+/// The schema validation and all rules of phenolint target version 2 of the phenopacket schema.
+/// A phenopacket declaring another version is either outdated or its version was never set.
+#[derive(Debug, Default)]
+#[register_rule(id = "META003")]
+pub struct SchemaVersionRule;
+
+impl RuleFromContext for SchemaVersionRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(SchemaVersionRule))
+    }
+}
+
+impl RuleCheck for SchemaVersionRule {
+    type Data<'a> = Single<'a, Value>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let meta_data_ptr = Pointer::at_meta_data();
+        let Some(meta_data) = data
+            .0
+            .and_then(|root| root.inner.pointer(meta_data_ptr.position()))
+        else {
+            return vec![];
+        };
+
+        let mut version_ptr = meta_data_ptr.clone();
+        version_ptr.down(SCHEMA_VERSION_FIELD);
+
+        let violation_ptr = match meta_data.get(SCHEMA_VERSION_FIELD) {
+            Some(Value::String(version)) if is_v2(version) => return vec![],
+            Some(_) => version_ptr,
+            None => meta_data_ptr,
+        };
+
+        vec![LintViolation::new(
+            ViolationSeverity::Warning,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_single_entry(violation_ptr),
+        )]
+    }
+}
+
+#[register_report(id = "META003")]
+struct SchemaVersionReport;
+
+impl ReportFromContext for SchemaVersionReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(SchemaVersionReport))
+    }
+}
+
+impl CompileReport for SchemaVersionReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let ptr = lint_violation.first_at();
+
+        let message = if ptr.get_tip() != SCHEMA_VERSION_FIELD {
+            "Phenopacket does not declare its schema version".to_string()
+        } else {
+            match full_node.value_at(ptr).as_deref() {
+                Some(Value::String(version)) if version.trim().is_empty() => {
+                    "Phenopacket schema version is empty".to_string()
+                }
+                Some(Value::String(version)) => {
+                    format!("Phenopacket schema version '{version}' is not a 2.x version")
+                }
+                _ => "Phenopacket schema version is not a string".to_string(),
+            }
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message,
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(ptr).unwrap().clone(),
+                String::default(),
+            )],
+            vec!["Phenolint validates and lints version 2 of the phenopacket schema.".to_string()],
+        )
+    }
+}
+
+/// Sets the schema version to the one configured via `LinterContext::with_default_schema_version`.
+#[register_patch(id = "META003")]
+struct SchemaVersionPatch {
+    default_version: String,
+}
+
+impl PatchFromContext for SchemaVersionPatch {
+    fn from_context(
+        context: &LinterContext,
+    ) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(SchemaVersionPatch {
+            default_version: context.default_schema_version().to_string(),
+        }))
+    }
+}
+
+impl CompilePatches for SchemaVersionPatch {
+    fn compile_patches(&self, _: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        let ptr = lint_violation.first_at().clone();
+        let value = Value::String(self.default_version.clone());
+
+        let instruction = if ptr.get_tip() == SCHEMA_VERSION_FIELD {
+            PatchInstruction::Replace { at: ptr, value }
+        } else {
+            let mut version_ptr = ptr;
+            version_ptr.down(SCHEMA_VERSION_FIELD);
+            PatchInstruction::Add {
+                at: version_ptr,
+                value,
+            }
+        };
+
+        vec![Patch::new(NonEmptyVec::with_single_entry(instruction))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use rstest::rstest;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[rstest]
+    #[case(json!({"metaData": {"phenopacketSchemaVersion": "2.0.2"}}), None)]
+    #[case(json!({"metaData": {"phenopacketSchemaVersion": "2"}}), None)]
+    #[case(json!({"metaData": {"phenopacketSchemaVersion": "1.0.0"}}), Some("/metaData/phenopacketSchemaVersion"))]
+    #[case(json!({"metaData": {"phenopacketSchemaVersion": "20"}}), Some("/metaData/phenopacketSchemaVersion"))]
+    #[case(json!({"metaData": {"phenopacketSchemaVersion": ""}}), Some("/metaData/phenopacketSchemaVersion"))]
+    #[case(json!({"metaData": {"createdBy": "Peter R."}}), Some("/metaData"))]
+    #[case(json!({"id": "no-meta-data"}), None)]
+    fn test_check(#[case] root: Value, #[case] expected_ptr: Option<&str>) {
+        let root = MaterializedNode::new(root, HashMap::new(), Pointer::at_root());
+
+        let violations = SchemaVersionRule.check(Single(Some(&root)));
+
+        assert_eq!(
+            violations
+                .first()
+                .map(|violation| violation.first_at().position()),
+            expected_ptr
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+
+/// ### META001
+/// ## What it does
+/// Checks that the timestamps of `metaData.updates` are in chronological order and
+/// that no update precedes the creation of the phenopacket.
+///
+/// ## Why is this bad?
+/// An update can not happen before the phenopacket was created or before a previous update.
+/// Such timestamps are usually the result of a wrong timezone or a typo and make the history of
+/// the phenopacket unreliable.
+#[derive(Debug, Default)]
+#[register_rule(id = "META001")]
+pub struct UpdatesChronologyRule;
+
+impl RuleFromContext for UpdatesChronologyRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(UpdatesChronologyRule))
+    }
+}
+
+impl RuleCheck for UpdatesChronologyRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        let Some(meta_data) = data.0.and_then(|node| node.inner.meta_data.as_ref()) else {
+            return violations;
+        };
+
+        let mut created_ptr = Pointer::at_meta_data();
+        created_ptr.down("created");
+
+        let mut latest = meta_data
+            .created
+            .as_ref()
+            .map(|created| ((created.seconds, created.nanos), created_ptr));
+
+        for (idx, update) in meta_data.updates.iter().enumerate() {
+            let Some(timestamp) = update.timestamp.as_ref() else {
+                continue;
+            };
+            let timestamp = (timestamp.seconds, timestamp.nanos);
+
+            let mut update_ptr = Pointer::at_meta_data();
+            update_ptr.down("updates").down(idx).down("timestamp");
+
+            match &latest {
+                Some((latest_timestamp, latest_ptr)) if timestamp < *latest_timestamp => {
+                    violations.push(LintViolation::new(
+                        ViolationSeverity::Warning,
+                        LintRule::rule_id(self),
+                        NonEmptyVec::with_rest(update_ptr, vec![latest_ptr.clone()]),
+                    ));
+                }
+                _ => latest = Some((timestamp, update_ptr)),
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "META001")]
+struct UpdatesChronologyReport;
+
+impl ReportFromContext for UpdatesChronologyReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(UpdatesChronologyReport))
+    }
+}
+
+impl CompileReport for UpdatesChronologyReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let update_ptr = lint_violation.first_at();
+        let mut labels = vec![LabelSpecs::new(
+            LabelPriority::Primary,
+            full_node.span_at(update_ptr).unwrap().clone(),
+            String::default(),
+        )];
+
+        let message = match lint_violation.at().get(1) {
+            Some(reference_ptr) => {
+                let is_created = reference_ptr.get_tip() == "created";
+                labels.push(LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(reference_ptr).unwrap().clone(),
+                    if is_created {
+                        "Phenopacket was created here".to_string()
+                    } else {
+                        "Previous update".to_string()
+                    },
+                ));
+
+                if is_created {
+                    "Update precedes the creation of the phenopacket"
+                } else {
+                    "Update precedes a previous update"
+                }
+            }
+            None => "Update is out of order",
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message.to_string(),
+            labels,
+            vec![
+                "Updates should be listed in chronological order and after the creation date."
+                    .to_string(),
+            ],
+        )
+    }
+}
@@ -1,8 +1,18 @@
+pub mod biosamples;
+pub mod cohort;
 pub mod curies;
+pub mod disease;
+pub mod enums;
+pub mod files;
 pub mod interpretation;
+pub mod measurements;
+pub mod medical_actions;
+pub mod meta_data;
 pub mod phenotypic_features;
 mod resources;
 pub mod rule_registration;
 pub mod rule_registry;
+pub mod structure;
+pub mod subject;
 pub mod traits;
 pub(super) mod utils;
@@ -0,0 +1,239 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::is_hpo_curie;
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use ontolius::TermId;
+use ontolius::ontology::HierarchyQueries;
+use ontolius::ontology::csr::FullCsrOntology;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::time_element::Element;
+use phenopackets::schema::v2::core::{Disease, PhenotypicFeature, TimeElement};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// ### PF013
+/// ## What it does
+/// Flags observed phenotypic features whose HPO onset lies in a different branch of the
+/// `Onset` hierarchy than the onset of every disease.
+///
+/// A feature onset that refines the disease onset, e.g. `Middle age onset` for a disease with
+/// `Adult onset`, or that is refined by it, is consistent and not flagged.
+///
+/// ## Why is this bad?
+/// A phenotype with `Childhood onset` in a patient with a disease of `Adult onset` contradicts itself.
+/// One of the two onsets was most likely mistyped or copied from another case.
+#[derive(Debug)]
+#[register_rule(id = "PF013")]
+pub struct ContradictingOnsetRule {
+    hpo: Arc<FullCsrOntology>,
+}
+
+impl RuleFromContext for ContradictingOnsetRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let Some(hpo) = context.hpo() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "PF013".to_string(),
+                ontology: "HPO".to_string(),
+            });
+        };
+
+        Ok(Box::new(ContradictingOnsetRule { hpo }))
+    }
+}
+
+impl ContradictingOnsetRule {
+    fn onset_term(onset: Option<&TimeElement>) -> Option<TermId> {
+        let Some(Element::OntologyClass(oc)) = &onset?.element else {
+            return None;
+        };
+
+        if !is_hpo_curie(&oc.id) {
+            return None;
+        }
+
+        TermId::from_str(&oc.id).ok()
+    }
+
+    fn is_consistent(&self, feature_onset: &TermId, disease_onset: &TermId) -> bool {
+        feature_onset == disease_onset
+            || self.hpo.is_ancestor_of(disease_onset, feature_onset)
+            || self.hpo.is_ancestor_of(feature_onset, disease_onset)
+    }
+}
+
+impl RuleCheck for ContradictingOnsetRule {
+    type Data<'a> = (List<'a, PhenotypicFeature>, List<'a, Disease>);
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let disease_onsets: Vec<(Pointer, TermId)> = data
+            .1
+            .iter()
+            .filter(|node| !node.inner.excluded)
+            .filter_map(|node| {
+                let term_id = Self::onset_term(node.inner.onset.as_ref())?;
+                let mut ptr = node.pointer().clone();
+                ptr.down("onset").down("ontologyClass");
+                Some((ptr, term_id))
+            })
+            .collect();
+
+        if disease_onsets.is_empty() {
+            return vec![];
+        }
+
+        let mut violations = vec![];
+
+        for node in data.0.iter().filter(|node| !node.inner.excluded) {
+            let Some(feature_onset) = Self::onset_term(node.inner.onset.as_ref()) else {
+                continue;
+            };
+
+            if disease_onsets
+                .iter()
+                .any(|(_, disease_onset)| self.is_consistent(&feature_onset, disease_onset))
+            {
+                continue;
+            }
+
+            let mut feature_onset_ptr = node.pointer().clone();
+            feature_onset_ptr.down("onset").down("ontologyClass");
+            let (disease_onset_ptr, _) = &disease_onsets[0];
+
+            violations.push(LintViolation::new(
+                ViolationSeverity::Warning,
+                LintRule::rule_id(self),
+                NonEmptyVec::with_rest(feature_onset_ptr, vec![disease_onset_ptr.clone()]),
+            ));
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF013")]
+struct ContradictingOnsetReport;
+
+impl ReportFromContext for ContradictingOnsetReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ContradictingOnsetReport))
+    }
+}
+
+impl ContradictingOnsetReport {
+    fn label_at(full_node: &dyn Node, onset_ptr: &Pointer) -> String {
+        let mut ptr = onset_ptr.clone();
+        ptr.down("label");
+
+        full_node
+            .value_at(&ptr)
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+}
+
+impl CompileReport for ContradictingOnsetReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let feature_onset_ptr = lint_violation.first_at();
+        let disease_onset_ptr = &lint_violation.at()[1];
+
+        let feature_onset = Self::label_at(full_node, feature_onset_ptr);
+        let disease_onset = Self::label_at(full_node, disease_onset_ptr);
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!(
+                "Phenotype onset '{feature_onset}' contradicts the disease onset '{disease_onset}'"
+            ),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(feature_onset_ptr).unwrap().clone(),
+                    "Onset of the phenotype".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(disease_onset_ptr).unwrap().clone(),
+                    "Onset of the disease".to_string(),
+                ),
+            ],
+            vec![
+                "Neither onset is a refinement of the other. Check which of the two is correct."
+                    .to_string(),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::HPO;
+    use crate::tree::node::MaterializedNode;
+    use phenopackets::schema::v2::core::OntologyClass;
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn onset(id: &str, label: &str) -> Option<TimeElement> {
+        Some(TimeElement {
+            element: Some(Element::OntologyClass(OntologyClass {
+                id: id.to_string(),
+                label: label.to_string(),
+            })),
+        })
+    }
+
+    #[rstest]
+    #[case("HP:0003596", "Middle age onset", 0)]
+    #[case("HP:0003581", "Adult onset", 0)]
+    #[case("HP:0003674", "Onset", 0)]
+    #[case("HP:0011463", "Childhood onset", 1)]
+    #[case("HP:0003577", "Congenital onset", 1)]
+    fn test_find_contradicting_onsets(
+        #[case] id: &str,
+        #[case] label: &str,
+        #[case] n_violations: usize,
+    ) {
+        let rule = ContradictingOnsetRule { hpo: HPO.clone() };
+
+        let features = [MaterializedNode::new(
+            PhenotypicFeature {
+                r#type: Some(OntologyClass {
+                    id: "HP:0001250".to_string(),
+                    label: "Seizure".to_string(),
+                }),
+                onset: onset(id, label),
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new("/phenotypicFeatures/0"),
+        )];
+        let diseases = [MaterializedNode::new(
+            Disease {
+                term: Some(OntologyClass {
+                    id: "MONDO:0005027".to_string(),
+                    label: "epilepsy".to_string(),
+                }),
+                onset: onset("HP:0003581", "Adult onset"),
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new("/diseases/0"),
+        )];
+
+        let violations = rule.check((List(&features), List(&diseases)));
+
+        assert_eq!(violations.len(), n_violations);
+    }
+}
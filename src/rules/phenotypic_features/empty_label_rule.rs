@@ -0,0 +1,181 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use ontolius::TermId;
+use ontolius::ontology::OntologyTerms;
+use ontolius::ontology::csr::FullCsrOntology;
+use ontolius::term::MinimalTerm;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::OntologyClass;
+use serde_json::Value;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Returns true if `ptr` points at the `type` of a phenotypic feature.
+fn is_feature_type(ptr: &Pointer) -> bool {
+    ptr.get_tip() == "type"
+        && ptr
+            .parent()
+            .and_then(|feature_ptr| feature_ptr.parent())
+            .is_some_and(|features_ptr| features_ptr.get_tip() == "phenotypicFeatures")
+}
+
+/// ### PF009
+/// ## What it does
+/// Flags phenotypic features whose `type` has an empty or whitespace-only label.
+///
+/// ## Why is this bad?
+/// Without a label, every display of the phenopacket shows a bare id. An empty label also often
+/// hints at a broken annotation pipeline, which dropped the labels of other terms as well.
+#[derive(Debug, Default)]
+#[register_rule(id = "PF009")]
+pub struct EmptyLabelRule;
+
+impl RuleFromContext for EmptyLabelRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(EmptyLabelRule))
+    }
+}
+
+impl RuleCheck for EmptyLabelRule {
+    type Data<'a> = List<'a, OntologyClass>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| is_feature_type(node.pointer()) && node.inner.label.trim().is_empty())
+            .map(|node| {
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(node.pointer().clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "PF009")]
+struct EmptyLabelReport;
+
+impl ReportFromContext for EmptyLabelReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(EmptyLabelReport))
+    }
+}
+
+impl CompileReport for EmptyLabelReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let type_ptr = lint_violation.first_at();
+        let mut id_ptr = type_ptr.clone();
+        id_ptr.down("id");
+
+        let term_id = full_node
+            .value_at(&id_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Phenotypic feature '{term_id}' has an empty label"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(type_ptr).unwrap().clone(),
+                "Expected the label of the term".to_string(),
+            )],
+            vec![
+                "Check whether the pipeline producing the phenopacket dropped the labels."
+                    .to_string(),
+            ],
+        )
+    }
+}
+
+/// Fills in the label of the term, if the HPO is available and contains it.
+#[register_patch(id = "PF009")]
+struct EmptyLabelPatch {
+    hpo: Option<Arc<FullCsrOntology>>,
+}
+
+impl PatchFromContext for EmptyLabelPatch {
+    fn from_context(
+        context: &LinterContext,
+    ) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(EmptyLabelPatch { hpo: context.hpo() }))
+    }
+}
+
+impl CompilePatches for EmptyLabelPatch {
+    fn compile_patches(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        let Some(hpo) = &self.hpo else {
+            return vec![];
+        };
+
+        let type_ptr = lint_violation.first_at();
+        let mut id_ptr = type_ptr.clone();
+        id_ptr.down("id");
+
+        let Some(term) = full_node
+            .value_at(&id_ptr)
+            .and_then(|id| id.as_str().and_then(|id| TermId::from_str(id).ok()))
+            .and_then(|term_id| hpo.term_by_id(&term_id))
+        else {
+            return vec![];
+        };
+
+        let mut label_ptr = type_ptr.clone();
+        label_ptr.down("label");
+
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Replace {
+                at: label_ptr,
+                value: Value::String(term.name().to_string()),
+            },
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[rstest]
+    #[case("/phenotypicFeatures/0/type", " ", 1)]
+    #[case("/phenotypicFeatures/0/type", "Seizure", 0)]
+    #[case("/biosamples/0/phenotypicFeatures/1/type", "", 1)]
+    #[case("/phenotypicFeatures/0/severity", " ", 0)]
+    #[case("/diseases/0/term", " ", 0)]
+    fn test_find_empty_labels(#[case] ptr: &str, #[case] label: &str, #[case] n_violations: usize) {
+        let nodes = [MaterializedNode::new(
+            OntologyClass {
+                id: "HP:0001250".to_string(),
+                label: label.to_string(),
+            },
+            HashMap::new(),
+            Pointer::new(ptr),
+        )];
+
+        let violations = EmptyLabelRule.check(List(&nodes));
+
+        assert_eq!(violations.len(), n_violations);
+    }
+}
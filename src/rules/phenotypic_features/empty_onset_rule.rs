@@ -0,0 +1,160 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+
+/// ### PF012
+/// ## What it does
+/// Flags phenotypic features whose `onset` is present but carries none of the time elements
+/// (`age`, `ageRange`, `ontologyClass`, `timestamp`, ...).
+///
+/// ## Why is this bad?
+/// An empty onset looks like the onset was recorded, while it carries no information at all.
+/// Downstream tools either choke on it or silently treat it as unknown.
+#[derive(Debug, Default)]
+#[register_rule(id = "PF012")]
+pub struct EmptyOnsetRule;
+
+impl RuleFromContext for EmptyOnsetRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(EmptyOnsetRule))
+    }
+}
+
+impl RuleCheck for EmptyOnsetRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            let Some(onset) = node.inner.onset.as_ref() else {
+                continue;
+            };
+
+            if onset.element.is_none() {
+                let mut ptr = node.pointer().clone();
+                ptr.down("onset");
+
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF012")]
+struct EmptyOnsetReport;
+
+impl ReportFromContext for EmptyOnsetReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(EmptyOnsetReport))
+    }
+}
+
+impl CompileReport for EmptyOnsetReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let onset_ptr = lint_violation.first_at();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            "Phenotypic feature has an empty onset".to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(onset_ptr).unwrap().clone(),
+                "Onset has no age, age range, term, timestamp or interval".to_string(),
+            )],
+            vec![],
+        )
+    }
+}
+
+#[register_patch(id = "PF012")]
+struct EmptyOnsetPatch;
+
+impl PatchFromContext for EmptyOnsetPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(EmptyOnsetPatch))
+    }
+}
+
+impl CompilePatches for EmptyOnsetPatch {
+    fn compile_patches(&self, _: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Remove {
+                at: lint_violation.first_at().clone(),
+            },
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use crate::tree::pointer::Pointer;
+    use phenopackets::schema::v2::core::time_element::Element;
+    use phenopackets::schema::v2::core::{OntologyClass, TimeElement};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[rstest]
+    #[case(TimeElement { element: None }, 1)]
+    #[case(
+        TimeElement {
+            element: Some(Element::OntologyClass(OntologyClass {
+                id: "HP:0003577".to_string(),
+                label: "Congenital onset".to_string(),
+            })),
+        },
+        0
+    )]
+    fn test_find_empty_onsets(#[case] onset: TimeElement, #[case] n_violations: usize) {
+        let mut ptr = Pointer::at_phenotypes();
+        ptr.down(0);
+        let nodes = [MaterializedNode::new(
+            PhenotypicFeature {
+                r#type: Some(OntologyClass {
+                    id: "HP:0001733".to_string(),
+                    label: "Pancreatitis".to_string(),
+                }),
+                onset: Some(onset),
+                ..Default::default()
+            },
+            HashMap::new(),
+            ptr,
+        )];
+
+        let violations = EmptyOnsetRule.check(List(&nodes));
+
+        assert_eq!(violations.len(), n_violations);
+        if let Some(violation) = violations.first() {
+            assert_eq!(
+                violation.first_at().position(),
+                "/phenotypicFeatures/0/onset"
+            );
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+
+/// ### EVID002
+/// ## What it does
+/// Flags excluded phenotypic features that carry `evidence` entries.
+///
+/// ## Why is this bad?
+/// Evidence documents how a phenotype was observed. An excluded phenotype was explicitly not
+/// observed, so the evidence either contradicts the exclusion or the `excluded` flag is wrong.
+#[derive(Debug, Default)]
+#[register_rule(id = "EVID002")]
+pub struct ExcludedWithEvidenceRule;
+
+impl RuleFromContext for ExcludedWithEvidenceRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(ExcludedWithEvidenceRule))
+    }
+}
+
+impl RuleCheck for ExcludedWithEvidenceRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| node.inner.excluded && !node.inner.evidence.is_empty())
+            .map(|node| {
+                let mut ptr = node.pointer().clone();
+                ptr.down("evidence");
+
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "EVID002")]
+struct ExcludedWithEvidenceReport;
+
+impl ReportFromContext for ExcludedWithEvidenceReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ExcludedWithEvidenceReport))
+    }
+}
+
+impl CompileReport for ExcludedWithEvidenceReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let evidence_ptr = lint_violation.first_at().clone();
+        let mut excluded_ptr = evidence_ptr.clone();
+        excluded_ptr.up().down("excluded");
+        let mut label_ptr = evidence_ptr.clone();
+        label_ptr.up().down("type").down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Excluded phenotypic feature '{label}' carries evidence"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(&evidence_ptr).unwrap().clone(),
+                    "Evidence of an observation".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(&excluded_ptr).unwrap().clone(),
+                    "Phenotype is excluded here".to_string(),
+                ),
+            ],
+            vec![],
+        )
+    }
+}
@@ -0,0 +1,134 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+
+/// ### PF017
+/// ## What it does
+/// Flags excluded phenotypic features that carry an `onset`, a `severity` or `modifiers`.
+/// Each qualifier is reported on its own.
+///
+/// ## Why is this bad?
+/// An excluded phenotype was not observed, so it can not have an onset or a severity.
+/// Either the qualifier is a leftover, or the `excluded` flag is wrong.
+#[derive(Debug, Default)]
+#[register_rule(id = "PF017")]
+pub struct ExcludedWithQualifierRule;
+
+impl RuleFromContext for ExcludedWithQualifierRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(ExcludedWithQualifierRule))
+    }
+}
+
+impl RuleCheck for ExcludedWithQualifierRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter().filter(|node| node.inner.excluded) {
+            let qualifiers = [
+                ("onset", node.inner.onset.is_some()),
+                ("severity", node.inner.severity.is_some()),
+                ("modifiers", !node.inner.modifiers.is_empty()),
+            ];
+
+            for (field, _) in qualifiers.iter().filter(|(_, is_set)| *is_set) {
+                let mut excluded_ptr = node.pointer().clone();
+                excluded_ptr.down("excluded");
+                let mut qualifier_ptr = node.pointer().clone();
+                qualifier_ptr.down(field);
+
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(excluded_ptr, vec![qualifier_ptr]),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF017")]
+struct ExcludedWithQualifierReport;
+
+impl ReportFromContext for ExcludedWithQualifierReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ExcludedWithQualifierReport))
+    }
+}
+
+impl CompileReport for ExcludedWithQualifierReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let excluded_ptr = lint_violation.first_at();
+        let qualifier_ptr = &lint_violation.at()[1];
+        let mut label_ptr = excluded_ptr.clone();
+        label_ptr.up().down("type").down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let qualifier = qualifier_ptr.get_tip();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Excluded phenotypic feature '{label}' has a {qualifier}"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(excluded_ptr).unwrap().clone(),
+                    "Phenotype is excluded here".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(qualifier_ptr).unwrap().clone(),
+                    format!("{qualifier} of an absent phenotype"),
+                ),
+            ],
+            vec![
+                "Remove the qualifier, or set `excluded` to false if the phenotype was observed."
+                    .to_string(),
+            ],
+        )
+    }
+}
+
+#[register_patch(id = "PF017")]
+struct ExcludedWithQualifierPatch;
+
+impl PatchFromContext for ExcludedWithQualifierPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(ExcludedWithQualifierPatch))
+    }
+}
+
+impl CompilePatches for ExcludedWithQualifierPatch {
+    fn compile_patches(&self, _: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Remove {
+                at: lint_violation.at()[1].clone(),
+            },
+        ))]
+    }
+}
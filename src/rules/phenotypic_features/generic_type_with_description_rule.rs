@@ -0,0 +1,137 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+
+/// The HPO root, `Phenotypic abnormality` and its organ system categories.
+const GENERIC_HPO_TERMS: [&str; 28] = [
+    "HP:0000001",
+    "HP:0000118",
+    "HP:0000119",
+    "HP:0000152",
+    "HP:0000478",
+    "HP:0000598",
+    "HP:0000707",
+    "HP:0000769",
+    "HP:0000818",
+    "HP:0001197",
+    "HP:0001507",
+    "HP:0001574",
+    "HP:0001608",
+    "HP:0001626",
+    "HP:0001871",
+    "HP:0001939",
+    "HP:0002086",
+    "HP:0002664",
+    "HP:0002715",
+    "HP:0003011",
+    "HP:0003549",
+    "HP:0025031",
+    "HP:0025142",
+    "HP:0025354",
+    "HP:0033127",
+    "HP:0040064",
+    "HP:0045027",
+    "HP:0100547",
+];
+
+/// Descriptions with at least this many characters are considered detailed.
+const MIN_DESCRIPTION_LENGTH: usize = 40;
+
+/// ### PF011
+/// ## What it does
+/// Flags phenotypic features that carry a long free-text `description` but only a very general
+/// `type`, like `Phenotypic abnormality` or one of the organ system categories.
+/// This is a heuristic and therefore not enabled by default.
+///
+/// ## Why is this bad?
+/// The description likely contains information that could be captured by a more specific HPO term.
+/// Free text can not be used computationally, whereas a specific term can.
+#[derive(Debug)]
+#[register_rule(id = "PF011")]
+pub struct GenericTypeWithDescriptionRule {
+    min_description_length: usize,
+}
+
+impl RuleFromContext for GenericTypeWithDescriptionRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(GenericTypeWithDescriptionRule {
+            min_description_length: MIN_DESCRIPTION_LENGTH,
+        }))
+    }
+}
+
+impl RuleCheck for GenericTypeWithDescriptionRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| {
+                node.inner
+                    .r#type
+                    .as_ref()
+                    .is_some_and(|oc| GENERIC_HPO_TERMS.contains(&oc.id.as_str()))
+                    && node.inner.description.trim().chars().count() >= self.min_description_length
+            })
+            .map(|node| {
+                let mut ptr = node.pointer().clone();
+                ptr.down("type");
+
+                LintViolation::new(
+                    ViolationSeverity::Info,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "PF011")]
+struct GenericTypeWithDescriptionReport;
+
+impl ReportFromContext for GenericTypeWithDescriptionReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(GenericTypeWithDescriptionReport))
+    }
+}
+
+impl CompileReport for GenericTypeWithDescriptionReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let type_ptr = lint_violation.first_at();
+        let mut description_ptr = type_ptr.clone();
+        description_ptr.up().down("description");
+
+        let mut labels = vec![LabelSpecs::new(
+            LabelPriority::Primary,
+            full_node.span_at(type_ptr).unwrap().clone(),
+            "General term".to_string(),
+        )];
+        if let Some(span) = full_node.span_at(&description_ptr) {
+            labels.push(LabelSpecs::new(
+                LabelPriority::Secondary,
+                span.clone(),
+                "Detailed description".to_string(),
+            ));
+        }
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            "Phenotypic feature uses a general term, but is described in detail".to_string(),
+            labels,
+            vec!["Consider replacing the type with a more specific HPO term that captures the description.".to_string()],
+        )
+    }
+}
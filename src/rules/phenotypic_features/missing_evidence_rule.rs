@@ -0,0 +1,86 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+
+/// ### EVID001
+/// ## What it does
+/// Flags observed phenotypic features that do not carry a single `evidence` entry.
+/// This rule is meant for curation standards that require evidence and is therefore not enabled by default.
+///
+/// ## Why is this bad?
+/// Without evidence, it can not be traced back how an observed phenotype was established.
+#[derive(Debug, Default)]
+#[register_rule(id = "EVID001")]
+pub struct MissingEvidenceRule;
+
+impl RuleFromContext for MissingEvidenceRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(MissingEvidenceRule))
+    }
+}
+
+impl RuleCheck for MissingEvidenceRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| !node.inner.excluded && node.inner.evidence.is_empty())
+            .map(|node| {
+                LintViolation::new(
+                    ViolationSeverity::Info,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(node.pointer().clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "EVID001")]
+struct MissingEvidenceReport;
+
+impl ReportFromContext for MissingEvidenceReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(MissingEvidenceReport))
+    }
+}
+
+impl CompileReport for MissingEvidenceReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let feature_ptr = lint_violation.first_at().clone();
+        let mut label_ptr = feature_ptr.clone();
+        label_ptr.down("type").down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Observed phenotypic feature '{label}' has no evidence"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(&feature_ptr).unwrap().clone(),
+                String::default(),
+            )],
+            vec![
+                "Add at least one evidence entry to document how this phenotype was observed."
+                    .to_string(),
+            ],
+        )
+    }
+}
@@ -1,9 +1,24 @@
-/*mod modifier_ontology_child_rule;
-mod observed_ancestor_rule;
-mod observed_ancestor_with_excluded_descendants_rule;
-mod onset_ontology_child_rule;
-mod phenotype_duplicate_rule;
-mod phenotype_ontology_child_rule;
+pub mod modifier_ontology_child_rule;
+pub mod observed_ancestor_rule;
+pub mod onset_ontology_child_rule;
+pub mod phenotype_duplicate_rule;
+pub mod phenotype_ontology_child_rule;
+/*mod observed_ancestor_with_excluded_descendants_rule;
 mod redundant_excluded_descendants_rule;
 mod severity_ontology_child_rule;
 */
+pub mod contradicting_onset_rule;
+pub mod empty_label_rule;
+pub mod empty_onset_rule;
+pub mod excluded_with_evidence_rule;
+pub mod excluded_with_qualifier_rule;
+pub mod generic_type_with_description_rule;
+pub mod missing_evidence_rule;
+pub mod modifier_branch_rule;
+pub mod observed_excluded_contradiction_rule;
+pub mod onset_age_format_rule;
+pub mod redundancy_chain_rule;
+pub mod redundant_description_rule;
+pub mod self_modifier_rule;
+pub mod stale_label_rule;
+pub mod undeclared_type_namespace_rule;
@@ -0,0 +1,256 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::is_hpo_curie;
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use log::warn;
+use ontolius::TermId;
+use ontolius::ontology::HierarchyQueries;
+use ontolius::ontology::csr::FullCsrOntology;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+use std::str::FromStr;
+use std::sync::Arc;
+
+fn parse_hpo_term(curie: &str) -> Option<TermId> {
+    if !is_hpo_curie(curie) {
+        return None;
+    }
+    TermId::from_str(curie).ok()
+}
+
+/// ### PF019
+/// ## What it does
+/// Flags modifiers that are not appropriate for the branch of the phenotype they modify,
+/// e.g. `Bilateral` on `Seizure`. Which modifier fits which branch is configured in the `LinterContext`.
+///
+/// This is a heuristic and opt-in. Without a configured mapping, nothing is flagged.
+///
+/// ## Why is this bad?
+/// A laterality only makes sense for paired organs. Such combinations are usually copied
+/// from another feature by mistake.
+#[derive(Debug)]
+#[register_rule(id = "PF019")]
+pub struct ModifierBranchRule {
+    hpo: Arc<FullCsrOntology>,
+    branches: Vec<(TermId, Vec<TermId>)>,
+}
+
+impl RuleFromContext for ModifierBranchRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let Some(hpo) = context.hpo() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "PF019".to_string(),
+                ontology: "HPO".to_string(),
+            });
+        };
+
+        let mut branches = vec![];
+        for (modifier, allowed) in context.modifier_branches() {
+            let Some(modifier_id) = parse_hpo_term(modifier) else {
+                warn!(
+                    "Ignoring modifier '{modifier}' of the modifier branches. It is no HPO term."
+                );
+                continue;
+            };
+
+            let allowed_ids = allowed
+                .iter()
+                .filter_map(|branch| {
+                    let branch_id = parse_hpo_term(branch);
+                    if branch_id.is_none() {
+                        warn!("Ignoring branch '{branch}' of modifier '{modifier}'. It is no HPO term.");
+                    }
+                    branch_id
+                })
+                .collect();
+            branches.push((modifier_id, allowed_ids));
+        }
+
+        Ok(Box::new(ModifierBranchRule { hpo, branches }))
+    }
+}
+
+impl ModifierBranchRule {
+    fn is_self_or_descendant(&self, term: &TermId, ancestor: &TermId) -> bool {
+        term == ancestor || self.hpo.is_descendant_of(term, ancestor)
+    }
+
+    fn is_appropriate(&self, modifier: &TermId, feature: &TermId) -> bool {
+        self.branches
+            .iter()
+            .filter(|(mapped_modifier, _)| self.is_self_or_descendant(modifier, mapped_modifier))
+            .all(|(_, allowed)| {
+                allowed
+                    .iter()
+                    .any(|branch| self.is_self_or_descendant(feature, branch))
+            })
+    }
+}
+
+impl RuleCheck for ModifierBranchRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        if self.branches.is_empty() {
+            return vec![];
+        }
+
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            let Some(feature_id) = node
+                .inner
+                .r#type
+                .as_ref()
+                .and_then(|feature_type| parse_hpo_term(&feature_type.id))
+            else {
+                continue;
+            };
+
+            for (idx, modifier) in node.inner.modifiers.iter().enumerate() {
+                let Some(modifier_id) = parse_hpo_term(&modifier.id) else {
+                    continue;
+                };
+                if self.is_appropriate(&modifier_id, &feature_id) {
+                    continue;
+                }
+
+                let mut modifier_ptr = node.pointer().clone();
+                modifier_ptr.down("modifiers").down(idx);
+                let mut type_ptr = node.pointer().clone();
+                type_ptr.down("type");
+
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Info,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(modifier_ptr, vec![type_ptr]),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF019")]
+struct ModifierBranchReport;
+
+impl ReportFromContext for ModifierBranchReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ModifierBranchReport))
+    }
+}
+
+impl ModifierBranchReport {
+    fn label_at(full_node: &dyn Node, term_ptr: &Pointer) -> String {
+        let mut ptr = term_ptr.clone();
+        ptr.down("label");
+
+        full_node
+            .value_at(&ptr)
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+}
+
+impl CompileReport for ModifierBranchReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let modifier_ptr = lint_violation.first_at();
+        let type_ptr = &lint_violation.at()[1];
+
+        let modifier = Self::label_at(full_node, modifier_ptr);
+        let feature = Self::label_at(full_node, type_ptr);
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Modifier '{modifier}' is unusual for the phenotype '{feature}'"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(modifier_ptr).unwrap().clone(),
+                    "Modifier".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(type_ptr).unwrap().clone(),
+                    "Not in a branch this modifier applies to".to_string(),
+                ),
+            ],
+            vec!["Check, whether the modifier was copied from another phenotype.".to_string()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::HPO;
+    use crate::tree::node::MaterializedNode;
+    use phenopackets::schema::v2::core::OntologyClass;
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn oc(id: &str, label: &str) -> OntologyClass {
+        OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }
+    }
+
+    fn rule(branches: Vec<(&str, Vec<&str>)>) -> ModifierBranchRule {
+        ModifierBranchRule {
+            hpo: HPO.clone(),
+            branches: branches
+                .into_iter()
+                .map(|(modifier, allowed)| {
+                    (
+                        TermId::from_str(modifier).unwrap(),
+                        allowed
+                            .into_iter()
+                            .map(|branch| TermId::from_str(branch).unwrap())
+                            .collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[rstest]
+    #[case(oc("HP:0001250", "Seizure"), oc("HP:0012832", "Bilateral"), vec![("HP:0012832", vec!["HP:0040064"])], 1)]
+    #[case(oc("HP:0002817", "Abnormality of the upper limb"), oc("HP:0012832", "Bilateral"), vec![("HP:0012832", vec!["HP:0040064"])], 0)]
+    #[case(oc("HP:0001250", "Seizure"), oc("HP:0012832", "Bilateral"), vec![("HP:0012831", vec!["HP:0040064"])], 1)]
+    #[case(oc("HP:0001250", "Seizure"), oc("HP:0012833", "Unilateral"), vec![("HP:0012832", vec!["HP:0040064"])], 0)]
+    #[case(oc("HP:0001250", "Seizure"), oc("HP:0012832", "Bilateral"), vec![], 0)]
+    fn test_find_inappropriate_modifiers(
+        #[case] feature_type: OntologyClass,
+        #[case] modifier: OntologyClass,
+        #[case] branches: Vec<(&str, Vec<&str>)>,
+        #[case] n_violations: usize,
+    ) {
+        let nodes = [MaterializedNode::new(
+            PhenotypicFeature {
+                r#type: Some(feature_type),
+                modifiers: vec![modifier],
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new("/phenotypicFeatures/0"),
+        )];
+
+        let violations = rule(branches).check(List(&nodes));
+
+        assert_eq!(violations.len(), n_violations);
+    }
+}
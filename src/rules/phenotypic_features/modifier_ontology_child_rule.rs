@@ -1,102 +1,184 @@
-use crate::linting_report::{LintReport, LintReportInfo, LintingViolation};
-use crate::traits::{LintRule, RuleCheck};
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::{is_hpo_curie, no_ontology_message};
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
 use ontolius::TermId;
 use ontolius::ontology::HierarchyQueries;
 use ontolius::ontology::csr::FullCsrOntology;
-use phenopackets::schema::v2::Phenopacket;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
 use std::str::FromStr;
 use std::sync::Arc;
-use annotate_snippets::Report;
-use phenolint_macros::lint_rule;
-use crate::register_rule;
-use crate::rules::rule_registry::RuleRegistration;
 
-#[derive(Debug)]
-/// Validates that phenotypic feature modifiers are descendants of the Clinical Modifier term.
-///
-/// This rule implements the linting check `PF002`, which ensures that all modifiers
-/// applied to phenotypic features are valid HPO clinical modifiers. According to the
-/// HPO specification, modifiers must be descendants of "Clinical modifier" (HP:0012823).
-///
-/// # Rule Logic
-///
-/// For each phenotypic feature in the phenopacket:
-/// 1. Iterates through all modifiers applied to the feature
-/// 2. Checks if each modifier is a descendant of HP:0012823 (Clinical modifier)
-/// 3. Reports a `NonModifier` violation if an invalid term is used as a modifier
-///
-/// # Example
+/// ### PF002
+/// ## What it does
+/// Validates that phenotypic feature modifiers are descendants of `Clinical modifier` (HP:0012823).
+/// Terms of namespaces without a loaded ontology can not be validated and are reported as info.
 ///
-/// Using "Generalized-onset seizure" (HP:0002197) as a modifier would be flagged as
-/// invalid because it's a phenotypic abnormality term, not a clinical modifier.
-/// Valid modifiers include terms like "Severe" (HP:0012828) or "Progressive" (HP:0003676),
-/// which are descendants of HP:0012823.
-//#[lint_rule(id = "PF002")]
+/// ## Why is this bad?
+/// According to the HPO specification, modifiers must be clinical modifiers. Using a phenotypic
+/// abnormality like `Generalized-onset seizure` as a modifier is invalid, whereas terms like
+/// `Severe` or `Progressive` are valid modifiers.
+#[derive(Debug)]
+#[register_rule(id = "PF002")]
 pub struct ModifierOntologyChildRule {
     hpo: Arc<FullCsrOntology>,
     clinical_modifiers: TermId,
 }
 
+impl RuleFromContext for ModifierOntologyChildRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let Some(hpo) = context.hpo() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "PF002".to_string(),
+                ontology: "HPO".to_string(),
+            });
+        };
 
-impl ModifierOntologyChildRule {
-    fn new(hpo: Arc<FullCsrOntology>) -> Self {
-        ModifierOntologyChildRule {
+        Ok(Box::new(ModifierOntologyChildRule {
             hpo,
-            clinical_modifiers: TermId::from_str("HP:0012823").unwrap(),
+            clinical_modifiers: TermId::from_str("HP:0012823").expect("Invalid term id"),
+        }))
+    }
+}
+
+impl RuleCheck for ModifierOntologyChildRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            for (idx, modifier) in node.inner.modifiers.iter().enumerate() {
+                let severity = if !is_hpo_curie(&modifier.id) {
+                    ViolationSeverity::Info
+                } else if let Ok(modifier_id) = TermId::from_str(&modifier.id)
+                    && !self
+                        .hpo
+                        .is_descendant_of(&modifier_id, &self.clinical_modifiers)
+                {
+                    ViolationSeverity::Error
+                } else {
+                    continue;
+                };
+
+                let mut ptr = node.pointer().clone();
+                ptr.down("modifiers").down(idx);
+
+                violations.push(LintViolation::new(
+                    severity,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                ));
+            }
         }
+
+        violations
     }
 }
 
+#[register_report(id = "PF002")]
+struct ModifierOntologyChildReport;
 
-impl RuleCheck for ModifierOntologyChildRule {
-    fn check(&self, phenopacket: &Phenopacket, report: &mut LintReport) {
-        phenopacket
-            .phenotypic_features
-            .iter()
-            .for_each(|feature_type| {
-                feature_type.modifiers.iter().for_each(|modi| {
-                    if !self.hpo.is_ancestor_of(
-                        &TermId::from_str(&modi.id).unwrap(),
-                        &self.clinical_modifiers,
-                    ) {
-                        report.push_info(LintReportInfo::new(LintingViolation::new("PF002", Report::default()),None));
-                    }
-                })
-            })
+impl ReportFromContext for ModifierOntologyChildReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ModifierOntologyChildReport))
     }
+}
+
+impl CompileReport for ModifierOntologyChildReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let modifier_ptr = lint_violation.first_at();
+        let mut label_ptr = modifier_ptr.clone();
+        label_ptr.down("label");
+
+        let mut id_ptr = modifier_ptr.clone();
+        id_ptr.down("id");
+
+        let value_of = |ptr: &Pointer| {
+            full_node
+                .value_at(ptr)
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_default()
+        };
+
+        let (message, notes) = if lint_violation.severity() == &ViolationSeverity::Info {
+            (no_ontology_message(&value_of(&id_ptr)), vec![])
+        } else {
+            (
+                format!("'{}' is not a clinical modifier", value_of(&label_ptr)),
+                vec![
+                    "Modifiers must be descendants of 'Clinical modifier' (HP:0012823)."
+                        .to_string(),
+                ],
+            )
+        };
 
+        ReportSpecs::from_violation(
+            lint_violation,
+            message,
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(modifier_ptr).unwrap().clone(),
+                String::default(),
+            )],
+            notes,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use annotate_snippets::Report;
     use super::*;
     use crate::test_utils::HPO;
-    use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+    use crate::tree::node::MaterializedNode;
+    use phenopackets::schema::v2::core::OntologyClass;
     use rstest::rstest;
-    use pretty_assertions::assert_eq;
-
-    #[rstest]
-    fn test_find_non_modifiers() {
-        let rule = ModifierOntologyChildRule::new(HPO.clone());
-
-        let modifier = OntologyClass {
-            id: "HP:0002197".to_string(),
-            label: "Generalized-onset seizure".to_string(),
-        };
+    use std::collections::HashMap;
 
-        let phenopacket = Phenopacket {
-            phenotypic_features: vec![PhenotypicFeature {
-                modifiers: vec![modifier.clone()],
+    fn feature_with_modifier(id: &str, label: &str) -> MaterializedNode<PhenotypicFeature> {
+        MaterializedNode::new(
+            PhenotypicFeature {
+                r#type: Some(OntologyClass {
+                    id: "HP:0001250".to_string(),
+                    label: "Seizure".to_string(),
+                }),
+                modifiers: vec![OntologyClass {
+                    id: id.to_string(),
+                    label: label.to_string(),
+                }],
                 ..Default::default()
-            }],
+            },
+            HashMap::new(),
+            Pointer::new("/phenotypicFeatures/0"),
+        )
+    }
 
-            ..Default::default()
+    #[rstest]
+    #[case("HP:0001250", "Seizure", 1)]
+    #[case("HP:0012828", "Severe", 0)]
+    #[case("MONDO:0000252", "inflammatory diarrhea", 1)]
+    fn test_find_non_modifiers(#[case] id: &str, #[case] label: &str, #[case] expected: usize) {
+        let rule = ModifierOntologyChildRule {
+            hpo: HPO.clone(),
+            clinical_modifiers: TermId::from_str("HP:0012823").unwrap(),
         };
+        let nodes = [feature_with_modifier(id, label)];
 
-        let mut report = LintReport::new();
-        rule.check(&phenopacket, &mut report);
+        let violations = rule.check(List(&nodes));
 
-        assert_eq!(report.violations().first().unwrap(), &LintingViolation::new("PF002", Report::default()));
+        assert_eq!(violations.len(), expected);
     }
 }
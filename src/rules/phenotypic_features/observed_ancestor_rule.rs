@@ -1,121 +1,222 @@
-use crate::linting_report::{LintReport, LintReportInfo, LintingViolation};
-use crate::rules::utils;
-use crate::traits::{ RuleCheck};
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::is_hpo_curie;
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use ontolius::TermId;
+use ontolius::ontology::HierarchyQueries;
 use ontolius::ontology::csr::FullCsrOntology;
-use phenopackets::schema::v2::Phenopacket;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+use std::str::FromStr;
 use std::sync::Arc;
-use annotate_snippets::Report;
 
-/// Validates that observed phenotypic terms don't have redundant observed ancestors.
+/// ### PF007
+/// ## What it does
+/// Flags observed phenotypic features that are ancestors of another observed phenotypic feature.
 ///
-/// This rule implements the linting check `PF007`, which identifies cases where both a
-/// specific phenotypic term and its more general ancestor terms are marked as observed
-/// in a phenopacket. Best practice in phenotype annotation is to use the most specific
-/// terms possible. When a specific phenotype is observed, annotating its general
-/// ancestors adds no additional information and should be avoided.
-///
-/// # Rule Logic
-///
-/// For each observed phenotypic term in the phenopacket:
-/// 1. Determines if the term is a "scion" (has no observed descendants, making it most specific)
-/// 2. Finds all ancestor terms that are also explicitly observed
-/// 3. Reports an `ObservedAncestor` violation if redundant ancestors are found
-///
-/// # Example
-///
-/// If "Ventricular septal defect" (HP:0001629) is marked as observed, then also marking
-/// its ancestor "Abnormal heart morphology" (HP:0001627) as observed would be flagged
-/// as redundant. The specific term already conveys all the information of the general
-/// term, making the ancestor annotation unnecessary.
-//#[lint_rule(id = "PF007")]
-struct ObservedAncestorRule {
+/// ## Why is this bad?
+/// The most specific term already implies all of its ancestors. Annotating e.g. `Abnormality of limbs`
+/// next to `Abnormality of the upper limb` adds no information.
+#[derive(Debug)]
+#[register_rule(id = "PF007")]
+pub struct ObservedAncestorRule {
     hpo: Arc<FullCsrOntology>,
 }
 
-impl ObservedAncestorRule {
-    fn new(hpo: Arc<FullCsrOntology>) -> Self {
-        ObservedAncestorRule { hpo }
+impl RuleFromContext for ObservedAncestorRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let Some(hpo) = context.hpo() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "PF007".to_string(),
+                ontology: "HPO".to_string(),
+            });
+        };
+
+        Ok(Box::new(ObservedAncestorRule { hpo }))
     }
 }
 
 impl RuleCheck for ObservedAncestorRule {
-    fn check(&self, phenopacket: &Phenopacket, report: &mut LintReport) {
-        let (observed, _) = utils::partition_phenotypic_features(phenopacket);
-
-        // Invalidate all ancestors of a family for an observed term
-        // Amongst the observed terms, we want to keep the most specific ones.
-        // Which means, if we find a term that is more general then another, we deem the more general term invalid.
-        observed.iter().for_each(|phenotypic_term| {
-            let is_scion =
-                utils::find_descendents(self.hpo.clone(), &observed, phenotypic_term).is_empty();
-
-            if is_scion {
-                let ancestor_terms =
-                    utils::find_ancestors(self.hpo.clone(), &observed, phenotypic_term);
-
-                if !ancestor_terms.is_empty() {
-                    // TODO: Add empty check
-                    report.push_info(LintReportInfo::new(
-                        LintingViolation::new("PF007", Report::default()), None
-                    ))
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let observed: Vec<(&Pointer, TermId)> = data
+            .iter()
+            .filter(|node| !node.inner.excluded)
+            .filter_map(|node| {
+                let feature_type = node.inner.r#type.as_ref()?;
+                if !is_hpo_curie(&feature_type.id) {
+                    return None;
                 }
+                let term_id = TermId::from_str(&feature_type.id).ok()?;
+                Some((node.pointer(), term_id))
+            })
+            .collect();
+
+        let mut violations = vec![];
+
+        for (ancestor_ptr, ancestor) in observed.iter() {
+            let descendant = observed
+                .iter()
+                .find(|(_, term)| term != ancestor && self.hpo.is_ancestor_of(ancestor, term));
+
+            if let Some((descendant_ptr, _)) = descendant {
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(
+                        (*ancestor_ptr).clone(),
+                        vec![(*descendant_ptr).clone()],
+                    ),
+                ));
             }
-        });
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF007")]
+struct ObservedAncestorReport;
+
+impl ReportFromContext for ObservedAncestorReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ObservedAncestorReport))
+    }
+}
+
+impl ObservedAncestorReport {
+    fn term_at(full_node: &dyn Node, feature_ptr: &Pointer, key: &str) -> String {
+        let mut ptr = feature_ptr.clone();
+        ptr.down("type").down(key);
+
+        full_node
+            .value_at(&ptr)
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+
+    fn count_annotations(full_node: &dyn Node, term_id: &str) -> usize {
+        full_node
+            .value_at(&Pointer::at_phenotypes())
+            .and_then(|features| {
+                features.as_array().map(|features| {
+                    features
+                        .iter()
+                        .filter(|feature| {
+                            feature.pointer("/type/id").and_then(|id| id.as_str()) == Some(term_id)
+                        })
+                        .count()
+                })
+            })
+            .unwrap_or_default()
     }
+}
+
+impl CompileReport for ObservedAncestorReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let ancestor_ptr = lint_violation.first_at();
+        let descendant_ptr = &lint_violation.at()[1];
 
+        let ancestor_id = Self::term_at(full_node, ancestor_ptr, "id");
+        let ancestor_label = Self::term_at(full_node, ancestor_ptr, "label");
+        let descendant_label = Self::term_at(full_node, descendant_ptr, "label");
 
+        let mut notes = vec![
+            "Only annotate the most specific term. It implies all of its ancestors.".to_string(),
+        ];
+        let n_annotations = Self::count_annotations(full_node, &ancestor_id);
+        if n_annotations > 1 {
+            notes.push(format!(
+                "'{ancestor_label}' is annotated {n_annotations} times. Duplicates are reported by PF006."
+            ));
+        }
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!(
+                "Observed phenotypic feature '{ancestor_label}' is an ancestor of observed '{descendant_label}'"
+            ),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(ancestor_ptr).unwrap().clone(),
+                    "Redundant ancestor".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(descendant_ptr).unwrap().clone(),
+                    "More specific term".to_string(),
+                ),
+            ],
+            notes,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use annotate_snippets::Report;
+    use super::*;
     use crate::test_utils::HPO;
-
-    use crate::linting_report::{LintReport, LintingViolation};
-    use crate::rules::phenotypic_features::observed_ancestor_rule::ObservedAncestorRule;
-    use crate::traits::RuleCheck;
-    use phenopackets::schema::v2::Phenopacket;
-    use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+    use crate::tree::node::MaterializedNode;
+    use phenopackets::schema::v2::core::OntologyClass;
     use rstest::rstest;
-    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
 
-    #[rstest]
-    fn test_find_related_phenotypic_features_case_1() {
-        let rule = ObservedAncestorRule::new(HPO.clone());
-        let expected_progenitor = OntologyClass {
-            id: "HP:0000448".to_string(),
-            label: "Prominent nose".to_string(),
-        };
-        let expected_middle = OntologyClass {
-            id: "HP:0005105".to_string(),
-            label: "Abnormal nasal morphology".to_string(),
-        };
-        let expected_ancestor = OntologyClass {
-            id: "HP:0000366".to_string(),
-            label: "Abnormality of the nose".to_string(),
-        };
-        let phenopacket = Phenopacket {
-            phenotypic_features: vec![
-                PhenotypicFeature {
-                    r#type: Some(expected_middle.clone()),
-                    ..Default::default()
-                },
-                PhenotypicFeature {
-                    r#type: Some(expected_ancestor.clone()),
-                    ..Default::default()
-                },
-                PhenotypicFeature {
-                    r#type: Some(expected_progenitor.clone()),
-                    ..Default::default()
-                },
-            ],
+    fn feature(id: &str, label: &str, excluded: bool) -> PhenotypicFeature {
+        PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: id.to_string(),
+                label: label.to_string(),
+            }),
+            excluded,
             ..Default::default()
-        };
+        }
+    }
+
+    #[rstest]
+    #[case(vec![
+        feature("HP:0002817", "Abnormality of the upper limb", false),
+        feature("HP:0040064", "Abnormality of limbs", false),
+        feature("HP:0003907", "Abnormal humeral metaphysis morphology", false),
+    ], 2)]
+    #[case(vec![
+        feature("HP:0040064", "Abnormality of limbs", false),
+        feature("HP:0002817", "Abnormality of the upper limb", true),
+    ], 0)]
+    #[case(vec![
+        feature("HP:0002817", "Abnormality of the upper limb", false),
+        feature("HP:0002817", "Abnormality of the upper limb", false),
+    ], 0)]
+    fn test_find_observed_ancestors(
+        #[case] features: Vec<PhenotypicFeature>,
+        #[case] n_violations: usize,
+    ) {
+        let rule = ObservedAncestorRule { hpo: HPO.clone() };
+        let nodes: Vec<_> = features
+            .into_iter()
+            .enumerate()
+            .map(|(idx, feature)| {
+                let mut ptr = Pointer::at_phenotypes();
+                ptr.down(idx);
+                MaterializedNode::new(feature, HashMap::new(), ptr)
+            })
+            .collect();
 
-        let mut report = LintReport::new();
-        rule.check(&phenopacket, &mut report);
+        let violations = rule.check(List(&nodes));
 
-        let violations = report.violations();
-        assert_eq!(violations.first().unwrap().clone(), LintingViolation::new("PF007", Report::default()));
+        assert_eq!(violations.len(), n_violations);
     }
 }
@@ -41,7 +41,7 @@ impl ObservedAncestorWithExcludedDescendantsRule {
 
 impl RuleCheck for ObservedAncestorWithExcludedDescendantsRule {
     fn check(&self, phenopacket: &Phenopacket, report: &mut LintReport) {
-        let (observed, excluded) = utils::partition_phenotypic_features(phenopacket);
+        let (observed, excluded) = utils::partition_phenotypic_features(&phenopacket.phenotypic_features);
 
         // Invalidate excluded terms that share the same family with an observed term and are descendents
         // If there is a more specific excluded term, we should invalidate that as well.
@@ -0,0 +1,388 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::is_hpo_curie;
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use log::warn;
+use ontolius::TermId;
+use ontolius::ontology::HierarchyQueries;
+use ontolius::ontology::csr::FullCsrOntology;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Modes checked, when none are configured in the `LinterContext`.
+const DEFAULT_CONTRADICTION_MODES: [ContradictionMode; 1] = [ContradictionMode::Descendant];
+
+fn hpo_term(feature: &PhenotypicFeature) -> Option<TermId> {
+    let feature_type = feature.r#type.as_ref()?;
+    if !is_hpo_curie(&feature_type.id) {
+        return None;
+    }
+    TermId::from_str(&feature_type.id).ok()
+}
+
+/// How an observed term relates to an excluded term.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContradictionMode {
+    /// The same term is observed and excluded.
+    Exact,
+    /// An ancestor of the excluded term is observed.
+    Ancestor,
+    /// A descendant of the excluded term is observed.
+    Descendant,
+}
+
+impl ContradictionMode {
+    fn parse(mode: &str) -> Option<Self> {
+        match mode.to_lowercase().as_str() {
+            "exact" => Some(ContradictionMode::Exact),
+            "ancestor" => Some(ContradictionMode::Ancestor),
+            "descendant" => Some(ContradictionMode::Descendant),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            ContradictionMode::Exact => "exact",
+            ContradictionMode::Ancestor => "ancestor",
+            ContradictionMode::Descendant => "descendant",
+        }
+    }
+
+    fn matches(&self, hpo: &FullCsrOntology, excluded: &TermId, observed: &TermId) -> bool {
+        match self {
+            ContradictionMode::Exact => observed == excluded,
+            ContradictionMode::Ancestor => {
+                observed != excluded && hpo.is_ancestor_of(observed, excluded)
+            }
+            ContradictionMode::Descendant => {
+                observed != excluded && hpo.is_descendant_of(observed, excluded)
+            }
+        }
+    }
+
+    /// An observed ancestor narrows the phenotype down and is no contradiction in itself.
+    fn severity(&self) -> ViolationSeverity {
+        match self {
+            ContradictionMode::Exact | ContradictionMode::Descendant => ViolationSeverity::Error,
+            ContradictionMode::Ancestor => ViolationSeverity::Info,
+        }
+    }
+}
+
+/// ### PF008
+/// ## What it does
+/// Flags excluded phenotypic features that contradict an observed feature. Which relations are checked
+/// can be configured in the `LinterContext`:
+/// - `exact`: the same term is observed and excluded.
+/// - `ancestor`: the excluded term is a descendant of an observed term.
+/// - `descendant`: the excluded term is an ancestor of an observed term.
+///
+/// By default only `descendant` is checked.
+///
+/// ## Why is this bad?
+/// Excluding `Abnormality of limbs` while observing `Abnormality of the upper limb` contradicts itself.
+/// A general abnormality can not be ruled out, when a specific instance of it is present.
+///
+/// Excluding a descendant of an observed term is a double negative, that is easily misread. Excluding
+/// and observing the same term is only consistent, if e.g. the onsets differ.
+#[derive(Debug)]
+#[register_rule(id = "PF008")]
+pub struct ObservedExcludedContradictionRule {
+    hpo: Arc<FullCsrOntology>,
+    modes: Vec<ContradictionMode>,
+}
+
+impl RuleFromContext for ObservedExcludedContradictionRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let Some(hpo) = context.hpo() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "PF008".to_string(),
+                ontology: "HPO".to_string(),
+            });
+        };
+
+        let modes = match context.contradiction_modes() {
+            Some(modes) => modes
+                .iter()
+                .filter_map(|mode| {
+                    let parsed = ContradictionMode::parse(mode);
+                    if parsed.is_none() {
+                        warn!("Ignoring unknown contradiction mode '{mode}'.");
+                    }
+                    parsed
+                })
+                .collect(),
+            None => DEFAULT_CONTRADICTION_MODES.to_vec(),
+        };
+
+        Ok(Box::new(ObservedExcludedContradictionRule { hpo, modes }))
+    }
+}
+
+impl RuleCheck for ObservedExcludedContradictionRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let observed: Vec<(&Pointer, TermId)> = data
+            .iter()
+            .filter(|node| !node.inner.excluded)
+            .filter_map(|node| Some((node.pointer(), hpo_term(&node.inner)?)))
+            .collect();
+        let mut violations = vec![];
+
+        for node in data.iter().filter(|node| node.inner.excluded) {
+            let Some(excluded_term) = hpo_term(&node.inner) else {
+                continue;
+            };
+
+            for mode in &self.modes {
+                // The first match in the order of the phenopacket, so reports are stable.
+                let Some((observed_ptr, _)) = observed.iter().find(|(_, observed_term)| {
+                    mode.matches(&self.hpo, &excluded_term, observed_term)
+                }) else {
+                    continue;
+                };
+
+                violations.push(
+                    LintViolation::new(
+                        mode.severity(),
+                        LintRule::rule_id(self),
+                        NonEmptyVec::with_rest(
+                            node.pointer().clone(),
+                            vec![(*observed_ptr).clone()],
+                        ),
+                    )
+                    .with_kind(mode.name()),
+                );
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF008")]
+struct ObservedExcludedContradictionReport {
+    hpo: Arc<FullCsrOntology>,
+}
+
+impl ReportFromContext for ObservedExcludedContradictionReport {
+    fn from_context(
+        context: &LinterContext,
+    ) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        let Some(hpo) = context.hpo() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "PF008".to_string(),
+                ontology: "HPO".to_string(),
+            });
+        };
+
+        Ok(Box::new(ObservedExcludedContradictionReport { hpo }))
+    }
+}
+
+impl ObservedExcludedContradictionReport {
+    fn string_at(full_node: &dyn Node, feature_ptr: &Pointer, key: &str) -> String {
+        let mut ptr = feature_ptr.clone();
+        ptr.down("type").down(key);
+
+        full_node
+            .value_at(&ptr)
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+
+    fn mode(&self, excluded_id: &str, observed_id: &str) -> ContradictionMode {
+        let (Ok(excluded), Ok(observed)) =
+            (TermId::from_str(excluded_id), TermId::from_str(observed_id))
+        else {
+            return ContradictionMode::Exact;
+        };
+
+        [ContradictionMode::Ancestor, ContradictionMode::Descendant]
+            .into_iter()
+            .find(|mode| mode.matches(&self.hpo, &excluded, &observed))
+            .unwrap_or(ContradictionMode::Exact)
+    }
+}
+
+impl CompileReport for ObservedExcludedContradictionReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let excluded_ptr = lint_violation.first_at();
+        let observed_ptr = &lint_violation.at()[1];
+
+        let excluded_label = Self::string_at(full_node, excluded_ptr, "label");
+        let observed_label = Self::string_at(full_node, observed_ptr, "label");
+        let mode = lint_violation
+            .kind()
+            .and_then(ContradictionMode::parse)
+            .unwrap_or_else(|| {
+                self.mode(
+                    &Self::string_at(full_node, excluded_ptr, "id"),
+                    &Self::string_at(full_node, observed_ptr, "id"),
+                )
+            });
+
+        let (message, observed_message, note) = match mode {
+            ContradictionMode::Exact => (
+                format!("Phenotypic feature '{excluded_label}' is observed and excluded"),
+                "Observed",
+                "Unless e.g. the onsets differ, either the exclusion or the observation is wrong.",
+            ),
+            ContradictionMode::Ancestor => (
+                format!(
+                    "Excluded phenotypic feature '{excluded_label}' is a descendant of observed '{observed_label}'"
+                ),
+                "Observed ancestor",
+                "Excluding a term below an observed one is a double negative. Consider a more specific observed term.",
+            ),
+            ContradictionMode::Descendant => (
+                format!(
+                    "Excluded phenotypic feature '{excluded_label}' is an ancestor of observed '{observed_label}'"
+                ),
+                "Observed descendant",
+                "Either the exclusion or the observation is wrong. Check which of the two is correct.",
+            ),
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message,
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(excluded_ptr).unwrap().clone(),
+                    "Excluded".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(observed_ptr).unwrap().clone(),
+                    observed_message.to_string(),
+                ),
+            ],
+            vec![note.to_string()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::HPO;
+    use crate::tree::node::MaterializedNode;
+    use phenopackets::schema::v2::core::OntologyClass;
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn feature(id: &str, label: &str, excluded: bool) -> PhenotypicFeature {
+        PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: id.to_string(),
+                label: label.to_string(),
+            }),
+            excluded,
+            ..Default::default()
+        }
+    }
+
+    fn check(
+        modes: Vec<ContradictionMode>,
+        features: Vec<PhenotypicFeature>,
+    ) -> Vec<(String, String)> {
+        let rule = ObservedExcludedContradictionRule {
+            hpo: HPO.clone(),
+            modes,
+        };
+        let nodes: Vec<_> = features
+            .into_iter()
+            .enumerate()
+            .map(|(idx, feature)| {
+                let mut ptr = Pointer::at_phenotypes();
+                ptr.down(idx);
+                MaterializedNode::new(feature, HashMap::new(), ptr)
+            })
+            .collect();
+
+        rule.check(List(&nodes))
+            .iter()
+            .map(|violation| {
+                (
+                    violation.at()[0].position().to_string(),
+                    violation.at()[1].position().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(vec![
+        feature("HP:0040064", "Abnormality of limbs", true),
+        feature("HP:0002817", "Abnormality of the upper limb", false),
+    ], vec![("/phenotypicFeatures/0", "/phenotypicFeatures/1")])]
+    #[case(vec![
+        feature("HP:0040064", "Abnormality of limbs", false),
+        feature("HP:0002817", "Abnormality of the upper limb", true),
+    ], vec![])]
+    #[case(vec![
+        feature("HP:0002817", "Abnormality of the upper limb", true),
+        feature("HP:0002817", "Abnormality of the upper limb", false),
+    ], vec![])]
+    fn test_find_excluded_with_observed_descendants(
+        #[case] features: Vec<PhenotypicFeature>,
+        #[case] expected: Vec<(&str, &str)>,
+    ) {
+        let positions = check(DEFAULT_CONTRADICTION_MODES.to_vec(), features);
+
+        let expected: Vec<(String, String)> = expected
+            .into_iter()
+            .map(|(excluded, observed)| (excluded.to_string(), observed.to_string()))
+            .collect();
+        assert_eq!(positions, expected);
+    }
+
+    #[rstest]
+    #[case(vec![ContradictionMode::Exact], 1)]
+    #[case(vec![ContradictionMode::Ancestor], 1)]
+    #[case(vec![ContradictionMode::Descendant], 1)]
+    #[case(vec![], 0)]
+    #[case(vec![ContradictionMode::Exact, ContradictionMode::Ancestor, ContradictionMode::Descendant], 3)]
+    fn test_modes(#[case] modes: Vec<ContradictionMode>, #[case] n_violations: usize) {
+        let features = vec![
+            // Exact
+            feature("HP:0001250", "Seizure", false),
+            feature("HP:0001250", "Seizure", true),
+            // Ancestor
+            feature("HP:0040064", "Abnormality of limbs", false),
+            feature("HP:0002817", "Abnormality of the upper limb", true),
+            // Descendant
+            feature("HP:0012831", "Laterality", true),
+            feature("HP:0012832", "Bilateral", false),
+        ];
+
+        assert_eq!(check(modes, features).len(), n_violations);
+    }
+
+    #[rstest]
+    #[case("exact", Some(ContradictionMode::Exact))]
+    #[case("Ancestor", Some(ContradictionMode::Ancestor))]
+    #[case("descendant", Some(ContradictionMode::Descendant))]
+    #[case("sibling", None)]
+    fn test_parse_mode(#[case] mode: &str, #[case] expected: Option<ContradictionMode>) {
+        assert_eq!(ContradictionMode::parse(mode), expected);
+    }
+}
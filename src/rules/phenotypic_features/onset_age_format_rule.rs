@@ -0,0 +1,163 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::parse_iso8601_duration;
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+use phenopackets::schema::v2::core::time_element::Element;
+use serde_json::Value;
+
+/// ### PF020
+/// ## What it does
+/// Flags phenotypic features whose `onset` is given as an `age` that is not a valid ISO8601
+/// duration, e.g. `30years` instead of `P30Y`.
+///
+/// Onsets given as ontology classes or in any other form are ignored.
+///
+/// ## Why is this bad?
+/// The schema only requires the age to be a string, but tools comparing or aggregating ages
+/// expect ISO8601 durations and cannot interpret anything else.
+#[derive(Debug, Default)]
+#[register_rule(id = "PF020")]
+pub struct OnsetAgeFormatRule;
+
+impl RuleFromContext for OnsetAgeFormatRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(OnsetAgeFormatRule))
+    }
+}
+
+impl RuleCheck for OnsetAgeFormatRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            let Some(Element::Age(age)) = node
+                .inner
+                .onset
+                .as_ref()
+                .and_then(|onset| onset.element.as_ref())
+            else {
+                continue;
+            };
+
+            if parse_iso8601_duration(&age.iso8601duration).is_none() {
+                let mut ptr = node.pointer().clone();
+                ptr.down("onset").down("age");
+
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF020")]
+struct OnsetAgeFormatReport;
+
+impl ReportFromContext for OnsetAgeFormatReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(OnsetAgeFormatReport))
+    }
+}
+
+impl CompileReport for OnsetAgeFormatReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let age_ptr = lint_violation.first_at();
+        let mut duration_ptr = age_ptr.clone();
+        duration_ptr.down("iso8601duration");
+
+        let label = match full_node.value_at(&duration_ptr).as_deref() {
+            Some(Value::String(duration)) => {
+                format!("'{duration}' is not an ISO8601 duration")
+            }
+            _ => "Age is not an ISO8601 duration".to_string(),
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            "Phenotypic feature has an onset age that is not an ISO8601 duration".to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(age_ptr).unwrap().clone(),
+                label,
+            )],
+            vec!["Ages are expected as ISO8601 durations, e.g. 'P10Y' or 'P2M15D'.".to_string()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use crate::tree::pointer::Pointer;
+    use phenopackets::schema::v2::core::{Age, OntologyClass, TimeElement};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn age(duration: &str) -> Element {
+        Element::Age(Age {
+            iso8601duration: duration.to_string(),
+        })
+    }
+
+    #[rstest]
+    #[case(age("P30Y"), 0)]
+    #[case(age("P2M15D"), 0)]
+    #[case(age("30years"), 1)]
+    #[case(age(""), 1)]
+    #[case(
+        Element::OntologyClass(OntologyClass {
+            id: "HP:0003577".to_string(),
+            label: "Congenital onset".to_string(),
+        }),
+        0
+    )]
+    fn test_find_invalid_onset_ages(#[case] onset: Element, #[case] n_violations: usize) {
+        let mut ptr = Pointer::at_phenotypes();
+        ptr.down(0);
+        let nodes = [MaterializedNode::new(
+            PhenotypicFeature {
+                r#type: Some(OntologyClass {
+                    id: "HP:0001733".to_string(),
+                    label: "Pancreatitis".to_string(),
+                }),
+                onset: Some(TimeElement {
+                    element: Some(onset),
+                }),
+                ..Default::default()
+            },
+            HashMap::new(),
+            ptr,
+        )];
+
+        let violations = OnsetAgeFormatRule.check(List(&nodes));
+
+        assert_eq!(violations.len(), n_violations);
+        if let Some(violation) = violations.first() {
+            assert_eq!(
+                violation.first_at().position(),
+                "/phenotypicFeatures/0/onset/age"
+            );
+        }
+    }
+}
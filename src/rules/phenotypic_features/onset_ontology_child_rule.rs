@@ -1,56 +1,66 @@
-use crate::linting_report::{LintReport, LintReportInfo, LintingViolation};
-use crate::traits::{RuleCheck};
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::{is_hpo_curie, no_ontology_message};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
 use ontolius::TermId;
 use ontolius::ontology::HierarchyQueries;
 use ontolius::ontology::csr::FullCsrOntology;
-use phenopackets::schema::v2::Phenopacket;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
 use phenopackets::schema::v2::core::time_element::Element;
 use std::str::FromStr;
 use std::sync::Arc;
-use annotate_snippets::Report;
 
-#[derive(Debug)]
-/// Validates that phenotypic feature onset terms are descendants of the Onset term.
-///
-/// This rule implements the linting check `PF003`, which ensures that all onset
-/// annotations for phenotypic features use valid HPO onset terms. According to the
-/// HPO specification, onset terms must be descendants of "Onset" (HP:0003674).
-///
-/// # Rule Logic
-///
-/// For each phenotypic feature in the phenopacket:
-/// 1. Checks if the feature has an onset annotation
-/// 2. Verifies the onset is specified as an OntologyClass element
-/// 3. Validates that the term is a descendant of HP:0003674 (Onset)
-/// 4. Reports a `NonOnset` violation if an invalid term is used for onset
+/// ### PF003
+/// ## What it does
+/// Validates that onset terms of phenotypic features are descendants of `Onset` (HP:0003674).
+/// Terms of namespaces without a loaded ontology can not be validated and are reported as info.
 ///
-/// # Example
-///
-/// Using "Abnormal heart morphology" (HP:0001627) as an onset term would be flagged
-/// as invalid because it's a phenotypic abnormality term, not an onset term.
-/// Valid onset terms include "Congenital onset" (HP:0003577), "Adult onset" (HP:0003581),
-/// or "Childhood onset" (HP:0011463), which are all descendants of HP:0003674.
-///
-//#[lint_rule(id = "PF003")]
+/// ## Why is this bad?
+/// According to the HPO specification, only onset terms like `Congenital onset` or `Adult onset`
+/// describe when a phenotype manifested. A phenotypic abnormality used as onset is meaningless.
+#[derive(Debug)]
+#[register_rule(id = "PF003")]
 pub struct OnsetOntologyChildRule {
     hpo: Arc<FullCsrOntology>,
     onsets: TermId,
 }
 
-impl OnsetOntologyChildRule {
-    fn new(hpo: Arc<FullCsrOntology>) -> Self {
-        OnsetOntologyChildRule {
+impl RuleFromContext for OnsetOntologyChildRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let Some(hpo) = context.hpo() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "PF003".to_string(),
+                ontology: "HPO".to_string(),
+            });
+        };
+
+        Ok(Box::new(OnsetOntologyChildRule {
             hpo,
-            onsets: TermId::from_str("HP:0003674").unwrap(),
-        }
+            onsets: TermId::from_str("HP:0003674").expect("Invalid term id"),
+        }))
     }
 }
 
-
 impl RuleCheck for OnsetOntologyChildRule {
-    fn check(&self, phenopacket: &Phenopacket, report: &mut LintReport) {
-        for feature in &phenopacket.phenotypic_features {
-            let Some(onset) = &feature.onset else {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            let Some(onset) = &node.inner.onset else {
                 continue;
             };
 
@@ -58,48 +68,119 @@ impl RuleCheck for OnsetOntologyChildRule {
                 continue;
             };
 
-            let Ok(term_id) = TermId::from_str(&oc.id) else {
+            let severity = if !is_hpo_curie(&oc.id) {
+                ViolationSeverity::Info
+            } else if let Ok(term_id) = TermId::from_str(&oc.id)
+                && !self.hpo.is_descendant_of(&term_id, &self.onsets)
+            {
+                ViolationSeverity::Error
+            } else {
                 continue;
             };
 
-            if !self.hpo.is_ancestor_of(&term_id, &self.onsets) {
-                report.push_info(LintReportInfo::new(LintingViolation::new("PF003", Report::default()),None));
-            }
+            let mut ptr = node.pointer().clone();
+            ptr.down("onset").down("ontologyClass");
+
+            violations.push(LintViolation::new(
+                severity,
+                LintRule::rule_id(self),
+                NonEmptyVec::with_single_entry(ptr),
+            ));
         }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF003")]
+struct OnsetOntologyChildReport;
+
+impl ReportFromContext for OnsetOntologyChildReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(OnsetOntologyChildReport))
+    }
+}
+
+impl CompileReport for OnsetOntologyChildReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let onset_ptr = lint_violation.first_at();
+        let mut id_ptr = onset_ptr.clone();
+        id_ptr.down("id");
+
+        let term_id = full_node
+            .value_at(&id_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        let (message, notes) = if lint_violation.severity() == &ViolationSeverity::Info {
+            (no_ontology_message(&term_id), vec![])
+        } else {
+            (
+                format!("'{term_id}' is not an onset term"),
+                vec!["Onsets must be descendants of 'Onset' (HP:0003674).".to_string()],
+            )
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message,
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(onset_ptr).unwrap().clone(),
+                String::default(),
+            )],
+            notes,
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use annotate_snippets::Report;
     use super::*;
     use crate::test_utils::HPO;
-    use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature, TimeElement};
+    use crate::tree::node::MaterializedNode;
+    use crate::tree::pointer::Pointer;
+    use phenopackets::schema::v2::core::{OntologyClass, TimeElement};
     use rstest::rstest;
-    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
 
     #[rstest]
-    fn test_find_non_onsets() {
-        let rule = OnsetOntologyChildRule::new(HPO.clone());
-        let onset = OntologyClass {
-            id: "HP:0002197".to_string(),
-            label: "Generalized-onset seizure".to_string(),
+    #[case("HP:0001250", "Seizure", Some(ViolationSeverity::Error))]
+    #[case("HP:0003577", "Congenital onset", None)]
+    #[case(
+        "MONDO:0000252",
+        "inflammatory diarrhea",
+        Some(ViolationSeverity::Info)
+    )]
+    fn test_find_non_onsets(
+        #[case] id: &str,
+        #[case] label: &str,
+        #[case] expected: Option<ViolationSeverity>,
+    ) {
+        let rule = OnsetOntologyChildRule {
+            hpo: HPO.clone(),
+            onsets: TermId::from_str("HP:0003674").unwrap(),
         };
-
-        let phenopacket = Phenopacket {
-            phenotypic_features: vec![PhenotypicFeature {
+        let nodes = [MaterializedNode::new(
+            PhenotypicFeature {
+                r#type: Some(OntologyClass {
+                    id: "HP:0001733".to_string(),
+                    label: "Pancreatitis".to_string(),
+                }),
                 onset: Some(TimeElement {
-                    element: Some(Element::OntologyClass(onset.clone())),
+                    element: Some(Element::OntologyClass(OntologyClass {
+                        id: id.to_string(),
+                        label: label.to_string(),
+                    })),
                 }),
                 ..Default::default()
-            }],
-
-            ..Default::default()
-        };
+            },
+            HashMap::new(),
+            Pointer::new("/phenotypicFeatures/0"),
+        )];
 
-        let mut report = LintReport::new();
-        rule.check(&phenopacket, &mut report);
+        let violations = rule.check(List(&nodes));
 
-        assert_eq!(report.violations().first().unwrap(), &LintingViolation::new("PF003", Report::default()))
+        assert_eq!(violations.first().map(|v| v.severity().clone()), expected);
     }
 }
@@ -1,144 +1,142 @@
-use crate::rules::rule_registry::RuleRegistration;
-use crate::enums::{FixAction};
-use crate::linting_report::{LintReport, LintReportInfo, LintingViolation};
-use crate::traits::{LintRule, RuleCheck};
-use phenopackets::schema::v2::Phenopacket;
-use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
-use std::collections::HashMap;
-use annotate_snippets::Report;
-use phenolint_macros::lint_rule;
-use crate::register_rule;
-
-#[derive(Debug, Default)]
-/// Validates that phenotypic features are not duplicated within a phenopacket.
-///
-/// This rule implements the linting check `PF006`, which identifies duplicate
-/// phenotypic feature annotations. A phenotypic feature is considered a duplicate
-/// if it has the same ontology class ID and identical properties (modifiers, onset,
-/// excluded status, etc.) as another feature in the same phenopacket.
-///
-/// # Rule Logic
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+
+/// ### PF006
+/// ## What it does
+/// Flags phenotypic features that are exact duplicates of a previous feature.
+/// Features of the same term, that differ in e.g. modifiers, onset or exclusion status are no duplicates.
 ///
-/// 1. Groups phenotypic features by their ontology class ID
-/// 2. Within each group, compares features for exact duplicates
-/// 3. Reports a `DuplicatePhenotype` violation for each duplicate found
-/// 4. Suggests a `Remove` fix action to eliminate the redundant annotation
-///
-/// # Example
-///
-/// If a phenopacket contains two identical entries for "Seizure" (HP:0001250) with
-/// the same modifiers, onset, and exclusion status, the second occurrence would be
-/// flagged as a duplicate. However, if the two "Seizure" annotations differ in their
-/// modifiers (e.g., one marked as "Severe" and another as "Mild"), they would not
-/// be considered duplicates.
-#[lint_rule(id = "PF006")]
+/// ## Why is this bad?
+/// Duplicates carry no additional information and distort e.g. phenotype counts of downstream analyses.
+#[derive(Debug, Default)]
+#[register_rule(id = "PF006")]
 pub struct PhenotypeDuplicateRule;
 
+impl RuleFromContext for PhenotypeDuplicateRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(PhenotypeDuplicateRule))
+    }
+}
+
+impl RuleCheck for PhenotypeDuplicateRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for (idx, node) in data.iter().enumerate() {
+            if node.inner.r#type.is_none() {
+                continue;
+            }
 
-impl PhenotypeDuplicateRule {
-    fn filter_by_duplicate_ontology_classes(
-        &self,
-        phenotypic_features: &[PhenotypicFeature],
-    ) -> HashMap<String, Vec<PhenotypicFeature>> {
-        let mut duplicates: HashMap<String, Vec<PhenotypicFeature>> = HashMap::new();
-        let mut seen: Vec<&OntologyClass> = Vec::new();
-
-        for pf in phenotypic_features {
-            //TODO: Add empty check
-            if let Some(ref ont_class) = pf.r#type {
-                if seen.contains(&ont_class) {
-                    duplicates
-                        .entry(ont_class.id.to_string())
-                        .or_default()
-                        .push(pf.clone());
-                }
-                seen.push(ont_class);
+            if let Some(original) = data[..idx].iter().find(|prev| prev.inner == node.inner) {
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(
+                        node.pointer().clone(),
+                        vec![original.pointer().clone()],
+                    ),
+                ));
             }
         }
 
-        duplicates
+        violations
     }
 }
 
+#[register_report(id = "PF006")]
+struct PhenotypeDuplicateReport;
 
-impl RuleCheck for PhenotypeDuplicateRule {
-    fn check(&self, phenopacket: &Phenopacket, report: &mut LintReport) {
-        let duplicate_features =
-            self.filter_by_duplicate_ontology_classes(phenopacket.phenotypic_features.as_slice());
-
-        for mut dup_pfs in duplicate_features.values().cloned() {
-            let mut seen = Vec::new();
-            let mut indices_to_remove = Vec::new();
-
-            for (index, pf) in dup_pfs.iter().enumerate() {
-                if seen.contains(&pf) {
-
-                    report.push_info(LintReportInfo::new(
-                        LintingViolation::new(Self::RULE_ID, Report::default()),
-                        // TODO
-                        Some(FixAction::Remove { at: "".to_string() }),
-                    ));
-                    indices_to_remove.push(index);
-                } else {
-                    seen.push(pf);
-                }
-            }
-            for &i in indices_to_remove.iter().rev() {
-                dup_pfs.remove(i);
-            }
-        }
+impl ReportFromContext for PhenotypeDuplicateReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(PhenotypeDuplicateReport))
     }
+}
 
+impl CompileReport for PhenotypeDuplicateReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let duplicate_ptr = lint_violation.first_at();
+        let original_ptr = &lint_violation.at()[1];
+
+        let mut label_ptr = duplicate_ptr.clone();
+        label_ptr.down("type").down("label");
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Phenotypic feature '{label}' is duplicated"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(duplicate_ptr).unwrap().clone(),
+                    "Duplicate".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(original_ptr).unwrap().clone(),
+                    "First annotated here".to_string(),
+                ),
+            ],
+            vec![],
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tree::node::MaterializedNode;
+    use crate::tree::pointer::Pointer;
+    use phenopackets::schema::v2::core::OntologyClass;
     use rstest::rstest;
-    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
+
+    fn macular_degeneration(excluded: bool) -> PhenotypicFeature {
+        PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: "HP:0001098".to_string(),
+                label: "Macular degeneration".to_string(),
+            }),
+            excluded,
+            ..Default::default()
+        }
+    }
 
     #[rstest]
-    fn test_find_duplicate_phenotypic_features() {
-        let rule = PhenotypeDuplicateRule;
-
-        let phenopacket = Phenopacket {
-            phenotypic_features: vec![
-                PhenotypicFeature {
-                    r#type: Some(OntologyClass {
-                        id: "HP:0001098".to_string(),
-                        label: "Macular degeneration".to_string(),
-                    }),
-                    excluded: true,
-                    ..Default::default()
-                },
-                PhenotypicFeature {
-                    r#type: Some(OntologyClass {
-                        id: "HP:0001098".to_string(),
-                        label: "Macular degeneration".to_string(),
-                    }),
-                    excluded: false,
-                    ..Default::default()
-                },
-            ],
-            ..Default::default()
-        };
-
-        let duplicates =
-            rule.filter_by_duplicate_ontology_classes(phenopacket.phenotypic_features.as_slice());
-
-        assert_eq!(duplicates.len(), 1);
-        assert_eq!(
-            duplicates
-                .values()
-                .next()
-                .unwrap()
-                .first()
-                .unwrap()
-                .r#type
-                .clone()
-                .unwrap()
-                .id,
-            "HP:0001098"
-        );
+    #[case(vec![macular_degeneration(false), macular_degeneration(false)], 1)]
+    #[case(vec![macular_degeneration(false), macular_degeneration(false), macular_degeneration(false)], 2)]
+    #[case(vec![macular_degeneration(true), macular_degeneration(false)], 0)]
+    fn test_find_duplicates(#[case] features: Vec<PhenotypicFeature>, #[case] n_violations: usize) {
+        let nodes: Vec<_> = features
+            .into_iter()
+            .enumerate()
+            .map(|(idx, feature)| {
+                let mut ptr = Pointer::at_phenotypes();
+                ptr.down(idx);
+                MaterializedNode::new(feature, HashMap::new(), ptr)
+            })
+            .collect();
+
+        let violations = PhenotypeDuplicateRule.check(List(&nodes));
+
+        assert_eq!(violations.len(), n_violations);
     }
 }
@@ -1,101 +1,181 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::{is_hpo_curie, no_ontology_message};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
 use ontolius::TermId;
 use ontolius::ontology::HierarchyQueries;
 use ontolius::ontology::csr::FullCsrOntology;
-use phenopackets::schema::v2::Phenopacket;
-
-use crate::linting_report::{LintReport, LintReportInfo, LintingViolation};
-use crate::traits::{ RuleCheck};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
 use std::str::FromStr;
 use std::sync::Arc;
-use annotate_snippets::Report;
 
-#[derive(Debug)]
-/// Validates that phenotypic features are descendants of the Phenotypic abnormality term.
-///
-/// This rule implements the linting check `PF001`, which ensures that all terms used
-/// as phenotypic features belong to the phenotypic abnormality branch of the HPO.
-/// According to the phenopacket specification, phenotypic features must be descendants
-/// of "Phenotypic abnormality" (HP:0000118).
-///
-/// # Rule Logic
-///
-/// For each phenotypic feature in the phenopacket:
-/// 1. Checks if the feature has an ontology class specified
-/// 2. Verifies that the term is a descendant of HP:0000118 (Phenotypic abnormality)
-/// 3. Reports a `NonPhenotypicFeature` violation if an invalid term is used
+/// ### PF001
+/// ## What it does
+/// Validates that phenotypic features are descendants of `Phenotypic abnormality` (HP:0000118).
+/// Terms of namespaces without a loaded ontology can not be validated and are reported as info.
 ///
-/// # Example
-///
-/// Using "Clinical modifier" (HP:0012823) or "Onset" (HP:0003674) as a phenotypic
-/// feature would be flagged as invalid because these terms are not descendants of
-/// HP:0000118. Valid phenotypic features include terms like "Seizure" (HP:0001250)
-/// or "Intellectual disability" (HP:0001249), which are part of the phenotypic
-/// abnormality hierarchy.
-//#[lint_rule(id = "PF001")]
+/// ## Why is this bad?
+/// According to the phenopacket specification, phenotypic features must belong to the phenotypic
+/// abnormality branch of the HPO. Terms like `Clinical modifier` or `Onset` describe a feature,
+/// but are no features themselves.
+#[derive(Debug)]
+#[register_rule(id = "PF001")]
 pub struct PhenotypeOntologyChildRule {
     hpo: Arc<FullCsrOntology>,
     phenotypic_abnormality: TermId,
 }
 
+impl RuleFromContext for PhenotypeOntologyChildRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let Some(hpo) = context.hpo() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "PF001".to_string(),
+                ontology: "HPO".to_string(),
+            });
+        };
+
+        Ok(Box::new(PhenotypeOntologyChildRule {
+            hpo,
+            phenotypic_abnormality: TermId::from_str("HP:0000118").expect("Invalid term id"),
+        }))
+    }
+}
 
 impl RuleCheck for PhenotypeOntologyChildRule {
-    fn check(&self, phenopacket: &Phenopacket, report: &mut LintReport) {
-        phenopacket
-            .phenotypic_features
-            .iter()
-            .for_each(|feature_type| {
-                if let Some(f) = &feature_type.r#type
-                    && !self.hpo.is_ancestor_of(
-                        &TermId::from_str(&f.id).unwrap(),
-                        &self.phenotypic_abnormality,
-                    )
-                {
-                    report.push_info(LintReportInfo::new(LintingViolation::new("PF001", Report::default()), None));
-                }
-            })
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            let Some(feature_type) = &node.inner.r#type else {
+                continue;
+            };
+
+            let severity = if !is_hpo_curie(&feature_type.id) {
+                ViolationSeverity::Info
+            } else if let Ok(term_id) = TermId::from_str(&feature_type.id)
+                && !self
+                    .hpo
+                    .is_descendant_of(&term_id, &self.phenotypic_abnormality)
+            {
+                ViolationSeverity::Error
+            } else {
+                continue;
+            };
+
+            let mut ptr = node.pointer().clone();
+            ptr.down("type");
+
+            violations.push(LintViolation::new(
+                severity,
+                LintRule::rule_id(self),
+                NonEmptyVec::with_single_entry(ptr),
+            ));
+        }
+
+        violations
     }
+}
 
+#[register_report(id = "PF001")]
+struct PhenotypeOntologyChildReport;
 
+impl ReportFromContext for PhenotypeOntologyChildReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(PhenotypeOntologyChildReport))
+    }
 }
-impl PhenotypeOntologyChildRule {
-    pub fn new(hpo: Arc<FullCsrOntology>) -> Self {
-        PhenotypeOntologyChildRule {
-            hpo,
-            phenotypic_abnormality: TermId::from_str("HP:0000118").unwrap(),
-        }
+
+impl CompileReport for PhenotypeOntologyChildReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let type_ptr = lint_violation.first_at();
+        let mut id_ptr = type_ptr.clone();
+        id_ptr.down("id");
+
+        let term_id = full_node
+            .value_at(&id_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        let (message, notes) = if lint_violation.severity() == &ViolationSeverity::Info {
+            (no_ontology_message(&term_id), vec![])
+        } else {
+            (
+                format!("'{term_id}' is not a phenotypic abnormality"),
+                vec![
+                    "Phenotypic features must be descendants of 'Phenotypic abnormality' (HP:0000118)."
+                        .to_string(),
+                ],
+            )
+        };
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            message,
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(type_ptr).unwrap().clone(),
+                String::default(),
+            )],
+            notes,
+        )
     }
 }
+
 #[cfg(test)]
 mod tests {
-    use annotate_snippets::Report;
     use super::*;
     use crate::test_utils::HPO;
-    use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+    use crate::tree::node::MaterializedNode;
+    use crate::tree::pointer::Pointer;
+    use phenopackets::schema::v2::core::OntologyClass;
     use rstest::rstest;
-    use pretty_assertions::assert_eq;
-    use crate::linting_report::LintReportInfo;
+    use std::collections::HashMap;
 
     #[rstest]
-    fn test_find_non_phenotypic_abnormalities() {
-        let rule = PhenotypeOntologyChildRule::new(HPO.clone());
-
-        let pf = OntologyClass {
-            id: "HP:0410401".to_string(),
-            label: "Worse in evening".to_string(),
+    #[case("HP:0410401", "Worse in evening", Some(ViolationSeverity::Error))]
+    #[case("HP:0001250", "Seizure", None)]
+    #[case(
+        "MONDO:0000252",
+        "inflammatory diarrhea",
+        Some(ViolationSeverity::Info)
+    )]
+    fn test_find_non_phenotypic_abnormalities(
+        #[case] id: &str,
+        #[case] label: &str,
+        #[case] expected: Option<ViolationSeverity>,
+    ) {
+        let rule = PhenotypeOntologyChildRule {
+            hpo: HPO.clone(),
+            phenotypic_abnormality: TermId::from_str("HP:0000118").unwrap(),
         };
-
-        let phenopacket = Phenopacket {
-            phenotypic_features: vec![PhenotypicFeature {
-                r#type: Some(pf.clone()),
+        let nodes = [MaterializedNode::new(
+            PhenotypicFeature {
+                r#type: Some(OntologyClass {
+                    id: id.to_string(),
+                    label: label.to_string(),
+                }),
                 ..Default::default()
-            }],
-
-            ..Default::default()
-        };
+            },
+            HashMap::new(),
+            Pointer::new("/phenotypicFeatures/0"),
+        )];
 
-        let mut report = LintReport::new();
-        rule.check(&phenopacket, &mut report);
+        let violations = rule.check(List(&nodes));
 
-       assert_eq!(report.violations().first().unwrap(), &LintingViolation::new("PF001", Report::default()))
+        assert_eq!(violations.first().map(|v| v.severity().clone()), expected);
     }
 }
@@ -0,0 +1,334 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::is_hpo_curie;
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use ontolius::TermId;
+use ontolius::ontology::HierarchyQueries;
+use ontolius::ontology::csr::FullCsrOntology;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Arc;
+
+struct AnnotatedTerm<'a> {
+    ptr: &'a Pointer,
+    term_id: TermId,
+    excluded: bool,
+}
+
+/// ### PF016
+/// ## What it does
+/// Finds lineages of a most specific observed term, in which observed and excluded ancestors
+/// interleave, e.g. observed `Abnormality of limbs`, excluded `Abnormality of the upper limb` and
+/// observed `Abnormal humeral metaphysis morphology`.
+///
+/// Each lineage is reported once, together with the minimal set of annotations to remove,
+/// so that only the most specific observed term is left.
+///
+/// ## Why is this bad?
+/// Such a lineage is redundant and contradicting at the same time. PF007 and PF008 report every
+/// pair of it, which buries the single fix under many overlapping findings.
+#[derive(Debug)]
+#[register_rule(id = "PF016")]
+pub struct RedundancyChainRule {
+    hpo: Arc<FullCsrOntology>,
+}
+
+impl RuleFromContext for RedundancyChainRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let Some(hpo) = context.hpo() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "PF016".to_string(),
+                ontology: "HPO".to_string(),
+            });
+        };
+
+        Ok(Box::new(RedundancyChainRule { hpo }))
+    }
+}
+
+impl RedundancyChainRule {
+    /// An excluded term interleaves, if it is the descendant of an observed term of the same lineage.
+    fn is_interleaved(&self, lineage: &[&AnnotatedTerm]) -> bool {
+        lineage
+            .iter()
+            .filter(|annotated| annotated.excluded)
+            .any(|excluded| {
+                lineage
+                    .iter()
+                    .filter(|annotated| !annotated.excluded)
+                    .any(|observed| {
+                        observed.term_id != excluded.term_id
+                            && self
+                                .hpo
+                                .is_ancestor_of(&observed.term_id, &excluded.term_id)
+                    })
+            })
+    }
+}
+
+impl RuleCheck for RedundancyChainRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let annotated: Vec<AnnotatedTerm> = data
+            .iter()
+            .filter_map(|node| {
+                let feature_type = node.inner.r#type.as_ref()?;
+                if !is_hpo_curie(&feature_type.id) {
+                    return None;
+                }
+                Some(AnnotatedTerm {
+                    ptr: node.pointer(),
+                    term_id: TermId::from_str(&feature_type.id).ok()?,
+                    excluded: node.inner.excluded,
+                })
+            })
+            .collect();
+
+        let leaves = annotated.iter().filter(|leaf| {
+            !leaf.excluded
+                && !annotated.iter().any(|other| {
+                    !other.excluded
+                        && other.term_id != leaf.term_id
+                        && self.hpo.is_ancestor_of(&leaf.term_id, &other.term_id)
+                })
+        });
+
+        let mut claimed: HashSet<&Pointer> = HashSet::new();
+        let mut violations = vec![];
+
+        for leaf in leaves {
+            // Duplicates and exact contradictions of the leaf are reported by PF006 and PF008.
+            let lineage: Vec<&AnnotatedTerm> = annotated
+                .iter()
+                .filter(|annotated| {
+                    !claimed.contains(annotated.ptr)
+                        && annotated.term_id != leaf.term_id
+                        && self.hpo.is_ancestor_of(&annotated.term_id, &leaf.term_id)
+                })
+                .collect();
+
+            if !self.is_interleaved(&lineage) {
+                continue;
+            }
+
+            claimed.extend(lineage.iter().map(|annotated| annotated.ptr));
+            violations.push(LintViolation::new(
+                ViolationSeverity::Warning,
+                LintRule::rule_id(self),
+                NonEmptyVec::with_rest(
+                    leaf.ptr.clone(),
+                    lineage
+                        .iter()
+                        .map(|annotated| annotated.ptr.clone())
+                        .collect(),
+                ),
+            ));
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF016")]
+struct RedundancyChainReport;
+
+impl ReportFromContext for RedundancyChainReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(RedundancyChainReport))
+    }
+}
+
+impl RedundancyChainReport {
+    fn label_at(full_node: &dyn Node, feature_ptr: &Pointer) -> String {
+        let mut ptr = feature_ptr.clone();
+        ptr.down("type").down("label");
+
+        full_node
+            .value_at(&ptr)
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+
+    fn is_excluded(full_node: &dyn Node, feature_ptr: &Pointer) -> bool {
+        let mut ptr = feature_ptr.clone();
+        ptr.down("excluded");
+
+        full_node
+            .value_at(&ptr)
+            .and_then(|value| value.as_bool())
+            .unwrap_or_default()
+    }
+}
+
+impl CompileReport for RedundancyChainReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let leaf_ptr = lint_violation.first_at();
+        let removals = &lint_violation.at()[1..];
+
+        let mut labels = vec![LabelSpecs::new(
+            LabelPriority::Primary,
+            full_node.span_at(leaf_ptr).unwrap().clone(),
+            "Most specific observed term".to_string(),
+        )];
+        for removal_ptr in removals {
+            let label = if Self::is_excluded(full_node, removal_ptr) {
+                "Excluded ancestor"
+            } else {
+                "Redundant observed ancestor"
+            };
+            labels.push(LabelSpecs::new(
+                LabelPriority::Secondary,
+                full_node.span_at(removal_ptr).unwrap().clone(),
+                label.to_string(),
+            ));
+        }
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!(
+                "Observed and excluded ancestors of '{}' interleave",
+                Self::label_at(full_node, leaf_ptr)
+            ),
+            labels,
+            vec![
+                format!(
+                    "Keep the most specific observed term and remove the other {} annotations of its lineage.",
+                    removals.len()
+                ),
+                "This resolves the overlapping findings of PF007 and PF008.".to_string(),
+            ],
+        )
+    }
+}
+
+#[register_patch(id = "PF016")]
+struct RedundancyChainPatch;
+
+impl PatchFromContext for RedundancyChainPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(RedundancyChainPatch))
+    }
+}
+
+impl CompilePatches for RedundancyChainPatch {
+    fn compile_patches(&self, _: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        let mut removals = lint_violation.at()[1..]
+            .iter()
+            .map(|ptr| PatchInstruction::Remove { at: ptr.clone() });
+
+        let Some(first) = removals.next() else {
+            return vec![];
+        };
+
+        vec![Patch::new(NonEmptyVec::with_rest(
+            first,
+            removals.collect(),
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::HPO;
+    use crate::tree::node::MaterializedNode;
+    use phenopackets::schema::v2::core::OntologyClass;
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn feature(id: &str, excluded: bool) -> PhenotypicFeature {
+        PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: id.to_string(),
+                label: "term".to_string(),
+            }),
+            excluded,
+            ..Default::default()
+        }
+    }
+
+    fn check(features: Vec<PhenotypicFeature>) -> Vec<Vec<String>> {
+        let rule = RedundancyChainRule { hpo: HPO.clone() };
+        let nodes: Vec<_> = features
+            .into_iter()
+            .enumerate()
+            .map(|(idx, feature)| {
+                let mut ptr = Pointer::at_phenotypes();
+                ptr.down(idx);
+                MaterializedNode::new(feature, HashMap::new(), ptr)
+            })
+            .collect();
+
+        rule.check(List(&nodes))
+            .iter()
+            .map(|violation| {
+                violation
+                    .at()
+                    .iter()
+                    .map(|ptr| ptr.position().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_deep_chain_is_collapsed() {
+        let findings = check(vec![
+            feature("HP:0000118", false),
+            feature("HP:0040064", true),
+            feature("HP:0002817", false),
+            feature("HP:0009809", true),
+            feature("HP:0003907", false),
+        ]);
+
+        assert_eq!(
+            findings,
+            vec![vec![
+                "/phenotypicFeatures/4",
+                "/phenotypicFeatures/0",
+                "/phenotypicFeatures/1",
+                "/phenotypicFeatures/2",
+                "/phenotypicFeatures/3",
+            ]]
+        );
+    }
+
+    #[rstest]
+    #[case(vec![
+        feature("HP:0040064", false),
+        feature("HP:0002817", false),
+        feature("HP:0003907", false),
+    ])]
+    #[case(vec![
+        feature("HP:0040064", true),
+        feature("HP:0003907", false),
+    ])]
+    #[case(vec![
+        feature("HP:0040064", false),
+        feature("HP:0002817", true),
+        feature("HP:0001250", false),
+    ])]
+    fn test_no_interleaving(#[case] features: Vec<PhenotypicFeature>) {
+        assert!(check(features).is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+
+/// ### PF015
+/// ## What it does
+/// Flags phenotypic features whose `description` only repeats the label of their `type`.
+/// Case and surrounding whitespace are ignored.
+///
+/// ## Why is this bad?
+/// The description is meant for details the ontology term can not capture.
+/// Repeating the label adds noise without adding information.
+#[derive(Debug, Default)]
+#[register_rule(id = "PF015")]
+pub struct RedundantDescriptionRule;
+
+impl RuleFromContext for RedundantDescriptionRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(RedundantDescriptionRule))
+    }
+}
+
+impl RuleCheck for RedundantDescriptionRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        data.iter()
+            .filter(|node| {
+                let description = node.inner.description.trim();
+                !description.is_empty()
+                    && node.inner.r#type.as_ref().is_some_and(|oc| {
+                        oc.label.trim().to_lowercase() == description.to_lowercase()
+                    })
+            })
+            .map(|node| {
+                let mut ptr = node.pointer().clone();
+                ptr.down("description");
+
+                LintViolation::new(
+                    ViolationSeverity::Info,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "PF015")]
+struct RedundantDescriptionReport;
+
+impl ReportFromContext for RedundantDescriptionReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(RedundantDescriptionReport))
+    }
+}
+
+impl CompileReport for RedundantDescriptionReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let description_ptr = lint_violation.first_at();
+        let mut label_ptr = description_ptr.clone();
+        label_ptr.up().down("type").down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Description of '{label}' repeats the label of its type"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(description_ptr).unwrap().clone(),
+                    "Redundant description".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(&label_ptr).unwrap().clone(),
+                    "Same as the label".to_string(),
+                ),
+            ],
+            vec![
+                "Remove the description or use it for details the term does not capture."
+                    .to_string(),
+            ],
+        )
+    }
+}
+
+#[register_patch(id = "PF015")]
+struct RedundantDescriptionPatch;
+
+impl PatchFromContext for RedundantDescriptionPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(RedundantDescriptionPatch))
+    }
+}
+
+impl CompilePatches for RedundantDescriptionPatch {
+    fn compile_patches(&self, _: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Remove {
+                at: lint_violation.first_at().clone(),
+            },
+        ))]
+    }
+}
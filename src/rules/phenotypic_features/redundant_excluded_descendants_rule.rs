@@ -39,7 +39,7 @@ impl RedundantExcludedDescendantsRule {
 
 impl RuleCheck for RedundantExcludedDescendantsRule {
     fn check(&self, phenopacket: &Phenopacket, report: &mut LintReport) {
-        let (_, excluded) = utils::partition_phenotypic_features(phenopacket);
+        let (_, excluded) = utils::partition_phenotypic_features(&phenopacket.phenotypic_features);
 
         // Case 3: Invalidate all descendents of a family for an excluded term
         // Because, if you can exclude a general phenotype the specific one can also be excluded.
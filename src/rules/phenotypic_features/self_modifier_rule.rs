@@ -0,0 +1,128 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::PhenotypicFeature;
+
+/// ### PF010
+/// ## What it does
+/// Flags phenotypic features that list their own `type` among their `modifiers`.
+///
+/// ## Why is this bad?
+/// A term can not modify itself. The modifier carries no information and is most likely a
+/// copy-paste error.
+#[derive(Debug, Default)]
+#[register_rule(id = "PF010")]
+pub struct SelfModifierRule;
+
+impl RuleFromContext for SelfModifierRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(SelfModifierRule))
+    }
+}
+
+impl RuleCheck for SelfModifierRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            let Some(feature_type) = node.inner.r#type.as_ref() else {
+                continue;
+            };
+
+            for (idx, modifier) in node.inner.modifiers.iter().enumerate() {
+                if modifier.id == feature_type.id {
+                    let mut ptr = node.pointer().clone();
+                    ptr.down("modifiers").down(idx);
+
+                    violations.push(LintViolation::new(
+                        ViolationSeverity::Warning,
+                        LintRule::rule_id(self),
+                        NonEmptyVec::with_single_entry(ptr),
+                    ));
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF010")]
+struct SelfModifierReport;
+
+impl ReportFromContext for SelfModifierReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(SelfModifierReport))
+    }
+}
+
+impl CompileReport for SelfModifierReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let modifier_ptr = lint_violation.first_at();
+        let mut type_ptr = modifier_ptr.clone();
+        type_ptr.up().up().down("type");
+
+        let mut id_ptr = modifier_ptr.clone();
+        id_ptr.down("id");
+        let term_id = full_node
+            .value_at(&id_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Phenotypic feature '{term_id}' is modified by itself"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(modifier_ptr).unwrap().clone(),
+                    "Modifier is identical to the type".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(&type_ptr).unwrap().clone(),
+                    String::default(),
+                ),
+            ],
+            vec![],
+        )
+    }
+}
+
+#[register_patch(id = "PF010")]
+struct SelfModifierPatch;
+
+impl PatchFromContext for SelfModifierPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(SelfModifierPatch))
+    }
+}
+
+impl CompilePatches for SelfModifierPatch {
+    fn compile_patches(&self, _: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Remove {
+                at: lint_violation.first_at().clone(),
+            },
+        ))]
+    }
+}
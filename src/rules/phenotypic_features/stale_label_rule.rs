@@ -0,0 +1,257 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::term_to_ontology_class;
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use ontolius::TermId;
+use ontolius::ontology::OntologyTerms;
+use ontolius::ontology::csr::FullCsrOntology;
+use ontolius::term::MinimalTerm;
+use ontolius::term::simple::SimpleTerm;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Lowercases the label and collapses its whitespace, so only the wording is compared.
+fn normalize(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Looks up the term of `id`, if the id is a valid term id of the HPO.
+fn find_term<'o>(hpo: &'o FullCsrOntology, id: &str) -> Option<&'o SimpleTerm> {
+    TermId::from_str(id)
+        .ok()
+        .and_then(|term_id| hpo.term_by_id(&term_id))
+}
+
+/// ### PF018
+/// ## What it does
+/// Flags ontology classes of phenotypic features, whose id is part of the HPO,
+/// but whose label differs from the name of the term. Case and whitespace are ignored.
+///
+/// Unknown ids and empty labels are skipped, they are reported by `PF001` and `PF009`.
+///
+/// ## Why is this bad?
+/// Labels are often copied from older HPO releases and go stale when a term is renamed.
+/// A label that does not match its id makes it unclear which of both was meant.
+#[derive(Debug)]
+#[register_rule(id = "PF018")]
+pub struct StaleLabelRule {
+    hpo: Arc<FullCsrOntology>,
+}
+
+impl RuleFromContext for StaleLabelRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let Some(hpo) = context.hpo() else {
+            return Err(FromContextError::NeedsOntology {
+                rule_ids: "PF018".to_string(),
+                ontology: "HPO".to_string(),
+            });
+        };
+
+        Ok(Box::new(StaleLabelRule { hpo }))
+    }
+}
+
+impl StaleLabelRule {
+    fn is_stale(&self, class: &OntologyClass) -> bool {
+        if class.label.trim().is_empty() {
+            return false;
+        }
+
+        find_term(&self.hpo, &class.id)
+            .is_some_and(|term| normalize(term.name()) != normalize(&class.label))
+    }
+}
+
+impl RuleCheck for StaleLabelRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let mut violations = vec![];
+
+        for node in data.iter() {
+            let feature = &node.inner;
+            let mut classes = vec![];
+
+            if let Some(r#type) = &feature.r#type {
+                let mut ptr = node.pointer().clone();
+                ptr.down("type");
+                classes.push((r#type, ptr));
+            }
+            if let Some(severity) = &feature.severity {
+                let mut ptr = node.pointer().clone();
+                ptr.down("severity");
+                classes.push((severity, ptr));
+            }
+            for (idx, modifier) in feature.modifiers.iter().enumerate() {
+                let mut ptr = node.pointer().clone();
+                ptr.down("modifiers").down(idx);
+                classes.push((modifier, ptr));
+            }
+
+            for (class, ptr) in classes {
+                if self.is_stale(class) {
+                    violations.push(LintViolation::new(
+                        ViolationSeverity::Warning,
+                        LintRule::rule_id(self),
+                        NonEmptyVec::with_single_entry(ptr),
+                    ));
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "PF018")]
+struct StaleLabelReport {
+    hpo: Option<Arc<FullCsrOntology>>,
+}
+
+impl ReportFromContext for StaleLabelReport {
+    fn from_context(
+        context: &LinterContext,
+    ) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(StaleLabelReport { hpo: context.hpo() }))
+    }
+}
+
+impl CompileReport for StaleLabelReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let class_ptr = lint_violation.first_at();
+
+        let value_of = |field: &str| {
+            let mut ptr = class_ptr.clone();
+            ptr.down(field);
+            full_node
+                .value_at(&ptr)
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_default()
+        };
+        let id = value_of("id");
+        let label = value_of("label");
+
+        let name = self
+            .hpo
+            .as_ref()
+            .and_then(|hpo| find_term(hpo, &id).map(|term| term.name().to_string()))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Label '{label}' does not match the name of '{id}'"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(class_ptr).unwrap().clone(),
+                format!("Expected the label '{name}'"),
+            )],
+            vec!["The label may be copied from an older release of the HPO.".to_string()],
+        )
+    }
+}
+
+/// Replaces the ontology class with the id and name of the term in the HPO.
+#[register_patch(id = "PF018")]
+struct StaleLabelPatch {
+    hpo: Option<Arc<FullCsrOntology>>,
+}
+
+impl PatchFromContext for StaleLabelPatch {
+    fn from_context(
+        context: &LinterContext,
+    ) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(StaleLabelPatch { hpo: context.hpo() }))
+    }
+}
+
+impl CompilePatches for StaleLabelPatch {
+    fn compile_patches(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        let Some(hpo) = &self.hpo else {
+            return vec![];
+        };
+
+        let class_ptr = lint_violation.first_at();
+        let mut id_ptr = class_ptr.clone();
+        id_ptr.down("id");
+
+        let Some(term) = full_node
+            .value_at(&id_ptr)
+            .and_then(|id| id.as_str().and_then(|id| find_term(hpo, id)))
+        else {
+            return vec![];
+        };
+
+        let Ok(value) = serde_json::to_value(term_to_ontology_class(term)) else {
+            return vec![];
+        };
+
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Replace {
+                at: class_ptr.clone(),
+                value,
+            },
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::HPO;
+    use crate::tree::node::MaterializedNode;
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn feature(id: &str, label: &str) -> MaterializedNode<PhenotypicFeature> {
+        MaterializedNode::new(
+            PhenotypicFeature {
+                r#type: Some(OntologyClass {
+                    id: id.to_string(),
+                    label: label.to_string(),
+                }),
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new("/phenotypicFeatures/0"),
+        )
+    }
+
+    #[rstest]
+    #[case("HP:0001250", "Seizures", 1)]
+    #[case("HP:0001250", "Seizure", 0)]
+    #[case("HP:0001250", " seizure ", 0)]
+    #[case("HP:0001250", "", 0)]
+    #[case("HP:9999999", "Unknown", 0)]
+    #[case("Seizure", "HP:0001250", 0)]
+    fn test_find_stale_labels(#[case] id: &str, #[case] label: &str, #[case] expected: usize) {
+        let rule = StaleLabelRule { hpo: HPO.clone() };
+        let nodes = [feature(id, label)];
+
+        let violations = rule.check(List(&nodes));
+
+        assert_eq!(violations.len(), expected);
+    }
+}
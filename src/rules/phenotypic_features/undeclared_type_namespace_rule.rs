@@ -0,0 +1,118 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::resources::find_prefix;
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::List;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::{PhenotypicFeature, Resource};
+use std::collections::HashSet;
+
+/// ### PF014
+/// ## What it does
+/// Flags phenotypic features whose type belongs to a namespace that is not among the declared resources.
+///
+/// ## Why is this bad?
+/// The declared resources define the scope of a phenopacket. A mouse phenotype (`MP`) in a phenopacket
+/// that only declares `HP` is either a curation mistake or a missing resource.
+/// Phenopackets without any resources are left to INTER002.
+#[derive(Debug, Default)]
+#[register_rule(id = "PF014")]
+pub struct UndeclaredTypeNamespaceRule;
+
+impl RuleFromContext for UndeclaredTypeNamespaceRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(UndeclaredTypeNamespaceRule))
+    }
+}
+
+impl RuleCheck for UndeclaredTypeNamespaceRule {
+    type Data<'a> = (List<'a, PhenotypicFeature>, List<'a, Resource>);
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let declared_prefixes: HashSet<&str> = data
+            .1
+            .iter()
+            .map(|resource| resource.inner.namespace_prefix.as_str())
+            .collect();
+
+        if declared_prefixes.is_empty() {
+            return vec![];
+        }
+
+        data.0
+            .iter()
+            .filter(|node| {
+                node.inner
+                    .r#type
+                    .as_ref()
+                    .and_then(|feature_type| find_prefix(&feature_type.id))
+                    .is_some_and(|prefix| !declared_prefixes.contains(prefix))
+            })
+            .map(|node| {
+                let mut type_ptr = node.pointer().clone();
+                type_ptr.down("type");
+
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(type_ptr, vec![Pointer::at_resources()]),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "PF014")]
+struct UndeclaredTypeNamespaceReport;
+
+impl ReportFromContext for UndeclaredTypeNamespaceReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(UndeclaredTypeNamespaceReport))
+    }
+}
+
+impl CompileReport for UndeclaredTypeNamespaceReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let type_ptr = lint_violation.first_at().clone();
+        let resources_ptr = &lint_violation.at()[1];
+        let mut id_ptr = type_ptr.clone();
+        id_ptr.down("id");
+
+        let term_id = full_node
+            .value_at(&id_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let prefix = find_prefix(&term_id).unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Phenotypic feature '{term_id}' is from the undeclared namespace '{prefix}'"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(&type_ptr).unwrap().clone(),
+                    String::default(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(resources_ptr).unwrap().clone(),
+                    format!("No resource declares '{prefix}'"),
+                ),
+            ],
+            vec![format!(
+                "Either use a term of a declared namespace or add a resource for '{prefix}'."
+            )],
+        )
+    }
+}
@@ -1,6 +1,12 @@
 use crate::LinterContext;
 use crate::diagnostics::LintViolation;
 use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
 use crate::report::enums::{LabelPriority, ViolationSeverity};
 use crate::report::report_registration::ReportRegistration;
 use crate::report::specs::{LabelSpecs, ReportSpecs};
@@ -8,11 +14,12 @@ use crate::report::traits::RuleReport;
 use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
 use crate::rules::rule_registration::RuleRegistration;
 use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext, RuleMetaData};
+use crate::tree::node::MaterializedNode;
 use crate::tree::node_repository::List;
 use crate::tree::pointer::Pointer;
-use crate::tree::traits::{LocatableNode, Node};
-use phenolint_macros::{register_report, register_rule};
-use phenopackets::schema::v2::core::{OntologyClass, Resource};
+use crate::tree::traits::{LocatableNode, Node, RetrievableNode};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::{Diagnosis, OntologyClass, Resource};
 use std::collections::HashSet;
 
 /// ### INTER002
@@ -109,35 +116,20 @@ impl ReportFromContext for CuriesHaveResourcesReport {
 
 impl CompileReport for CuriesHaveResourcesReport {
     fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
-        let resources_ptr = Pointer::new("/metaData/resources");
-        let span = if let Some(resources_range) = full_node.span_at(&resources_ptr).cloned() {
-            resources_range
-        } else {
-            // `metaData` lacks the `resources` field itself.
-            let metadata_ptr = Pointer::new("/metaData");
-            full_node.span_at(&metadata_ptr)
+        let mut labels = vec![LabelSpecs::new(
+            LabelPriority::Primary,
+            full_node
+                .span_at(lint_violation.first_at())
                 .cloned()
-                .expect("We assume `metaData` is always in the `Node` because we validate the basic phenopacket invariants before running this rule")
-        };
+                .expect("Should be there"),
+            "This ontology class ...".to_string(),
+        )];
+        labels.extend(resources_label(full_node));
 
         ReportSpecs::from_violation(
             lint_violation,
             "An ontology class needs a resource".to_string(),
-            vec![
-                LabelSpecs::new(
-                    LabelPriority::Primary,
-                    full_node
-                        .span_at(lint_violation.first_at())
-                        .cloned()
-                        .expect("Should be there"),
-                    "This ontology class ...".to_string(),
-                ),
-                LabelSpecs::new(
-                    LabelPriority::Secondary,
-                    span,
-                    "... should have a resource here".to_string(),
-                ),
-            ],
+            labels,
             vec![
                 "Phenopacket Schema prescribes that all ontology classes need a resource to document the version of the used ontology, or to support CURIE -> IRI expansion.".to_string(),
             ]
@@ -145,7 +137,308 @@ impl CompileReport for CuriesHaveResourcesReport {
     }
 }
 
-fn find_prefix(curie: &str) -> Option<&str> {
+/// Label at `metaData.resources`, or at `metaData` if it lacks the `resources` field itself.
+///
+/// Without schema validation, the phenopacket may lack `metaData` as well. Then there is no label.
+fn resources_label(full_node: &dyn Node) -> Option<LabelSpecs> {
+    let span = full_node
+        .span_at(&Pointer::new("/metaData/resources"))
+        .or_else(|| full_node.span_at(&Pointer::new("/metaData")))?;
+
+    Some(LabelSpecs::new(
+        LabelPriority::Secondary,
+        span.clone(),
+        "... should have a resource here".to_string(),
+    ))
+}
+
+/// Locations of gene ids within a genomic interpretation.
+const GENE_ID_POSITIONS: [&str; 2] = [
+    "/gene/valueId",
+    "/variantInterpretation/variationDescriptor/geneContext/valueId",
+];
+
+/// Pointers and values of all gene ids within the genomic interpretations of a diagnosis.
+fn gene_ids(diagnosis: &MaterializedNode<Diagnosis>) -> Vec<(Pointer, String)> {
+    let Some(genomic_interpretations) = diagnosis.value_at(&Pointer::new("genomicInterpretations"))
+    else {
+        return vec![];
+    };
+    let Some(genomic_interpretations) = genomic_interpretations.as_array() else {
+        return vec![];
+    };
+
+    let mut gene_ids = vec![];
+    for (idx, genomic_interpretation) in genomic_interpretations.iter().enumerate() {
+        for position in GENE_ID_POSITIONS {
+            let Some(value_id) = genomic_interpretation
+                .pointer(position)
+                .and_then(|value_id| value_id.as_str())
+            else {
+                continue;
+            };
+
+            let mut value_id_ptr = diagnosis.pointer().clone();
+            value_id_ptr.down("genomicInterpretations").down(idx);
+            for segment in Pointer::new(position).segments() {
+                value_id_ptr.down(segment);
+            }
+            gene_ids.push((value_id_ptr, value_id.to_string()));
+        }
+    }
+    gene_ids
+}
+
+/// ### INTER006
+/// ## What it does
+/// Check that a phenopacket contains a resource for the namespace of each gene id
+/// used in genomic interpretations, e.g. `HGNC` or `ENSEMBL`.
+///
+/// ## Why is this bad?
+/// Like ontology classes, gene ids are CURIEs. Without a `Resource` the version of the gene
+/// nomenclature is undocumented and the id can not be expanded to an IRI.
+#[register_rule(id = "INTER006")]
+struct GenesHaveResourcesRule;
+
+impl RuleFromContext for GenesHaveResourcesRule {
+    fn from_context(_context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self))
+    }
+}
+
+impl RuleCheck for GenesHaveResourcesRule {
+    type Data<'a> = (List<'a, Diagnosis>, List<'a, Resource>);
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let known_prefixes: HashSet<_> = data
+            .1
+            .iter()
+            .map(|r| r.inner.namespace_prefix.as_str())
+            .collect();
+
+        let mut violations = vec![];
+
+        for node in data.0.iter() {
+            for (value_id_ptr, value_id) in gene_ids(node) {
+                if let Some(prefix) = find_prefix(&value_id)
+                    && !known_prefixes.contains(prefix)
+                {
+                    violations.push(LintViolation::new(
+                        ViolationSeverity::Error,
+                        LintRule::rule_id(self),
+                        NonEmptyVec::with_single_entry(value_id_ptr),
+                    ));
+                }
+            }
+        }
+        violations
+    }
+}
+
+#[register_report(id = "INTER006")]
+pub struct GenesHaveResourcesReport;
+
+impl ReportFromContext for GenesHaveResourcesReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(Self))
+    }
+}
+
+impl CompileReport for GenesHaveResourcesReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let value_id = full_node
+            .value_at(lint_violation.first_at())
+            .and_then(|value_id| value_id.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        let mut labels = vec![LabelSpecs::new(
+            LabelPriority::Primary,
+            full_node
+                .span_at(lint_violation.first_at())
+                .cloned()
+                .expect("Should be there"),
+            "This gene id ...".to_string(),
+        )];
+        labels.extend(resources_label(full_node));
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Gene id '{value_id}' needs a resource"),
+            labels,
+            vec![
+                "Declare the gene nomenclature, e.g. HGNC or Ensembl, as a resource with a matching namespace prefix.".to_string(),
+            ],
+        )
+    }
+}
+
+/// ### INTER003
+/// ## What it does
+/// Check that every resource is used by at least one CURIE of the phenopacket, i.e. by the id of an
+/// ontology class or by a gene id of a genomic interpretation.
+///
+/// ## Why is this bad?
+/// Unused resources suggest that terms were removed, or that the resources were copied from
+/// another phenopacket. They document ontology versions, that the phenopacket does not depend on.
+#[register_rule(id = "INTER003")]
+struct UnusedResourcesRule;
+
+impl RuleFromContext for UnusedResourcesRule {
+    fn from_context(_context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self))
+    }
+}
+
+impl RuleCheck for UnusedResourcesRule {
+    type Data<'a> = (
+        List<'a, OntologyClass>,
+        List<'a, Resource>,
+        List<'a, Diagnosis>,
+    );
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let gene_ids: Vec<String> = data
+            .2
+            .iter()
+            .flat_map(|node| gene_ids(node).into_iter().map(|(_, value_id)| value_id))
+            .collect();
+        let used_prefixes: HashSet<&str> = data
+            .0
+            .iter()
+            .map(|node| node.inner.id.as_str())
+            .chain(gene_ids.iter().map(String::as_str))
+            .filter_map(find_prefix)
+            .collect();
+
+        data.1
+            .iter()
+            .filter(|node| !used_prefixes.contains(node.inner.namespace_prefix.as_str()))
+            .map(|node| {
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(node.pointer().clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "INTER003")]
+pub struct UnusedResourcesReport;
+
+impl ReportFromContext for UnusedResourcesReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(Self))
+    }
+}
+
+impl CompileReport for UnusedResourcesReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let mut prefix_ptr = lint_violation.first_at().clone();
+        prefix_ptr.down("namespacePrefix");
+
+        let namespace_prefix = full_node
+            .value_at(&prefix_ptr)
+            .and_then(|prefix| prefix.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Resource '{namespace_prefix}' is not used by any CURIE"),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node
+                    .span_at(lint_violation.first_at())
+                    .cloned()
+                    .expect("Should be there"),
+                "Unused resource".to_string(),
+            )],
+            vec![
+                "Remove the resource, or check whether the terms of its ontology were lost."
+                    .to_string(),
+            ],
+        )
+    }
+}
+
+#[register_patch(id = "INTER003")]
+struct UnusedResourcesPatch;
+
+impl PatchFromContext for UnusedResourcesPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(Self))
+    }
+}
+
+impl CompilePatches for UnusedResourcesPatch {
+    fn compile_patches(&self, _: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Remove {
+                at: lint_violation.first_at().clone(),
+            },
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod test_unused_resources {
+    use crate::rules::resources::UnusedResourcesRule;
+    use crate::rules::traits::RuleCheck;
+    use crate::tree::node::MaterializedNode;
+    use crate::tree::node_repository::List;
+    use crate::tree::pointer::Pointer;
+    use phenopackets::schema::v2::core::{OntologyClass, Resource};
+    use rstest::rstest;
+
+    fn resource(idx: usize, namespace_prefix: &str) -> MaterializedNode<Resource> {
+        MaterializedNode::new(
+            Resource {
+                id: namespace_prefix.to_lowercase(),
+                namespace_prefix: namespace_prefix.to_string(),
+                ..Default::default()
+            },
+            Default::default(),
+            Pointer::new(&format!("/metaData/resources/{idx}")),
+        )
+    }
+
+    fn ontology_class(id: &str) -> MaterializedNode<OntologyClass> {
+        MaterializedNode::new(
+            OntologyClass {
+                id: id.to_string(),
+                label: "term".to_string(),
+            },
+            Default::default(),
+            Pointer::new("/phenotypicFeatures/0/type"),
+        )
+    }
+
+    #[rstest]
+    #[case(vec!["HP:0001250", "MONDO:0005027"], vec![])]
+    #[case(vec!["HP:0001250"], vec!["/metaData/resources/1"])]
+    #[case(vec![], vec!["/metaData/resources/0", "/metaData/resources/1"])]
+    fn test_find_unused_resources(#[case] used_ids: Vec<&str>, #[case] expected: Vec<&str>) {
+        let ocs: Vec<_> = used_ids.into_iter().map(ontology_class).collect();
+        let resources = [resource(0, "HP"), resource(1, "MONDO")];
+
+        let violations = UnusedResourcesRule.check((List(&ocs), List(&resources), List(&[])));
+
+        let positions: Vec<&str> = violations
+            .iter()
+            .map(|violation| violation.first_at().position())
+            .collect();
+        assert_eq!(positions, expected);
+    }
+}
+
+pub(crate) fn find_prefix(curie: &str) -> Option<&str> {
     if let Some(idx) = curie.find(":") {
         Some(&curie[..idx])
     } else if let Some(idx) = curie.find("_") {
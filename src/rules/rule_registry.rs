@@ -2,11 +2,14 @@ use crate::linter_context::LinterContext;
 use crate::rules::rule_registration::{RuleRegistration, all_rule_ids};
 use crate::rules::traits::LintRule;
 use log::warn;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 
+/// Enabled rules, keyed and iterated in order of their rule ID.
+///
+/// The stable order keeps `fail_fast` and `max_findings` deterministic.
 #[derive(Default)]
 pub struct RuleRegistry {
-    rules: HashMap<String, Box<dyn LintRule>>,
+    rules: BTreeMap<String, Box<dyn LintRule>>,
 }
 
 impl RuleRegistry {
@@ -19,7 +22,7 @@ impl RuleRegistry {
     }
 
     pub fn with_enabled_rules(enabled_rules: &[String], context: &LinterContext) -> Self {
-        let mut registry = HashMap::new();
+        let mut registry = BTreeMap::new();
 
         for registration in inventory::iter::<RuleRegistration> {
             if enabled_rules
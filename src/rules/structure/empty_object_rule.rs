@@ -0,0 +1,182 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use serde_json::Value;
+
+/// Keys of optional objects. Removing them does not invalidate the phenopacket.
+const OPTIONAL_OBJECT_KEYS: [&str; 21] = [
+    "onset",
+    "resolution",
+    "severity",
+    "vitalStatus",
+    "timeAtLastEncounter",
+    "timeOfDeath",
+    "causeOfDeath",
+    "taxonomy",
+    "primarySite",
+    "laterality",
+    "procedure",
+    "timeOfCollection",
+    "sampledTissue",
+    "sampleType",
+    "histologicalDiagnosis",
+    "tumorProgression",
+    "tumorGrade",
+    "treatmentTarget",
+    "treatmentIntent",
+    "fileAttributes",
+    "individualToFileIdentifiers",
+];
+
+/// Collects the pointers of all empty objects stored under one of the `OPTIONAL_OBJECT_KEYS`.
+fn find_empty_objects(value: &Value, ptr: &Pointer, found: &mut Vec<Pointer>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let mut child_ptr = ptr.clone();
+                child_ptr.down(key);
+
+                if child.as_object().is_some_and(|obj| obj.is_empty())
+                    && OPTIONAL_OBJECT_KEYS.contains(&key.as_str())
+                {
+                    found.push(child_ptr);
+                } else {
+                    find_empty_objects(child, &child_ptr, found);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for (idx, child) in values.iter().enumerate() {
+                let mut child_ptr = ptr.clone();
+                child_ptr.down(idx);
+                find_empty_objects(child, &child_ptr, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// ### STR001
+/// ## What it does
+/// Finds empty objects like `"onset": {}` in optional positions. These stubs are often emitted by
+/// tools that serialize unset fields. Empty objects in required positions, e.g. the `type` of
+/// a phenotypic feature, are not flagged, because removing them would not fix the phenopacket.
+///
+/// ## Why is this bad?
+/// An empty object suggests that information was recorded, while it carries none.
+#[derive(Debug, Default)]
+#[register_rule(id = "STR001")]
+pub struct EmptyObjectRule;
+
+impl RuleFromContext for EmptyObjectRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(EmptyObjectRule))
+    }
+}
+
+impl RuleCheck for EmptyObjectRule {
+    type Data<'a> = Single<'a, Value>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(root) = data.0 else {
+            return vec![];
+        };
+
+        let mut found = vec![];
+        find_empty_objects(&root.inner, root.pointer(), &mut found);
+
+        found
+            .into_iter()
+            .map(|ptr| {
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "STR001")]
+struct EmptyObjectReport;
+
+impl ReportFromContext for EmptyObjectReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(EmptyObjectReport))
+    }
+}
+
+impl CompileReport for EmptyObjectReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let violation_ptr = lint_violation.first_at();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Found empty '{}' object", violation_ptr.get_tip()),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(violation_ptr).unwrap().clone(),
+                "Carries no information".to_string(),
+            )],
+            vec![],
+        )
+    }
+}
+
+#[register_patch(id = "STR001")]
+struct EmptyObjectPatch;
+
+impl PatchFromContext for EmptyObjectPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(EmptyObjectPatch))
+    }
+}
+
+impl CompilePatches for EmptyObjectPatch {
+    fn compile_patches(&self, _: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Remove {
+                at: lint_violation.first_at().clone(),
+            },
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+
+    #[rstest]
+    #[case(json!({"phenotypicFeatures": [{"type": {"id": "HP:0001250", "label": "Seizure"}, "onset": {}}]}), vec!["/phenotypicFeatures/0/onset"])]
+    #[case(json!({"phenotypicFeatures": [{"type": {"id": "HP:0001250", "label": "Seizure"}, "onset": {"age": {"iso8601duration": "P3Y"}}}]}), vec![])]
+    #[case(json!({"phenotypicFeatures": [{"type": {}}]}), vec![])]
+    #[case(json!({"subject": {"id": "s", "vitalStatus": {}}}), vec!["/subject/vitalStatus"])]
+    fn test_find_empty_objects(#[case] value: Value, #[case] expected: Vec<&str>) {
+        let mut found = vec![];
+        find_empty_objects(&value, &Pointer::at_root(), &mut found);
+
+        let found: Vec<&str> = found.iter().map(|ptr| ptr.position()).collect();
+        assert_eq!(found, expected);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use std::collections::{BTreeSet, HashMap};
+
+/// Returns the kind of entity an `id` pointer belongs to, e.g. `biosample` for `/biosamples/0/id`.
+fn entity_kind(id_ptr: &Pointer) -> &'static str {
+    match id_ptr.segments().next().as_deref() {
+        Some("subject") => "subject",
+        Some("interpretations") => "interpretation",
+        Some("biosamples") => "biosample",
+        _ => "phenopacket",
+    }
+}
+
+/// ### STR002
+/// ## What it does
+/// Flags id strings that are used by more than one kind of entity, e.g. a biosample with the same id
+/// as the phenopacket. Considered are the ids of the phenopacket, the subject, interpretations and biosamples.
+///
+/// Kinds that intentionally share ids can be grouped in the `LinterContext`.
+///
+/// ## Why is this bad?
+/// References like `subjectOrBiosampleId` can not tell which entity is meant, when several
+/// entities share an id.
+#[derive(Debug, Default)]
+#[register_rule(id = "STR002")]
+pub struct IdCollisionRule {
+    shared_kinds: HashMap<String, String>,
+}
+
+impl IdCollisionRule {
+    fn canonical_kind(&self, kind: &str) -> String {
+        self.shared_kinds
+            .get(kind)
+            .cloned()
+            .unwrap_or_else(|| kind.to_string())
+    }
+}
+
+impl RuleFromContext for IdCollisionRule {
+    fn from_context(context: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        let mut shared_kinds = HashMap::new();
+
+        for group in context.shared_id_kinds() {
+            let Some(canonical) = group.first().map(|kind| kind.to_lowercase()) else {
+                continue;
+            };
+            for kind in group {
+                shared_kinds.insert(kind.to_lowercase(), canonical.clone());
+            }
+        }
+
+        Ok(Box::new(IdCollisionRule { shared_kinds }))
+    }
+}
+
+impl RuleCheck for IdCollisionRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(node) = data.0 else {
+            return vec![];
+        };
+        let pp = &node.inner;
+
+        let mut ids: Vec<(&str, Pointer)> = vec![(pp.id.as_str(), Pointer::new("id"))];
+        if let Some(subject) = &pp.subject {
+            let mut ptr = Pointer::at_subject();
+            ptr.down("id");
+            ids.push((subject.id.as_str(), ptr));
+        }
+        for (idx, interpretation) in pp.interpretations.iter().enumerate() {
+            let mut ptr = Pointer::new("interpretations");
+            ptr.down(idx).down("id");
+            ids.push((interpretation.id.as_str(), ptr));
+        }
+        for (idx, biosample) in pp.biosamples.iter().enumerate() {
+            let mut ptr = Pointer::new("biosamples");
+            ptr.down(idx).down("id");
+            ids.push((biosample.id.as_str(), ptr));
+        }
+
+        let mut by_id: Vec<(&str, Vec<Pointer>)> = vec![];
+        for (id, ptr) in ids.into_iter().filter(|(id, _)| !id.is_empty()) {
+            match by_id.iter_mut().find(|(known, _)| *known == id) {
+                Some((_, pointers)) => pointers.push(ptr),
+                None => by_id.push((id, vec![ptr])),
+            }
+        }
+
+        by_id
+            .into_iter()
+            .filter(|(_, pointers)| {
+                let kinds: BTreeSet<String> = pointers
+                    .iter()
+                    .map(|ptr| self.canonical_kind(entity_kind(ptr)))
+                    .collect();
+                kinds.len() > 1
+            })
+            .map(|(_, mut pointers)| {
+                let first = pointers.remove(0);
+
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(first, pointers),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "STR002")]
+struct IdCollisionReport;
+
+impl ReportFromContext for IdCollisionReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(IdCollisionReport))
+    }
+}
+
+impl CompileReport for IdCollisionReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let first_ptr = lint_violation.first_at();
+        let id = full_node
+            .value_at(first_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        let kinds: BTreeSet<&str> = lint_violation.at().iter().map(entity_kind).collect();
+        let kinds: Vec<&str> = kinds.into_iter().collect();
+
+        let mut labels = vec![LabelSpecs::new(
+            LabelPriority::Primary,
+            full_node.span_at(first_ptr).unwrap().clone(),
+            format!("Used by the {}", entity_kind(first_ptr)),
+        )];
+        for ptr in lint_violation.at().iter().skip(1) {
+            if let Some(span) = full_node.span_at(ptr) {
+                labels.push(LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    span.clone(),
+                    format!("Reused by a {}", entity_kind(ptr)),
+                ));
+            }
+        }
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Id '{id}' is shared by: {}", kinds.join(", ")),
+            labels,
+            vec!["Give each entity its own id, so references to it are unambiguous.".to_string()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use phenopackets::schema::v2::core::{Biosample, Individual};
+    use rstest::rstest;
+
+    fn phenopacket(subject_id: &str, biosample_ids: &[&str]) -> Phenopacket {
+        Phenopacket {
+            id: "patient-1".to_string(),
+            subject: Some(Individual {
+                id: subject_id.to_string(),
+                ..Default::default()
+            }),
+            biosamples: biosample_ids
+                .iter()
+                .map(|id| Biosample {
+                    id: id.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(phenopacket("P1", &["patient-1"]), vec![], vec![vec!["/id", "/biosamples/0/id"]])]
+    #[case(phenopacket("patient-1", &["sample-1"]), vec![], vec![vec!["/id", "/subject/id"]])]
+    #[case(phenopacket("patient-1", &["sample-1"]), vec![("subject", "phenopacket")], vec![])]
+    #[case(phenopacket("P1", &["sample-1", "sample-1"]), vec![], vec![])]
+    #[case(phenopacket("P1", &["sample-1"]), vec![], vec![])]
+    fn test_find_id_collisions(
+        #[case] pp: Phenopacket,
+        #[case] shared_kinds: Vec<(&str, &str)>,
+        #[case] expected: Vec<Vec<&str>>,
+    ) {
+        let rule = IdCollisionRule {
+            shared_kinds: shared_kinds
+                .into_iter()
+                .map(|(kind, canonical)| (kind.to_string(), canonical.to_string()))
+                .collect(),
+        };
+        let node = MaterializedNode::new(pp, HashMap::new(), Pointer::at_root());
+
+        let violations = rule.check(Single(Some(&node)));
+
+        let positions: Vec<Vec<&str>> = violations
+            .iter()
+            .map(|violation| violation.at().iter().map(Pointer::position).collect())
+            .collect();
+        assert_eq!(positions, expected);
+    }
+}
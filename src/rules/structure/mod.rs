@@ -0,0 +1,3 @@
+pub mod empty_object_rule;
+pub mod id_collision_rule;
+pub mod stringified_boolean_rule;
@@ -0,0 +1,124 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::parsing::stringified_booleans::{find_stringified_booleans, parse_boolean};
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use serde_json::Value;
+
+/// ### STR003
+/// ## What it does
+/// Finds booleans that are serialized as strings, e.g. `"excluded": "true"`.
+///
+/// Phenopackets are validated with their stringified booleans parsed, so these values are reported
+/// and patched instead of rejecting the whole phenopacket.
+///
+/// ## Why is this bad?
+/// Stringified booleans are usually produced by tooling that writes every value as a string.
+/// Strict parsers reject them, lenient ones may read any non-empty string as `true`.
+#[derive(Debug, Default)]
+#[register_rule(id = "STR003")]
+pub struct StringifiedBooleanRule;
+
+impl RuleFromContext for StringifiedBooleanRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(StringifiedBooleanRule))
+    }
+}
+
+impl RuleCheck for StringifiedBooleanRule {
+    type Data<'a> = Single<'a, Value>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(root) = data.0 else {
+            return vec![];
+        };
+
+        let mut found = vec![];
+        find_stringified_booleans(&root.inner, root.pointer(), &mut found);
+
+        found
+            .into_iter()
+            .map(|(ptr, _)| {
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(ptr),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "STR003")]
+struct StringifiedBooleanReport;
+
+impl ReportFromContext for StringifiedBooleanReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(StringifiedBooleanReport))
+    }
+}
+
+impl CompileReport for StringifiedBooleanReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let violation_ptr = lint_violation.first_at();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!(
+                "Found a string instead of a boolean for '{}'",
+                violation_ptr.get_tip()
+            ),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(violation_ptr).unwrap().clone(),
+                "Expected a boolean".to_string(),
+            )],
+            vec!["Write the value without quotes, e.g. 'true' instead of '\"true\"'.".to_string()],
+        )
+    }
+}
+
+#[register_patch(id = "STR003")]
+struct StringifiedBooleanPatch;
+
+impl PatchFromContext for StringifiedBooleanPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(StringifiedBooleanPatch))
+    }
+}
+
+impl CompilePatches for StringifiedBooleanPatch {
+    fn compile_patches(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        let at = lint_violation.first_at();
+
+        let Some(boolean) = full_node
+            .value_at(at)
+            .and_then(|value| value.as_str().and_then(parse_boolean))
+        else {
+            return vec![];
+        };
+
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Replace {
+                at: at.clone(),
+                value: Value::Bool(boolean),
+            },
+        ))]
+    }
+}
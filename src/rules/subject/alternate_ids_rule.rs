@@ -0,0 +1,156 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+
+/// ### SUBJ002
+/// ## What it does
+/// Flags empty and duplicated entries in `subject.alternateIds`. IDs are compared without
+/// surrounding whitespace, exact duplicates are already rejected by the schema validation.
+///
+/// ## Why is this bad?
+/// An empty ID identifies nothing and a duplicated ID adds nothing. Both are usually left-overs
+/// of an export or a merge of records.
+#[derive(Debug, Default)]
+#[register_rule(id = "SUBJ002")]
+pub struct AlternateIdsRule;
+
+impl RuleFromContext for AlternateIdsRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(AlternateIdsRule))
+    }
+}
+
+impl RuleCheck for AlternateIdsRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(subject) = data.0.and_then(|node| node.inner.subject.as_ref()) else {
+            return vec![];
+        };
+
+        let mut alternate_ids_ptr = Pointer::at_subject();
+        alternate_ids_ptr.down("alternateIds");
+        let at_idx = |idx: usize| {
+            let mut ptr = alternate_ids_ptr.clone();
+            ptr.down(idx);
+            ptr
+        };
+
+        let mut violations = vec![];
+
+        for (idx, alternate_id) in subject.alternate_ids.iter().enumerate() {
+            if alternate_id.trim().is_empty() {
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_single_entry(at_idx(idx)),
+                ));
+            } else if let Some(first_idx) = subject.alternate_ids[..idx]
+                .iter()
+                .position(|previous| previous.trim() == alternate_id.trim())
+            {
+                violations.push(LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(at_idx(idx), vec![at_idx(first_idx)]),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+#[register_report(id = "SUBJ002")]
+struct AlternateIdsReport;
+
+impl ReportFromContext for AlternateIdsReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(AlternateIdsReport))
+    }
+}
+
+impl CompileReport for AlternateIdsReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let id_ptr = lint_violation.first_at();
+
+        let Some(first_ptr) = lint_violation.at().get(1) else {
+            return ReportSpecs::from_violation(
+                lint_violation,
+                "Subject has an empty alternate ID".to_string(),
+                vec![LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(id_ptr).unwrap().clone(),
+                    String::default(),
+                )],
+                vec![],
+            );
+        };
+
+        let alternate_id = full_node
+            .value_at(id_ptr)
+            .and_then(|id| id.as_str().map(|id| id.trim().to_string()))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Subject lists alternate ID '{alternate_id}' more than once"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(id_ptr).unwrap().clone(),
+                    "Duplicate".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(first_ptr).unwrap().clone(),
+                    "First listed here".to_string(),
+                ),
+            ],
+            vec![],
+        )
+    }
+}
+
+#[register_patch(id = "SUBJ002")]
+struct AlternateIdsPatch;
+
+impl PatchFromContext for AlternateIdsPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(AlternateIdsPatch))
+    }
+}
+
+impl CompilePatches for AlternateIdsPatch {
+    fn compile_patches(&self, _: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        // Only empty IDs are removed. For duplicates, it is up to the curator which entry to keep.
+        if lint_violation.at().len() > 1 {
+            return vec![];
+        }
+
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Remove {
+                at: lint_violation.first_at().clone(),
+            },
+        ))]
+    }
+}
@@ -0,0 +1,184 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::{List, Single};
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::core::{Disease, VitalStatus};
+
+/// ### SUBJ004
+/// ## What it does
+/// Flags a `subject.vitalStatus.causeOfDeath` that is not among the observed diseases of the phenopacket.
+///
+/// ## Why is this bad?
+/// The cause of death is usually a condition of the subject. If it is missing from the diseases,
+/// either the disease was not annotated or the cause of death was copied from another case.
+#[derive(Debug, Default)]
+#[register_rule(id = "SUBJ004")]
+pub struct CauseOfDeathDiseaseRule;
+
+impl RuleFromContext for CauseOfDeathDiseaseRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(CauseOfDeathDiseaseRule))
+    }
+}
+
+impl RuleCheck for CauseOfDeathDiseaseRule {
+    type Data<'a> = (Single<'a, VitalStatus>, List<'a, Disease>);
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(vital_status) = data.0.0 else {
+            return vec![];
+        };
+        let Some(cause_of_death) = vital_status.inner.cause_of_death.as_ref() else {
+            return vec![];
+        };
+
+        let is_listed = data.1.iter().any(|node| {
+            !node.inner.excluded
+                && node
+                    .inner
+                    .term
+                    .as_ref()
+                    .is_some_and(|term| term.id == cause_of_death.id)
+        });
+        if is_listed {
+            return vec![];
+        }
+
+        let mut cause_ptr = vital_status.pointer().clone();
+        cause_ptr.down("causeOfDeath");
+        let diseases_ptr = data.1.first().and_then(|node| node.pointer().parent());
+
+        vec![LintViolation::new(
+            ViolationSeverity::Info,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_rest(cause_ptr, diseases_ptr.into_iter().collect()),
+        )]
+    }
+}
+
+#[register_report(id = "SUBJ004")]
+struct CauseOfDeathDiseaseReport;
+
+impl ReportFromContext for CauseOfDeathDiseaseReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(CauseOfDeathDiseaseReport))
+    }
+}
+
+impl CompileReport for CauseOfDeathDiseaseReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let cause_ptr = lint_violation.first_at();
+        let mut id_ptr = cause_ptr.clone();
+        id_ptr.down("id");
+
+        let cause_id = full_node
+            .value_at(&id_ptr)
+            .and_then(|id| id.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        let mut labels = vec![LabelSpecs::new(
+            LabelPriority::Primary,
+            full_node.span_at(cause_ptr).unwrap().clone(),
+            "Cause of death".to_string(),
+        )];
+        if let Some(diseases_ptr) = lint_violation.at().get(1) {
+            labels.push(LabelSpecs::new(
+                LabelPriority::Secondary,
+                full_node.span_at(diseases_ptr).unwrap().clone(),
+                format!("No observed disease '{cause_id}'"),
+            ));
+        }
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Cause of death '{cause_id}' is not listed among the diseases"),
+            labels,
+            vec![
+                "Add the cause of death to the diseases, if it is a condition of the subject."
+                    .to_string(),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::node::MaterializedNode;
+    use crate::tree::pointer::Pointer;
+    use phenopackets::schema::v2::core::OntologyClass;
+    use phenopackets::schema::v2::core::vital_status::Status;
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn term(id: &str) -> Option<OntologyClass> {
+        Some(OntologyClass {
+            id: id.to_string(),
+            label: "term".to_string(),
+        })
+    }
+
+    #[rstest]
+    #[case("MONDO:0007254", false, 0)]
+    #[case("MONDO:0007254", true, 1)]
+    #[case("MONDO:0005027", false, 1)]
+    fn test_find_orphan_cause_of_death(
+        #[case] disease_id: &str,
+        #[case] excluded: bool,
+        #[case] n_violations: usize,
+    ) {
+        let vital_status = MaterializedNode::new(
+            VitalStatus {
+                status: Status::Deceased.into(),
+                cause_of_death: term("MONDO:0007254"),
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new("/subject/vitalStatus"),
+        );
+        let diseases = [MaterializedNode::new(
+            Disease {
+                term: term(disease_id),
+                excluded,
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new("/diseases/0"),
+        )];
+
+        let violations =
+            CauseOfDeathDiseaseRule.check((Single(Some(&vital_status)), List(&diseases)));
+
+        assert_eq!(violations.len(), n_violations);
+        if let Some(violation) = violations.first() {
+            assert_eq!(violation.at()[1].position(), "/diseases");
+        }
+    }
+
+    #[rstest]
+    fn test_no_cause_of_death() {
+        let vital_status = MaterializedNode::new(
+            VitalStatus {
+                status: Status::Alive.into(),
+                ..Default::default()
+            },
+            HashMap::new(),
+            Pointer::new("/subject/vitalStatus"),
+        );
+
+        let violations = CauseOfDeathDiseaseRule.check((Single(Some(&vital_status)), List(&[])));
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::rules::utils::parse_iso8601_duration;
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Individual;
+use phenopackets::schema::v2::core::time_element::Element;
+
+/// Whether the last encounter of the subject lies before its birth.
+///
+/// Ages are relative to the birth, so only negative ages like `-P5Y` are before it.
+/// Other forms of `TimeElement` can not be compared with a date.
+fn is_before_birth(subject: &Individual) -> bool {
+    let (Some(time_at_last_encounter), Some(date_of_birth)) =
+        (&subject.time_at_last_encounter, &subject.date_of_birth)
+    else {
+        return false;
+    };
+
+    match &time_at_last_encounter.element {
+        Some(Element::Age(age)) => age
+            .iso8601duration
+            .strip_prefix('-')
+            .and_then(parse_iso8601_duration)
+            .is_some_and(|days| days > 0.0),
+        Some(Element::Timestamp(timestamp)) => {
+            (timestamp.seconds, timestamp.nanos) < (date_of_birth.seconds, date_of_birth.nanos)
+        }
+        _ => false,
+    }
+}
+
+/// ### SUBJ006
+/// ## What it does
+/// Flags subjects whose `timeAtLastEncounter` lies before their `dateOfBirth`,
+/// either as a timestamp before the date of birth or as a negative age.
+///
+/// ## Why is this bad?
+/// A subject can not be encountered before it was born. Usually the two dates were transposed
+/// or the age was entered with a wrong sign.
+#[derive(Debug, Default)]
+#[register_rule(id = "SUBJ006")]
+pub struct LastEncounterBeforeBirthRule;
+
+impl RuleFromContext for LastEncounterBeforeBirthRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(LastEncounterBeforeBirthRule))
+    }
+}
+
+impl RuleCheck for LastEncounterBeforeBirthRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(subject) = data.0.and_then(|node| node.inner.subject.as_ref()) else {
+            return vec![];
+        };
+        if !is_before_birth(subject) {
+            return vec![];
+        }
+
+        let mut encounter_ptr = Pointer::at_subject();
+        encounter_ptr.down("timeAtLastEncounter");
+        let mut birth_ptr = Pointer::at_subject();
+        birth_ptr.down("dateOfBirth");
+
+        vec![LintViolation::new(
+            ViolationSeverity::Warning,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_rest(encounter_ptr, vec![birth_ptr]),
+        )]
+    }
+}
+
+#[register_report(id = "SUBJ006")]
+struct LastEncounterBeforeBirthReport;
+
+impl ReportFromContext for LastEncounterBeforeBirthReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(LastEncounterBeforeBirthReport))
+    }
+}
+
+impl CompileReport for LastEncounterBeforeBirthReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let encounter_ptr = lint_violation.first_at();
+        let birth_ptr = &lint_violation.at()[1];
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            "The last encounter of the subject lies before its date of birth".to_string(),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(encounter_ptr).unwrap().clone(),
+                    "Last encounter".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(birth_ptr).unwrap().clone(),
+                    "Date of birth".to_string(),
+                ),
+            ],
+            vec![
+                "Check whether the dates were transposed or the age has a wrong sign.".to_string(),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_before_birth;
+    use phenopackets::schema::v2::core::time_element::Element;
+    use phenopackets::schema::v2::core::{Age, Individual, OntologyClass, TimeElement};
+    use prost_types::Timestamp;
+    use rstest::rstest;
+
+    fn age(iso8601duration: &str) -> Option<TimeElement> {
+        Some(TimeElement {
+            element: Some(Element::Age(Age {
+                iso8601duration: iso8601duration.to_string(),
+            })),
+        })
+    }
+
+    fn timestamp(seconds: i64) -> Option<TimeElement> {
+        Some(TimeElement {
+            element: Some(Element::Timestamp(Timestamp { seconds, nanos: 0 })),
+        })
+    }
+
+    #[rstest]
+    #[case(age("P5Y"), Some(100), false)]
+    #[case(age("-P5Y"), Some(100), true)]
+    #[case(age("-P0D"), Some(100), false)]
+    #[case(age("-5years"), Some(100), false)]
+    #[case(timestamp(200), Some(100), false)]
+    #[case(timestamp(100), Some(100), false)]
+    #[case(timestamp(50), Some(100), true)]
+    #[case(Some(TimeElement { element: Some(Element::OntologyClass(OntologyClass::default())) }), Some(100), false)]
+    #[case(timestamp(50), None, false)]
+    #[case(None, Some(100), false)]
+    fn test_is_before_birth(
+        #[case] time_at_last_encounter: Option<TimeElement>,
+        #[case] date_of_birth: Option<i64>,
+        #[case] expected: bool,
+    ) {
+        let subject = Individual {
+            time_at_last_encounter,
+            date_of_birth: date_of_birth.map(|seconds| Timestamp { seconds, nanos: 0 }),
+            ..Default::default()
+        };
+
+        assert_eq!(is_before_birth(&subject), expected);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+
+/// ### SUBJ001
+/// ## What it does
+/// Flags phenopackets without a `subject`, while interpretations or biosamples reference a subject ID.
+///
+/// ## Why is this bad?
+/// The references point to an individual that is not described anywhere in the phenopacket.
+/// The phenopacket is malformed and the references can not be resolved.
+#[derive(Debug, Default)]
+#[register_rule(id = "SUBJ001")]
+pub struct MissingSubjectRule;
+
+impl RuleFromContext for MissingSubjectRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(MissingSubjectRule))
+    }
+}
+
+impl RuleCheck for MissingSubjectRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(node) = data.0 else {
+            return vec![];
+        };
+        let pp = &node.inner;
+
+        if pp.subject.is_some() {
+            return vec![];
+        }
+
+        let mut references = vec![];
+
+        for (i_idx, interpretation) in pp.interpretations.iter().enumerate() {
+            let Some(diagnosis) = &interpretation.diagnosis else {
+                continue;
+            };
+
+            for (g_idx, genomic_interpretation) in
+                diagnosis.genomic_interpretations.iter().enumerate()
+            {
+                if !genomic_interpretation.subject_or_biosample_id.is_empty() {
+                    let mut ptr = Pointer::new("interpretations");
+                    ptr.down(i_idx)
+                        .down("diagnosis")
+                        .down("genomicInterpretations")
+                        .down(g_idx)
+                        .down("subjectOrBiosampleId");
+                    references.push(ptr);
+                }
+            }
+        }
+
+        for (idx, biosample) in pp.biosamples.iter().enumerate() {
+            if !biosample.individual_id.is_empty() {
+                let mut ptr = Pointer::new("biosamples");
+                ptr.down(idx).down("individualId");
+                references.push(ptr);
+            }
+        }
+
+        if references.is_empty() {
+            return vec![];
+        }
+
+        vec![LintViolation::new(
+            ViolationSeverity::Error,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_rest(Pointer::at_root(), references),
+        )]
+    }
+}
+
+#[register_report(id = "SUBJ001")]
+struct MissingSubjectReport;
+
+impl ReportFromContext for MissingSubjectReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(MissingSubjectReport))
+    }
+}
+
+impl CompileReport for MissingSubjectReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let mut id_ptr = Pointer::at_root();
+        id_ptr.down("id");
+
+        let mut labels = vec![LabelSpecs::new(
+            LabelPriority::Primary,
+            full_node.span_at(&id_ptr).unwrap().clone(),
+            "Phenopacket has no subject".to_string(),
+        )];
+
+        for reference_ptr in lint_violation.at().iter().skip(1) {
+            if let Some(span) = full_node.span_at(reference_ptr) {
+                labels.push(LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    span.clone(),
+                    "Subject is referenced here".to_string(),
+                ));
+            }
+        }
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            "Phenopacket references a subject, but has no subject section".to_string(),
+            labels,
+            vec![],
+        )
+    }
+}
@@ -0,0 +1,6 @@
+pub mod alternate_ids_rule;
+pub mod cause_of_death_disease_rule;
+pub mod last_encounter_before_birth_rule;
+pub mod missing_subject_rule;
+pub mod redundant_sex_feature_rule;
+pub mod sex_karyotype_rule;
@@ -0,0 +1,144 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::patches::enums::PatchInstruction;
+use crate::patches::patch::Patch;
+use crate::patches::patch_registration::PatchRegistration;
+use crate::patches::traits::RulePatch;
+use crate::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch};
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::RuleReport;
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::{List, Single};
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::{LocatableNode, Node};
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{PhenotypicFeature, Sex};
+
+/// Terms that only state the sex of an individual.
+const SEX_TERMS: [(&str, Sex); 4] = [
+    ("PATO:0000383", Sex::Female),
+    ("PATO:0000384", Sex::Male),
+    ("NCIT:C16576", Sex::Female),
+    ("NCIT:C20197", Sex::Male),
+];
+
+/// ### SUBJ003
+/// ## What it does
+/// Flags observed phenotypic features that only restate `subject.sex`, e.g. `female` for a female subject.
+///
+/// ## Why is this bad?
+/// The sex of an individual is not a phenotype and already has its own field.
+/// Duplicating it as a feature adds noise and can drift out of sync with the subject.
+#[derive(Debug, Default)]
+#[register_rule(id = "SUBJ003")]
+pub struct RedundantSexFeatureRule;
+
+impl RuleFromContext for RedundantSexFeatureRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(RedundantSexFeatureRule))
+    }
+}
+
+impl RuleCheck for RedundantSexFeatureRule {
+    type Data<'a> = (Single<'a, Phenopacket>, List<'a, PhenotypicFeature>);
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(subject) = data.0.0.and_then(|node| node.inner.subject.as_ref()) else {
+            return vec![];
+        };
+        let sex = subject.sex();
+        if sex == Sex::UnknownSex {
+            return vec![];
+        }
+
+        data.1
+            .iter()
+            .filter(|node| {
+                !node.inner.excluded
+                    && node.inner.r#type.as_ref().is_some_and(|feature_type| {
+                        SEX_TERMS.iter().any(|(term_id, term_sex)| {
+                            *term_id == feature_type.id && *term_sex == sex
+                        })
+                    })
+            })
+            .map(|node| {
+                let mut sex_ptr = Pointer::at_subject();
+                sex_ptr.down("sex");
+
+                LintViolation::new(
+                    ViolationSeverity::Warning,
+                    LintRule::rule_id(self),
+                    NonEmptyVec::with_rest(node.pointer().clone(), vec![sex_ptr]),
+                )
+            })
+            .collect()
+    }
+}
+
+#[register_report(id = "SUBJ003")]
+struct RedundantSexFeatureReport;
+
+impl ReportFromContext for RedundantSexFeatureReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(RedundantSexFeatureReport))
+    }
+}
+
+impl CompileReport for RedundantSexFeatureReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let feature_ptr = lint_violation.first_at();
+        let sex_ptr = &lint_violation.at()[1];
+        let mut label_ptr = feature_ptr.clone();
+        label_ptr.down("type").down("label");
+
+        let label = full_node
+            .value_at(&label_ptr)
+            .and_then(|label| label.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Phenotypic feature '{label}' restates the sex of the subject"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(feature_ptr).unwrap().clone(),
+                    "Redundant feature".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(sex_ptr).unwrap().clone(),
+                    "Sex is already stated here".to_string(),
+                ),
+            ],
+            vec!["The sex of an individual belongs into `subject.sex` only.".to_string()],
+        )
+    }
+}
+
+#[register_patch(id = "SUBJ003")]
+struct RedundantSexFeaturePatch;
+
+impl PatchFromContext for RedundantSexFeaturePatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(RedundantSexFeaturePatch))
+    }
+}
+
+impl CompilePatches for RedundantSexFeaturePatch {
+    fn compile_patches(&self, _: &dyn Node, lint_violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Remove {
+                at: lint_violation.first_at().clone(),
+            },
+        ))]
+    }
+}
@@ -0,0 +1,132 @@
+use crate::diagnostics::LintViolation;
+use crate::error::FromContextError;
+use crate::helper::non_empty_vec::NonEmptyVec;
+use crate::linter_context::LinterContext;
+use crate::report::enums::{LabelPriority, ViolationSeverity};
+use crate::report::report_registration::ReportRegistration;
+use crate::report::specs::{LabelSpecs, ReportSpecs};
+use crate::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use crate::rules::rule_registration::RuleRegistration;
+use crate::rules::traits::RuleMetaData;
+use crate::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use crate::tree::node_repository::Single;
+use crate::tree::pointer::Pointer;
+use crate::tree::traits::Node;
+use phenolint_macros::{register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{KaryotypicSex, Sex};
+
+/// Whether the karyotype contains a Y chromosome.
+/// `None` for karyotypes, that do not tell anything about the sex of the subject.
+fn has_y_chromosome(karyotypic_sex: KaryotypicSex) -> Option<bool> {
+    match karyotypic_sex {
+        KaryotypicSex::Xy
+        | KaryotypicSex::Xxy
+        | KaryotypicSex::Xxyy
+        | KaryotypicSex::Xxxy
+        | KaryotypicSex::Xyy => Some(true),
+        KaryotypicSex::Xx | KaryotypicSex::Xo | KaryotypicSex::Xxx | KaryotypicSex::Xxxx => {
+            Some(false)
+        }
+        KaryotypicSex::UnknownKaryotype | KaryotypicSex::OtherKaryotype => None,
+    }
+}
+
+/// ### SUBJ005
+/// ## What it does
+/// Flags subjects whose `sex` contradicts their `karyotypicSex`, e.g. `FEMALE` with `XY`
+/// or `MALE` with `XX`.
+///
+/// ## Why is this bad?
+/// Usually one of both fields was curated incorrectly.
+/// Since there are rare conditions, where phenotypic and karyotypic sex differ,
+/// the contradiction has to be resolved by a curator and no fix is offered.
+#[derive(Debug, Default)]
+#[register_rule(id = "SUBJ005")]
+pub struct SexKaryotypeRule;
+
+impl RuleFromContext for SexKaryotypeRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(SexKaryotypeRule))
+    }
+}
+
+impl RuleCheck for SexKaryotypeRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        let Some(subject) = data.0.and_then(|node| node.inner.subject.as_ref()) else {
+            return vec![];
+        };
+        let Some(has_y_chromosome) = has_y_chromosome(subject.karyotypic_sex()) else {
+            return vec![];
+        };
+
+        let contradicts = match subject.sex() {
+            Sex::Female => has_y_chromosome,
+            Sex::Male => !has_y_chromosome,
+            Sex::UnknownSex | Sex::OtherSex => false,
+        };
+        if !contradicts {
+            return vec![];
+        }
+
+        let mut sex_ptr = Pointer::at_subject();
+        sex_ptr.down("sex");
+        let mut karyotype_ptr = Pointer::at_subject();
+        karyotype_ptr.down("karyotypicSex");
+
+        vec![LintViolation::new(
+            ViolationSeverity::Warning,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_rest(sex_ptr, vec![karyotype_ptr]),
+        )]
+    }
+}
+
+#[register_report(id = "SUBJ005")]
+struct SexKaryotypeReport;
+
+impl ReportFromContext for SexKaryotypeReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(SexKaryotypeReport))
+    }
+}
+
+impl CompileReport for SexKaryotypeReport {
+    fn compile_report(&self, full_node: &dyn Node, lint_violation: &LintViolation) -> ReportSpecs {
+        let sex_ptr = lint_violation.first_at();
+        let karyotype_ptr = &lint_violation.at()[1];
+
+        let value_str = |ptr: &Pointer| {
+            full_node
+                .value_at(ptr)
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_default()
+        };
+        let sex = value_str(sex_ptr);
+        let karyotype = value_str(karyotype_ptr);
+
+        ReportSpecs::from_violation(
+            lint_violation,
+            format!("Sex '{sex}' of the subject contradicts its karyotypic sex '{karyotype}'"),
+            vec![
+                LabelSpecs::new(
+                    LabelPriority::Primary,
+                    full_node.span_at(sex_ptr).unwrap().clone(),
+                    "Sex stated here".to_string(),
+                ),
+                LabelSpecs::new(
+                    LabelPriority::Secondary,
+                    full_node.span_at(karyotype_ptr).unwrap().clone(),
+                    "Contradicting karyotype".to_string(),
+                ),
+            ],
+            vec![
+                "Check whether one of both fields was curated incorrectly. If the phenotypic sex \
+                 really differs from the karyotype, the finding can be ignored."
+                    .to_string(),
+            ],
+        )
+    }
+}
@@ -1,12 +1,20 @@
 use crate::LinterContext;
 use crate::diagnostics::LintViolation;
 use crate::error::FromContextError;
+use crate::rules::enums::RuleScope;
 use crate::tree::node_repository::NodeRepository;
+use std::any::TypeId;
 
 pub trait LintRule: RuleFromContext + Send + Sync {
     fn rule_id(&self) -> &str;
 
     fn check_erased(&self, board: &NodeRepository) -> Vec<LintViolation>;
+
+    /// The types of the nodes this rule consumes.
+    fn node_types(&self) -> Vec<TypeId>;
+
+    /// See `RuleCheck::scope`.
+    fn scope(&self) -> RuleScope;
 }
 
 pub trait RuleMetaData: Send + Sync {
@@ -22,6 +30,11 @@ pub trait RuleFromContext {
 pub trait RuleCheck: Send + Sync + 'static {
     type Data<'a>: LintData<'a> + ?Sized;
     fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation>;
+
+    /// Whether the rule lints phenopackets or cohorts. Phenopackets by default.
+    fn scope(&self) -> RuleScope {
+        RuleScope::Phenopacket
+    }
 }
 
 impl<T> LintRule for T
@@ -38,10 +51,22 @@ where
 
         self.check(data)
     }
+
+    fn node_types(&self) -> Vec<TypeId> {
+        <<Self as RuleCheck>::Data<'static> as LintData<'static>>::node_types()
+    }
+
+    fn scope(&self) -> RuleScope {
+        <Self as RuleCheck>::scope(self)
+    }
 }
 
 pub trait LintData<'a> {
     fn fetch(board: &'a NodeRepository) -> Self
     where
         Self: Sized;
+
+    fn node_types() -> Vec<TypeId>
+    where
+        Self: Sized;
 }
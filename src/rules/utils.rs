@@ -1,10 +1,11 @@
+use once_cell::sync::Lazy;
 use ontolius::ontology::HierarchyQueries;
 use ontolius::ontology::csr::FullCsrOntology;
 use ontolius::term::MinimalTerm;
 use ontolius::term::simple::SimpleTerm;
 use ontolius::{Identified, TermId};
-use phenopackets::schema::v2::Phenopacket;
 use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use regex::Regex;
 use std::collections::HashSet;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -118,14 +119,35 @@ pub(crate) fn find_descendents(
         .collect()
 }
 
+/// Checks whether a CURIE belongs to the HPO namespace.
+///
+/// The hierarchy rules can only validate HPO terms. Terms of other namespaces, e.g. `MONDO`,
+/// have no loaded ontology to be validated against.
+pub(crate) fn is_hpo_curie(curie: &str) -> bool {
+    curie
+        .split_once(':')
+        .is_some_and(|(prefix, _)| prefix == "HP")
+}
+
+/// Message for terms whose namespace has no ontology the hierarchy rules could validate against.
+pub(crate) fn no_ontology_message(curie: &str) -> String {
+    let prefix = curie.split_once(':').map_or(curie, |(prefix, _)| prefix);
+    format!(
+        "Term '{curie}' can not be validated. There is no ontology to validate against for namespace '{prefix}'"
+    )
+}
+
+/// Splits the types of phenotypic features into the observed and the excluded ones.
+///
+/// Features without a type, or with a type that is not a valid CURIE, are skipped.
 #[allow(dead_code)]
-pub(crate) fn partition_phenotypic_features(
-    phenopacket: &Phenopacket,
+pub(crate) fn partition_phenotypic_features<'a>(
+    phenotypic_features: impl IntoIterator<Item = &'a PhenotypicFeature>,
 ) -> (HashSet<TermId>, HashSet<TermId>) {
     let mut observed = HashSet::new();
     let mut excluded = HashSet::new();
 
-    for pf in &phenopacket.phenotypic_features {
+    for pf in phenotypic_features {
         let Some(feature_type) = &pf.r#type else {
             continue;
         };
@@ -143,13 +165,92 @@ pub(crate) fn partition_phenotypic_features(
 
     (observed, excluded)
 }
-#[allow(dead_code)]
 pub(crate) fn term_to_ontology_class(term: &SimpleTerm) -> OntologyClass {
     OntologyClass {
         id: term.identifier().to_string(),
         label: term.name().to_string(),
     }
 }
+
+static ISO8601_DURATION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^P(?:(\d+(?:\.\d+)?)Y)?(?:(\d+(?:\.\d+)?)M)?(?:(\d+(?:\.\d+)?)W)?(?:(\d+(?:\.\d+)?)D)?(?:T(?:(\d+(?:\.\d+)?)H)?(?:(\d+(?:\.\d+)?)M)?(?:(\d+(?:\.\d+)?)S)?)?$",
+    )
+    .expect("Invalid regex")
+});
+
+/// Parses an ISO8601 duration, e.g. `P10Y` or `P2M15D`, into an approximate number of days.
+///
+/// Years and months are converted with their average length, which is precise enough to order ages.
+/// Returns `None` if the string is not a valid duration.
+pub(crate) fn parse_iso8601_duration(duration: &str) -> Option<f64> {
+    if duration == "P" || duration.ends_with('T') {
+        return None;
+    }
+
+    let captures = ISO8601_DURATION.captures(duration)?;
+    let days_per_unit = [
+        365.25,
+        30.44,
+        7.0,
+        1.0,
+        1.0 / 24.0,
+        1.0 / 1440.0,
+        1.0 / 86400.0,
+    ];
+
+    Some(
+        days_per_unit
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, days)| {
+                let amount: f64 = captures.get(idx + 1)?.as_str().parse().ok()?;
+                Some(amount * days)
+            })
+            .sum(),
+    )
+}
+
+static RFC3339_TIMESTAMP: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^(\d{4})-(\d{2})-(\d{2})[Tt](\d{2}):(\d{2}):(\d{2})(?:\.\d+)?(?:[Zz]|[+-](\d{2}):(\d{2}))$",
+    )
+    .expect("Invalid regex")
+});
+
+/// Checks whether a string is an RFC 3339 timestamp, e.g. `2021-05-14T10:35:00Z`.
+///
+/// Besides the format, the ranges of all fields are checked, so e.g. `2021-02-30T10:35:00Z` is rejected.
+pub(crate) fn is_rfc3339_timestamp(timestamp: &str) -> bool {
+    let Some(captures) = RFC3339_TIMESTAMP.captures(timestamp) else {
+        return false;
+    };
+    let field = |idx: usize| -> u32 {
+        captures
+            .get(idx)
+            .and_then(|field| field.as_str().parse().ok())
+            .unwrap_or_default()
+    };
+
+    let (year, month, day) = (field(1), field(2), field(3));
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    (1..=days_in_month).contains(&day)
+        && field(4) < 24
+        && field(5) < 60
+        // A leap second may add a 61st second.
+        && field(6) <= 60
+        && field(7) < 24
+        && field(8) < 60
+}
+
 //TODO
 // Duplicates Same level  | Action
 // Pure duplicates -> Remove
@@ -173,6 +274,15 @@ mod tests {
         ]
     }
 
+    #[rstest]
+    #[case("HP:0001250", true)]
+    #[case("MONDO:0000252", false)]
+    #[case("HPO:0001250", false)]
+    #[case("HP0001250", false)]
+    fn test_is_hpo_curie(#[case] curie: &str, #[case] expected: bool) {
+        assert_eq!(is_hpo_curie(curie), expected);
+    }
+
     #[rstest]
     fn test_find_ancestors(term_ancestry: Vec<TermId>) {
         let ancestors = find_ancestors(
@@ -195,4 +305,31 @@ mod tests {
 
         assert!(ancestors.contains(&TermId::from_str("HP:0003907").unwrap()));
     }
+
+    #[rstest]
+    #[case("P10Y", Some(3652.5))]
+    #[case("P1Y6M", Some(365.25 + 6.0 * 30.44))]
+    #[case("P2W", Some(14.0))]
+    #[case("P3D", Some(3.0))]
+    #[case("P", None)]
+    #[case("P1YT", None)]
+    #[case("30years", None)]
+    fn test_parse_iso8601_duration(#[case] duration: &str, #[case] expected: Option<f64>) {
+        assert_eq!(parse_iso8601_duration(duration), expected);
+    }
+
+    #[rstest]
+    #[case("2021-05-14T10:35:00Z", true)]
+    #[case("2021-05-14T10:35:00.123456Z", true)]
+    #[case("2021-05-14T10:35:00+02:00", true)]
+    #[case("2024-02-29T00:00:00Z", true)]
+    #[case("2023-02-29T00:00:00Z", false)]
+    #[case("2021-13-14T10:35:00Z", false)]
+    #[case("2021-05-14T24:35:00Z", false)]
+    #[case("2021-05-14", false)]
+    #[case("14.05.2021 10:35", false)]
+    #[case("", false)]
+    fn test_is_rfc3339_timestamp(#[case] timestamp: &str, #[case] expected: bool) {
+        assert_eq!(is_rfc3339_timestamp(timestamp), expected);
+    }
 }
@@ -1,18 +1,51 @@
+use crate::parsing::stringified_booleans::parse_stringified_booleans;
 use jsonschema::{Registry, Resource, ValidationError, Validator};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::error::Error;
-
+use std::sync::{Arc, OnceLock};
+
+/// The compiled phenopacket schema, compiled on the first validator that gets constructed.
+static COMPILED_PHENOPACKET_SCHEMA: OnceLock<Validator> = OnceLock::new();
+/// The compiled cohort schema, compiled on the first cohort that gets validated.
+static COMPILED_COHORT_SCHEMA: OnceLock<Validator> = OnceLock::new();
+static SHARED_VALIDATOR: OnceLock<Arc<PhenopacketSchemaValidator>> = OnceLock::new();
+
+/// Validates phenopackets and cohorts against the phenopacket JSON schemas.
+///
+/// The schemas are compiled once per process, so constructing further validators is cheap.
+#[derive(Clone)]
 pub struct PhenopacketSchemaValidator {
-    schema: Validator,
+    schema: &'static Validator,
 }
 
 impl PhenopacketSchemaValidator {
+    /// Returns a validator shared by the whole process.
+    pub fn shared() -> Arc<Self> {
+        SHARED_VALIDATOR
+            .get_or_init(|| Arc::new(Self::default()))
+            .clone()
+    }
+
+    /// Validates a phenopacket against the phenopacket schema.
+    ///
+    /// Stringified booleans, e.g. `"excluded": "true"`, are validated as booleans,
+    /// see `parse_stringified_booleans`.
     pub fn validate_phenopacket<'i>(
         &self,
         phenopacket: &'i Value,
     ) -> Result<(), Box<ValidationError<'i>>> {
-        self.schema.validate(phenopacket).map_err(Box::new)
+        self.schema
+            .validate(&parse_stringified_booleans(phenopacket))
+            .map_err(|err| Box::new(err.to_owned()))
+    }
+
+    /// Validates a cohort, including its members, against the cohort schema.
+    pub fn validate_cohort<'i>(&self, cohort: &'i Value) -> Result<(), Box<ValidationError<'i>>> {
+        COMPILED_COHORT_SCHEMA
+            .get_or_init(|| Self::compile_schema(include_str!("schema/cohort-schema.json")))
+            .validate(cohort)
+            .map_err(Box::new)
     }
 
     fn process_and_export_schemas() -> Result<HashMap<String, Resource>, Box<dyn Error>> {
@@ -102,8 +135,10 @@ impl PhenopacketSchemaValidator {
         }
     }
 
-    fn build_main_schema(registry: Registry) -> Result<Validator, Box<dyn Error>> {
-        let main_schema = include_str!("schema/phenopacket-schema.json");
+    fn build_main_schema(
+        registry: Registry,
+        main_schema: &str,
+    ) -> Result<Validator, Box<dyn Error>> {
         let cleaned = Self::normalize_schema_refs(main_schema);
         let mut value: Value = serde_json::from_str(&cleaned)?;
 
@@ -114,16 +149,21 @@ impl PhenopacketSchemaValidator {
             .build(&value)
             .map_err(Into::into)
     }
-}
 
-impl Default for PhenopacketSchemaValidator {
-    fn default() -> Self {
+    fn compile_schema(main_schema: &str) -> Validator {
         let resources = Self::process_and_export_schemas().expect("Failed to process schemas");
 
         let registry =
             Registry::try_from_resources(resources).expect("Failed to create schema registry");
 
-        let schema = Self::build_main_schema(registry).expect("Failed to build main schema");
+        Self::build_main_schema(registry, main_schema).expect("Failed to build main schema")
+    }
+}
+
+impl Default for PhenopacketSchemaValidator {
+    fn default() -> Self {
+        let schema = COMPILED_PHENOPACKET_SCHEMA
+            .get_or_init(|| Self::compile_schema(include_str!("schema/phenopacket-schema.json")));
 
         Self { schema }
     }
@@ -230,6 +270,22 @@ mod tests {
         assert!(err_msg.contains("YES") || err_msg.contains("is not"));
     }
 
+    #[rstest]
+    fn test_validators_share_compiled_schema() {
+        let first = PhenopacketSchemaValidator::default();
+        let second = PhenopacketSchemaValidator::default();
+
+        assert!(std::ptr::eq(first.schema, second.schema));
+        assert!(std::ptr::eq(
+            first.schema,
+            PhenopacketSchemaValidator::shared().schema
+        ));
+        assert!(Arc::ptr_eq(
+            &PhenopacketSchemaValidator::shared(),
+            &PhenopacketSchemaValidator::shared()
+        ));
+    }
+
     #[rstest]
     fn test_validator_thread_safety() {
         let validator = std::sync::Arc::new(PhenopacketSchemaValidator::default());
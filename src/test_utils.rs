@@ -18,6 +18,9 @@ pub(crate) fn json_phenopacket_dir() -> PathBuf {
 pub(crate) static HPO: Lazy<Arc<FullCsrOntology>> =
     Lazy::new(|| init_ontolius(assets_dir().join("hp.toy.json")));
 
+pub(crate) static GENO: Lazy<Arc<FullCsrOntology>> =
+    Lazy::new(|| init_ontolius(assets_dir().join("geno.toy.json")));
+
 fn init_ontolius(hpo_path: PathBuf) -> Arc<FullCsrOntology> {
     let loader = OntologyLoaderBuilder::new().obographs_parser().build();
 
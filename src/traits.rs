@@ -1,5 +1,19 @@
 use crate::error::LintResult;
+use std::time::Duration;
 
 pub trait Lint<T: ?Sized> {
+    /// Lints `phenodata` and applies the patches of its findings, if `patch` is set.
+    ///
+    /// Reports are always compiled and kept on the findings, see `LintFinding::report`.
+    /// `quit` only controls whether they are rendered to stderr.
     fn lint(&mut self, phenodata: &T, patch: bool, quit: bool) -> LintResult;
 }
+
+/// Observes the rules of a lint run, e.g. to log progress or time the rules of a batch job.
+///
+/// Set it with `Phenolint::set_observer`. Without an observer, the rules are not timed at all.
+pub trait RuleObserver: Send + Sync {
+    fn on_rule_start(&self, rule_id: &str);
+
+    fn on_rule_end(&self, rule_id: &str, n_violations: usize, duration: Duration);
+}
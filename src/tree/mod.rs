@@ -2,5 +2,7 @@ pub(crate) mod abstract_pheno_tree;
 pub mod node;
 pub mod node_repository;
 pub mod pointer;
+pub mod query;
 pub mod traits;
 pub(crate) mod utils;
+pub mod vrs_allele;
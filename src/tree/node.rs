@@ -1,4 +1,5 @@
 use crate::tree::pointer::Pointer;
+use crate::tree::query::query;
 use crate::tree::traits::{LocatableNode, RetrievableNode};
 use serde::Serialize;
 use serde_json::Value;
@@ -20,6 +21,16 @@ impl DynamicNode {
             pointer,
         }
     }
+
+    /// Returns the absolute pointers of all values below this node matching `path_expr`.
+    ///
+    /// See [`query`] for the supported expressions.
+    pub fn query(&self, path_expr: &str) -> Vec<Pointer> {
+        query(&self.inner, path_expr)
+            .iter()
+            .map(|relative| self.pointer.join(relative))
+            .collect()
+    }
 }
 
 impl RetrievableNode for DynamicNode {
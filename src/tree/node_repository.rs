@@ -2,20 +2,27 @@ use crate::rules::traits::LintData;
 use crate::tree::node::MaterializedNode;
 use crate::tree::pointer::Pointer;
 use crate::tree::traits::LocatableNode;
+use phenopackets::schema::v2::core::OntologyClass;
 
 use std::any::{Any, TypeId};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 
+/// Stores the materialized nodes of a phenopacket by their type.
+///
+/// Nodes of a type are kept in pointer order, see the `Ord` implementation of `Pointer`.
+/// `List` fetches and `Single` fetches therefore do not depend on the order nodes were inserted in.
 #[derive(Default)]
 pub struct NodeRepository {
     board: HashMap<TypeId, Box<dyn Any>>,
+    types_at: HashMap<Pointer, Vec<TypeId>>,
 }
 
 impl NodeRepository {
     pub fn new() -> NodeRepository {
         NodeRepository {
             board: HashMap::new(),
+            types_at: HashMap::new(),
         }
     }
 
@@ -28,27 +35,59 @@ impl NodeRepository {
     }
 
     pub fn insert<T: 'static>(&mut self, node: MaterializedNode<T>) {
-        self.board
+        self.types_at
+            .entry(node.pointer().clone())
+            .or_default()
+            .push(TypeId::of::<T>());
+        let nodes = self
+            .board
             .entry(TypeId::of::<T>())
             .or_insert_with(|| Box::new(Vec::<MaterializedNode<T>>::new()))
             .downcast_mut::<Vec<MaterializedNode<T>>>()
-            .unwrap()
-            .push(node);
+            .unwrap();
+        let idx = nodes.partition_point(|other| other.pointer() <= node.pointer());
+        nodes.insert(idx, node);
+    }
+
+    /// Returns the pointers of all ontology classes whose `id` equals the given CURIE.
+    ///
+    /// Useful to locate every usage of a term, e.g. for bulk renames.
+    pub fn find_curie(&self, curie: &str) -> Vec<Pointer> {
+        self.get_raw::<OntologyClass>()
+            .iter()
+            .filter(|node| node.inner.id == curie)
+            .map(|node| node.pointer().clone())
+            .collect()
+    }
+
+    /// Returns the types of all nodes materialized at the given pointer.
+    pub fn node_types_at(&self, ptr: &Pointer) -> &[TypeId] {
+        self.types_at.get(ptr).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns the types of all nodes that contain, or are contained in, one of the `changed` pointers.
+    ///
+    /// These are the nodes whose values differ after changing the phenopacket at these pointers.
+    pub fn node_types_affected_by(&self, changed: &[Pointer]) -> HashSet<TypeId> {
+        self.types_at
+            .iter()
+            .filter(|(ptr, _)| {
+                changed.iter().any(|changed_ptr| {
+                    *ptr == changed_ptr
+                        || ptr.is_ancestor_of(changed_ptr)
+                        || ptr.is_descendant_of(changed_ptr)
+                })
+            })
+            .flat_map(|(_, types)| types.iter().copied())
+            .collect()
     }
 
     pub fn node_by_pointer<T: 'static>(&self, ptr: &Pointer) -> Option<&MaterializedNode<T>> {
-        for nodes in self.board.values() {
-            let casted_node = nodes
-                .downcast_ref::<Vec<MaterializedNode<T>>>()
-                .expect("Should be downcastable");
-
-            for node in casted_node.iter() {
-                if node.pointer() == ptr {
-                    return Some(node);
-                }
-            }
-        }
-        None
+        let nodes = self.get_raw::<T>();
+        nodes
+            .binary_search_by(|node| node.pointer().cmp(ptr))
+            .ok()
+            .map(|idx| &nodes[idx])
     }
 }
 
@@ -58,6 +97,10 @@ impl<'a, T> LintData<'a> for Single<'a, T> {
     fn fetch(board: &'a NodeRepository) -> Self {
         Single(board.get_raw::<T>().first())
     }
+
+    fn node_types() -> Vec<TypeId> {
+        vec![TypeId::of::<T>()]
+    }
 }
 
 pub struct List<'a, T: 'static>(pub &'a [MaterializedNode<T>]);
@@ -74,6 +117,10 @@ impl<'a, T> LintData<'a> for List<'a, T> {
     fn fetch(board: &'a NodeRepository) -> Self {
         List(board.get_raw())
     }
+
+    fn node_types() -> Vec<TypeId> {
+        vec![TypeId::of::<T>()]
+    }
 }
 
 impl<'a, A, B> LintData<'a> for (A, B)
@@ -84,6 +131,10 @@ where
     fn fetch(board: &'a NodeRepository) -> Self {
         (A::fetch(board), B::fetch(board))
     }
+
+    fn node_types() -> Vec<TypeId> {
+        [A::node_types(), B::node_types()].concat()
+    }
 }
 
 impl<'a, A, B, C> LintData<'a> for (A, B, C)
@@ -95,4 +146,119 @@ where
     fn fetch(board: &'a NodeRepository) -> Self {
         (A::fetch(board), B::fetch(board), C::fetch(board))
     }
+
+    fn node_types() -> Vec<TypeId> {
+        [A::node_types(), B::node_types(), C::node_types()].concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use phenopackets::schema::v2::core::{Disease, PhenotypicFeature};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn oc_node(id: &str, ptr: &str) -> MaterializedNode<OntologyClass> {
+        MaterializedNode::new(
+            OntologyClass {
+                id: id.to_string(),
+                label: "some label".to_string(),
+            },
+            HashMap::new(),
+            Pointer::new(ptr),
+        )
+    }
+
+    #[rstest]
+    fn test_node_types_affected_by() {
+        let mut repo = NodeRepository::new();
+        repo.insert(MaterializedNode::new(
+            PhenotypicFeature::default(),
+            HashMap::new(),
+            Pointer::new("/phenotypicFeatures/0"),
+        ));
+        repo.insert(oc_node("HP:0001250", "/phenotypicFeatures/0/type"));
+        repo.insert(MaterializedNode::new(
+            Disease::default(),
+            HashMap::new(),
+            Pointer::new("/diseases/0"),
+        ));
+
+        let below =
+            repo.node_types_affected_by(&[Pointer::new("/phenotypicFeatures/0/type/label")]);
+        let above = repo.node_types_affected_by(&[Pointer::new("/phenotypicFeatures")]);
+
+        let expected = HashSet::from([
+            TypeId::of::<PhenotypicFeature>(),
+            TypeId::of::<OntologyClass>(),
+        ]);
+        assert_eq!(below, expected);
+        assert_eq!(above, expected);
+    }
+
+    #[rstest]
+    fn test_find_curie() {
+        let mut repo = NodeRepository::new();
+        repo.insert(oc_node("MONDO:0000252", "/diseases/0/term"));
+        repo.insert(oc_node("HP:0001250", "/phenotypicFeatures/0/type"));
+        repo.insert(oc_node(
+            "MONDO:0000252",
+            "/interpretations/0/diagnosis/disease",
+        ));
+
+        let found = repo.find_curie("MONDO:0000252");
+
+        assert_eq!(
+            found,
+            vec![
+                Pointer::new("/diseases/0/term"),
+                Pointer::new("/interpretations/0/diagnosis/disease")
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_nodes_in_pointer_order() {
+        let mut repo = NodeRepository::new();
+        repo.insert(oc_node("HP:0000010", "/phenotypicFeatures/10/type"));
+        repo.insert(oc_node("HP:0000002", "/phenotypicFeatures/2/type"));
+        repo.insert(oc_node("MONDO:0000252", "/diseases/0/term"));
+
+        let List(nodes) = List::<OntologyClass>::fetch(&repo);
+
+        assert_eq!(
+            nodes.iter().map(|node| node.pointer()).collect::<Vec<_>>(),
+            vec![
+                &Pointer::new("/diseases/0/term"),
+                &Pointer::new("/phenotypicFeatures/2/type"),
+                &Pointer::new("/phenotypicFeatures/10/type"),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_node_by_pointer() {
+        let mut repo = NodeRepository::new();
+        repo.insert(oc_node("HP:0000010", "/phenotypicFeatures/10/type"));
+        repo.insert(oc_node("HP:0000002", "/phenotypicFeatures/2/type"));
+
+        let node = repo
+            .node_by_pointer::<OntologyClass>(&Pointer::new("/phenotypicFeatures/2/type"))
+            .unwrap();
+
+        assert_eq!(node.inner.id, "HP:0000002");
+        assert!(
+            repo.node_by_pointer::<OntologyClass>(&Pointer::new("/phenotypicFeatures/3/type"))
+                .is_none()
+        );
+    }
+
+    #[rstest]
+    fn test_find_curie_not_present() {
+        let mut repo = NodeRepository::new();
+        repo.insert(oc_node("HP:0001250", "/phenotypicFeatures/0/type"));
+
+        assert!(repo.find_curie("HP:0000001").is_empty());
+    }
 }
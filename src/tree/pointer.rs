@@ -1,6 +1,12 @@
-use crate::tree::utils::{escape, unescape};
+use crate::error::PointerError;
+use crate::tree::utils::{escape, is_escaped, unescape};
+use serde::{Serialize, Serializer};
+use std::cmp::Ordering;
 use std::fmt::Display;
 
+/// The RFC 6901 token, which references the position after the last element of an array.
+pub const APPEND_TOKEN: &str = "-";
+
 /// A struct representing a JSON Pointer (RFC 6901).
 ///
 /// This internally stores the pointer as an escaped string (e.g., "/a/~1b").
@@ -20,6 +26,18 @@ impl Pointer {
         Self(location)
     }
 
+    /// Builds a pointer from decoded segments, escaping each of them.
+    ///
+    /// Unlike `Pointer::new`, a `/` inside a segment is part of the key,
+    /// e.g. `["a/b", "c"]` becomes `"/a~1b/c"`.
+    pub fn from_segments<S: AsRef<str>>(segments: impl IntoIterator<Item = S>) -> Self {
+        let mut ptr = Pointer::at_root();
+        for segment in segments {
+            ptr.down(segment.as_ref());
+        }
+        ptr
+    }
+
     pub fn at_root() -> Self {
         Self(String::new())
     }
@@ -100,8 +118,7 @@ impl Pointer {
     /// assert_eq!(ptr.position(), "/user/name");
     /// ```
     pub fn down<S: ToString>(&mut self, step: S) -> &mut Self {
-        let step = step.to_string();
-        let step = escape(&step);
+        let step = escape_segment(&step.to_string());
         self.0 = format!("{}/{}", self.0, step);
 
         self
@@ -135,9 +152,174 @@ impl Pointer {
         self.0.is_empty()
     }
 
+    /// Returns a new pointer to the end of the array at `self`, e.g. `"/phenotypicFeatures/-"`.
+    ///
+    /// Adding a value at this pointer appends it to the array.
+    pub fn end_of_array(&self) -> Pointer {
+        let mut ptr = self.clone();
+        ptr.down(APPEND_TOKEN);
+        ptr
+    }
+
+    /// Checks if the pointer ends in the `-` token, see `Pointer::end_of_array`.
+    pub fn is_append(&self) -> bool {
+        self.0
+            .rsplit_once('/')
+            .is_some_and(|(_, tip)| tip == APPEND_TOKEN)
+    }
+
     pub fn segments(&self) -> impl Iterator<Item = String> + '_ {
         self.0.split('/').skip(1).map(unescape)
     }
+
+    /// Returns the number of segments of the pointer. The root has a depth of `0`.
+    pub fn depth(&self) -> usize {
+        self.0.split('/').skip(1).count()
+    }
+
+    /// Returns the pointer one level up, without modifying `self`.
+    ///
+    /// # Returns
+    /// `None`, if the pointer is at the root.
+    pub fn parent(&self) -> Option<Pointer> {
+        if self.is_root() {
+            return None;
+        }
+
+        let mut parent = self.clone();
+        parent.up();
+        Some(parent)
+    }
+
+    /// Returns a new pointer with the segments of `other` appended to the segments of `self`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let ptr = Pointer::new("/phenotypicFeatures/0");
+    /// let joined = ptr.join(&Pointer::new("/type/id"));
+    /// assert_eq!(joined.position(), "/phenotypicFeatures/0/type/id");
+    /// ```
+    pub fn join(&self, other: &Pointer) -> Pointer {
+        let mut joined = self.clone();
+        for segment in other.segments() {
+            joined.down(segment);
+        }
+        joined
+    }
+
+    /// Returns the part of the pointer below `anchor`, e.g. `"/type/id"` for
+    /// `"/phenotypicFeatures/0/type/id"` relative to `"/phenotypicFeatures/0"`.
+    ///
+    /// # Returns
+    /// `None`, if the pointer is not located at or below `anchor`.
+    pub fn relative_to(&self, anchor: &Pointer) -> Option<Pointer> {
+        if anchor.is_root() {
+            return Some(self.clone());
+        }
+
+        let suffix = self.0.strip_prefix(anchor.0.as_str())?;
+        if suffix.is_empty() || suffix.starts_with('/') {
+            Some(Self(suffix.to_string()))
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether `other` is located strictly below this pointer.
+    ///
+    /// The pointers are compared segment by segment, so `"/disease"` is not an ancestor of
+    /// `"/diseases/0"`. A pointer is not its own ancestor.
+    pub fn is_ancestor_of(&self, other: &Pointer) -> bool {
+        self.depth() < other.depth()
+            && self
+                .0
+                .split('/')
+                .skip(1)
+                .zip(other.0.split('/').skip(1))
+                .all(|(a, b)| a == b)
+    }
+
+    /// Checks whether this pointer is located strictly below `other`, see `Pointer::is_ancestor_of`.
+    pub fn is_descendant_of(&self, other: &Pointer) -> bool {
+        other.is_ancestor_of(self)
+    }
+
+    /// Returns the longest path both pointers share, compared segment by segment.
+    ///
+    /// For example, the common ancestor of `"/phenotypicFeatures/0/type"` and
+    /// `"/phenotypicFeatures/1/type"` is `"/phenotypicFeatures"`.
+    /// Pointers without a shared segment have the root as their common ancestor.
+    pub fn common_ancestor(&self, other: &Pointer) -> Pointer {
+        let shared: Vec<&str> = self
+            .0
+            .split('/')
+            .skip(1)
+            .zip(other.0.split('/').skip(1))
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect();
+
+        if shared.is_empty() {
+            Pointer::at_root()
+        } else {
+            Self(format!("/{}", shared.join("/")))
+        }
+    }
+}
+
+/// Escapes an unescaped segment, `~` becomes `~0` and `/` becomes `~1`.
+fn escape_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// Parses an RFC 6901 string, which is already escaped, e.g. `"/a~1b/c"`.
+impl TryFrom<&str> for Pointer {
+    type Error = PointerError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if !value.is_empty() && !value.starts_with('/') {
+            return Err(PointerError::MissingLeadingSlash(value.to_string()));
+        }
+        if !is_escaped(value) {
+            return Err(PointerError::InvalidEscape(value.to_string()));
+        }
+
+        Ok(Self(value.to_string()))
+    }
+}
+
+/// Orders pointers by their position in the document, comparing segment by segment.
+///
+/// Array indices are compared numerically, so `"/phenotypicFeatures/2"` comes before
+/// `"/phenotypicFeatures/10"`. A pointer comes before all pointers below it.
+impl Ord for Pointer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for (seg1, seg2) in self.0.split('/').skip(1).zip(other.0.split('/').skip(1)) {
+            let ordering = match (seg1.parse::<usize>(), seg2.parse::<usize>()) {
+                (Ok(idx1), Ok(idx2)) => idx1.cmp(&idx2).then_with(|| seg1.cmp(seg2)),
+                _ => seg1.cmp(seg2),
+            };
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        self.depth().cmp(&other.depth())
+    }
+}
+
+impl PartialOrd for Pointer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Serializes as the RFC 6901 string, e.g. `"/phenotypicFeatures/0/type"`.
+impl Serialize for Pointer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.position())
+    }
 }
 
 impl Display for Pointer {
@@ -335,6 +517,242 @@ mod tests {
         assert_eq!(segments, vec!["foo", "a~b", "c/d"]);
     }
 
+    #[rstest]
+    fn test_from_segments() {
+        let ptr = Pointer::from_segments(["a/b", "c"]);
+
+        assert_eq!(ptr.position(), "/a~1b/c");
+        assert_eq!(ptr.segments().collect::<Vec<_>>(), vec!["a/b", "c"]);
+    }
+
+    #[rstest]
+    fn test_from_segments_escapes_tilde() {
+        let segments: &[&str] = &["a~b", "0"];
+        let ptr = Pointer::from_segments(segments);
+
+        assert_eq!(ptr.position(), "/a~0b/0");
+        assert_eq!(ptr.segments().collect::<Vec<_>>(), vec!["a~b", "0"]);
+    }
+
+    #[rstest]
+    #[case("", "")]
+    #[case("/foo/bar", "/foo/bar")]
+    #[case("/a~1b/c", "/a~1b/c")]
+    #[case("/a~0b", "/a~0b")]
+    fn test_try_from(#[case] location: &str, #[case] expected: &str) {
+        let ptr = Pointer::try_from(location).unwrap();
+
+        assert_eq!(ptr.position(), expected);
+        assert_eq!(
+            ptr,
+            Pointer::from_segments(ptr.segments().collect::<Vec<_>>())
+        );
+    }
+
+    #[rstest]
+    #[case("foo/bar", PointerError::MissingLeadingSlash("foo/bar".to_string()))]
+    #[case("/a~b", PointerError::InvalidEscape("/a~b".to_string()))]
+    #[case("/a~2", PointerError::InvalidEscape("/a~2".to_string()))]
+    fn test_try_from_invalid(#[case] location: &str, #[case] expected: PointerError) {
+        assert_eq!(Pointer::try_from(location), Err(expected));
+    }
+
+    #[rstest]
+    fn test_end_of_array() {
+        let ptr = Pointer::at_phenotypes().end_of_array();
+
+        assert_eq!(ptr.position(), "/phenotypicFeatures/-");
+        assert_eq!(ptr, Pointer::new("/phenotypicFeatures/-"));
+        assert!(ptr.is_append());
+        assert_eq!(ptr.parent().unwrap(), Pointer::at_phenotypes());
+    }
+
+    #[rstest]
+    #[case("/phenotypicFeatures/-", true)]
+    #[case("/-", true)]
+    #[case("/phenotypicFeatures/0", false)]
+    #[case("/phenotypicFeatures/-1", false)]
+    #[case("/phenotypicFeatures/-/type", false)]
+    #[case("", false)]
+    fn test_is_append(#[case] location: &str, #[case] expected: bool) {
+        assert_eq!(Pointer::new(location).is_append(), expected);
+    }
+
+    #[rstest]
+    #[case("", 0)]
+    #[case("/foo", 1)]
+    #[case("/foo/bar/baz", 3)]
+    #[case("/foo/a~1b", 2)]
+    fn test_depth(#[case] location: &str, #[case] depth: usize) {
+        assert_eq!(Pointer::new(location).depth(), depth);
+    }
+
+    #[rstest]
+    fn test_parent_root() {
+        assert_eq!(Pointer::at_root().parent(), None);
+    }
+
+    #[rstest]
+    fn test_parent_single_segment() {
+        let ptr = Pointer::new("/foo");
+        let parent = ptr.parent().unwrap();
+
+        assert!(parent.is_root());
+        assert_eq!(ptr.position(), "/foo");
+    }
+
+    #[rstest]
+    fn test_parent_escaped_segment() {
+        let mut ptr = Pointer::new("/foo");
+        ptr.down("a/b").down("c~d");
+
+        assert_eq!(ptr.parent().unwrap().position(), "/foo/a~1b");
+    }
+
+    #[rstest]
+    fn test_join_root() {
+        let ptr = Pointer::new("/foo/bar");
+
+        assert_eq!(ptr.join(&Pointer::at_root()), ptr);
+        assert_eq!(Pointer::at_root().join(&ptr), ptr);
+    }
+
+    #[rstest]
+    fn test_join_single_segment() {
+        let ptr = Pointer::new("/phenotypicFeatures/0");
+
+        assert_eq!(
+            ptr.join(&Pointer::new("/type")).position(),
+            "/phenotypicFeatures/0/type"
+        );
+    }
+
+    #[rstest]
+    fn test_join_escaped_segments() {
+        let mut other = Pointer::at_root();
+        other.down("a/b").down("c~d");
+
+        let joined = Pointer::new("/foo").join(&other);
+
+        assert_eq!(joined.position(), "/foo/a~1b/c~0d");
+        assert_eq!(
+            joined.segments().collect::<Vec<_>>(),
+            vec!["foo", "a/b", "c~d"]
+        );
+    }
+
+    #[rstest]
+    fn test_relative_to_root_anchor() {
+        let ptr = Pointer::new("/phenotypicFeatures/0/type");
+
+        assert_eq!(ptr.relative_to(&Pointer::at_root()), Some(ptr.clone()));
+    }
+
+    #[rstest]
+    fn test_relative_to_ancestor() {
+        let ptr = Pointer::new("/phenotypicFeatures/0/type/id");
+        let anchor = Pointer::new("/phenotypicFeatures/0");
+
+        assert_eq!(ptr.relative_to(&anchor).unwrap().position(), "/type/id");
+        assert!(ptr.relative_to(&ptr).unwrap().is_root());
+    }
+
+    #[rstest]
+    fn test_relative_to_deeper_anchor() {
+        let ptr = Pointer::new("/phenotypicFeatures/0");
+        let anchor = Pointer::new("/phenotypicFeatures/0/type");
+
+        assert_eq!(ptr.relative_to(&anchor), None);
+    }
+
+    #[rstest]
+    fn test_relative_to_partial_segment() {
+        let ptr = Pointer::new("/phenotypicFeatures/10/type");
+        let anchor = Pointer::new("/phenotypicFeatures/1");
+
+        assert_eq!(ptr.relative_to(&anchor), None);
+    }
+
+    #[rstest]
+    #[case("", "/foo", true)]
+    #[case("/foo", "/foo/bar", true)]
+    #[case("/foo", "/foo/bar/baz", true)]
+    #[case("/foo", "/foo", false)]
+    #[case("/foo/bar", "/foo", false)]
+    #[case("", "", false)]
+    #[case("/subject", "/metaData/created", false)]
+    fn test_is_ancestor_of(#[case] ancestor: &str, #[case] other: &str, #[case] expected: bool) {
+        let ancestor = Pointer::new(ancestor);
+        let other = Pointer::new(other);
+
+        assert_eq!(ancestor.is_ancestor_of(&other), expected);
+        assert_eq!(other.is_descendant_of(&ancestor), expected);
+    }
+
+    #[rstest]
+    fn test_is_ancestor_of_partial_segment() {
+        let disease = Pointer::new("/disease");
+        let diseases = Pointer::new("/diseases/0");
+
+        assert!(!disease.is_ancestor_of(&diseases));
+        assert!(!diseases.is_descendant_of(&disease));
+    }
+
+    #[rstest]
+    fn test_is_ancestor_of_escaped_segment() {
+        let mut escaped = Pointer::new("/foo");
+        escaped.down("a/b");
+
+        assert!(!Pointer::new("/foo/a").is_ancestor_of(&escaped));
+        assert!(Pointer::new("/foo").is_ancestor_of(&escaped));
+    }
+
+    #[rstest]
+    fn test_common_ancestor_siblings() {
+        let ptr1 = Pointer::new("/phenotypicFeatures/0/type");
+        let ptr2 = Pointer::new("/phenotypicFeatures/1/type");
+        assert_eq!(
+            ptr1.common_ancestor(&ptr2).position(),
+            "/phenotypicFeatures"
+        );
+    }
+
+    #[rstest]
+    fn test_common_ancestor_nested() {
+        let ptr1 = Pointer::new("/a/b/c");
+        let ptr2 = Pointer::new("/a/b");
+        assert_eq!(ptr1.common_ancestor(&ptr2).position(), "/a/b");
+        assert_eq!(ptr2.common_ancestor(&ptr1).position(), "/a/b");
+    }
+
+    #[rstest]
+    fn test_common_ancestor_disjoint() {
+        let ptr1 = Pointer::new("/subject/id");
+        let ptr2 = Pointer::new("/metaData/created");
+        assert!(ptr1.common_ancestor(&ptr2).is_root());
+    }
+
+    #[rstest]
+    fn test_common_ancestor_partial_segment() {
+        let ptr1 = Pointer::new("/foo/bar");
+        let ptr2 = Pointer::new("/foo/barbaz");
+        assert_eq!(ptr1.common_ancestor(&ptr2).position(), "/foo");
+    }
+
+    #[rstest]
+    #[case("/phenotypicFeatures/2", "/phenotypicFeatures/10")]
+    #[case("/phenotypicFeatures", "/phenotypicFeatures/0")]
+    #[case("", "/id")]
+    #[case("/diseases/0/term", "/phenotypicFeatures/0/type")]
+    #[case("/phenotypicFeatures/1", "/phenotypicFeatures/01")]
+    fn test_ordering(#[case] smaller: &str, #[case] larger: &str) {
+        let (smaller, larger) = (Pointer::new(smaller), Pointer::new(larger));
+
+        assert!(smaller < larger);
+        assert!(larger > smaller);
+        assert_eq!(smaller.cmp(&smaller.clone()), Ordering::Equal);
+    }
+
     #[rstest]
     fn test_display_trait() {
         let ptr = Pointer::new("/user/name");
@@ -412,4 +830,15 @@ mod tests {
         assert!(ptr.position().contains("~0"));
         assert!(ptr.position().contains("~1"));
     }
+
+    #[rstest]
+    fn test_serialize_as_string() {
+        let mut ptr = Pointer::new("/metaData");
+        ptr.down("a~b").down(0);
+
+        assert_eq!(
+            serde_json::to_value(&ptr).unwrap(),
+            serde_json::json!("/metaData/a~0b/0")
+        );
+    }
 }
@@ -0,0 +1,146 @@
+use crate::tree::pointer::Pointer;
+use crate::tree::utils::unescape;
+use serde_json::Value;
+
+/// Returns the pointers of all values in `value` that match `path_expr`, in pointer order.
+///
+/// The expression is a JSON pointer with a few additions:
+/// - `/key` matches the child `key`, `/0` the first element of an array.
+/// - `//key` matches `key` at any depth below the current values.
+/// - `*` matches every child of an object or array.
+///
+/// For example, `/phenotypicFeatures//id` matches all `id` fields below the phenotypic features,
+/// and `/phenotypicFeatures/*/type` the type of every feature.
+pub fn query(value: &Value, path_expr: &str) -> Vec<Pointer> {
+    let mut current: Vec<(Pointer, &Value)> = vec![(Pointer::at_root(), value)];
+    let mut descendant = false;
+
+    for segment in path_expr.strip_prefix('/').unwrap_or(path_expr).split('/') {
+        if segment.is_empty() {
+            descendant = true;
+            continue;
+        }
+
+        let segment = unescape(segment);
+        let mut next = vec![];
+        for (ptr, value) in current.iter() {
+            collect_matches(ptr, value, &segment, descendant, &mut next);
+        }
+        next.sort_by(|(ptr1, _), (ptr2, _)| ptr1.cmp(ptr2));
+        next.dedup_by(|(ptr1, _), (ptr2, _)| ptr1 == ptr2);
+
+        current = next;
+        descendant = false;
+    }
+
+    current.into_iter().map(|(ptr, _)| ptr).collect()
+}
+
+/// Collects the children of `value` matching `segment`. With `descendant`, all values below
+/// `value` are searched.
+fn collect_matches<'v>(
+    ptr: &Pointer,
+    value: &'v Value,
+    segment: &str,
+    descendant: bool,
+    matches: &mut Vec<(Pointer, &'v Value)>,
+) {
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, child)| (key.clone(), child))
+            .collect(),
+        Value::Array(list) => list
+            .iter()
+            .enumerate()
+            .map(|(idx, child)| (idx.to_string(), child))
+            .collect(),
+        _ => vec![],
+    };
+
+    for (key, child) in children {
+        let mut child_ptr = ptr.clone();
+        child_ptr.down(&key);
+
+        if segment == "*" || segment == key {
+            matches.push((child_ptr.clone(), child));
+        }
+        if descendant {
+            collect_matches(&child_ptr, child, segment, descendant, matches);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+
+    fn phenopacket() -> Value {
+        json!({
+            "id": "phenopacket.1",
+            "subject": {"id": "patient.1"},
+            "phenotypicFeatures": [
+                {"type": {"id": "HP:0001250", "label": "Seizure"}},
+                {
+                    "type": {"id": "HP:0001263", "label": "Global developmental delay"},
+                    "modifiers": [{"id": "HP:0012828", "label": "Severe"}]
+                }
+            ]
+        })
+    }
+
+    fn pointers(locations: &[&str]) -> Vec<Pointer> {
+        locations
+            .iter()
+            .map(|location| Pointer::new(location))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_query_descendants() {
+        assert_eq!(
+            query(&phenopacket(), "/phenotypicFeatures//id"),
+            pointers(&[
+                "/phenotypicFeatures/0/type/id",
+                "/phenotypicFeatures/1/modifiers/0/id",
+                "/phenotypicFeatures/1/type/id",
+            ])
+        );
+    }
+
+    #[rstest]
+    fn test_query_descendants_from_root() {
+        assert_eq!(query(&phenopacket(), "//id").len(), 5);
+    }
+
+    #[rstest]
+    fn test_query_child() {
+        assert_eq!(
+            query(&phenopacket(), "/phenotypicFeatures/1/modifiers/0/label"),
+            pointers(&["/phenotypicFeatures/1/modifiers/0/label"])
+        );
+    }
+
+    #[rstest]
+    fn test_query_wildcard() {
+        assert_eq!(
+            query(&phenopacket(), "/phenotypicFeatures/*/type"),
+            pointers(&["/phenotypicFeatures/0/type", "/phenotypicFeatures/1/type"])
+        );
+    }
+
+    #[rstest]
+    #[case("/phenotypicFeatures/2")]
+    #[case("/diseases//id")]
+    #[case("/id/label")]
+    fn test_query_no_match(#[case] path_expr: &str) {
+        assert!(query(&phenopacket(), path_expr).is_empty());
+    }
+
+    #[rstest]
+    fn test_query_root() {
+        assert_eq!(query(&phenopacket(), ""), vec![Pointer::at_root()]);
+    }
+}
@@ -0,0 +1,10 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// A VRS allele of a genomic interpretation, at
+/// `variantInterpretation/variationDescriptor/variation/allele`.
+///
+/// The VRS structs of the phenopackets crate do not match the schema, so the allele is kept as raw JSON.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct VrsAllele(pub Value);
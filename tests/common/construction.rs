@@ -1,11 +1,12 @@
-use crate::common::paths::{assets_dir, hpo_dir};
+use crate::common::paths::{assets_dir, geno_dir, hpo_dir};
 use phenolint::LinterContext;
 use phenolint::phenolint::Phenolint;
 use phenopackets::schema::v2::Phenopacket;
 use phenopackets::schema::v2::core::MetaData;
 
 pub fn build_linter(rules: Vec<&str>) -> Phenolint {
-    let context = LinterContext::new(Some(hpo_dir(assets_dir())));
+    let context = LinterContext::new(Some(hpo_dir(assets_dir())))
+        .with_geno_path(Some(geno_dir(assets_dir())));
     let rules: Vec<String> = rules.into_iter().map(|s| s.to_string()).collect();
     Phenolint::new(context, rules)
 }
@@ -10,7 +10,12 @@ pub fn assets_dir() -> PathBuf {
 
 #[fixture]
 pub fn hpo_dir(assets_dir: PathBuf) -> PathBuf {
-    assets_dir.join("hpo.toy.json")
+    assets_dir.join("hp.toy.json")
+}
+
+#[fixture]
+pub fn geno_dir(assets_dir: PathBuf) -> PathBuf {
+    assets_dir.join("geno.toy.json")
 }
 
 #[fixture]
@@ -1,10 +1,15 @@
+#![allow(dead_code)]
+
 use crate::common::asserts::{LintResultAssertSettings, assert_lint_result};
 use crate::common::construction::build_linter;
 use gag::BufferRedirect;
 use phenolint::enums::InputTypes;
+use phenolint::error::LintResult;
+use phenolint::phenolint::Phenolint;
 use phenolint::traits::Lint;
 use phenopackets::schema::v2::Phenopacket;
 use prost::Message;
+use serde_json::Value;
 use std::env;
 use std::io::Read;
 
@@ -16,12 +21,7 @@ pub fn run_rule_test(
     let formats: Vec<InputTypes> = vec![InputTypes::Json, InputTypes::Yaml, InputTypes::Protobuf];
 
     for format in formats {
-        let mut stdout_buf = BufferRedirect::stdout().unwrap();
-        let mut stderr_buf = BufferRedirect::stderr().unwrap();
-
-        let mut linter = build_linter(vec![rule_id]);
-
-        let res = match format {
+        run_captured(rule_id, &format, &assert_settings, |linter| match format {
             InputTypes::Json => linter.lint(
                 serde_json::to_string_pretty(&input).unwrap().as_str(),
                 true,
@@ -35,27 +35,61 @@ pub fn run_rule_test(
                 input.encode(&mut buffer).unwrap();
                 linter.lint(buffer.as_slice(), true, false)
             }
-        };
-
-        let mut stdout_output = String::new();
-        let mut stderr_output = String::new();
-        stdout_buf.read_to_string(&mut stdout_output).unwrap();
-        stderr_buf.read_to_string(&mut stderr_output).unwrap();
-        drop(stdout_buf);
-        drop(stderr_buf);
-
-        let output = if !stderr_output.is_empty() {
-            stderr_output
-        } else {
-            stdout_output
-        };
-
-        if env::var("CI").is_err() {
-            eprintln!("Testing {format}");
-            eprintln!("----");
-            eprintln!("{}", output);
-        }
-
-        assert_lint_result(res, &assert_settings, output, format);
+        });
+    }
+}
+
+/// Runs a rule on a phenopacket given as plain JSON, for content the phenopacket structs can not represent.
+/// Only the text formats are tested, because the value is never converted into a `Phenopacket`.
+pub fn run_raw_rule_test(rule_id: &str, input: &Value, assert_settings: LintResultAssertSettings) {
+    let formats: Vec<InputTypes> = vec![InputTypes::Json, InputTypes::Yaml];
+
+    for format in formats {
+        run_captured(rule_id, &format, &assert_settings, |linter| match format {
+            InputTypes::Yaml => {
+                linter.lint(serde_yaml::to_string(&input).unwrap().as_str(), true, false)
+            }
+            _ => linter.lint(
+                serde_json::to_string_pretty(&input).unwrap().as_str(),
+                true,
+                false,
+            ),
+        });
+    }
+}
+
+fn run_captured<F>(
+    rule_id: &str,
+    format: &InputTypes,
+    assert_settings: &LintResultAssertSettings,
+    lint: F,
+) where
+    F: FnOnce(&mut Phenolint) -> LintResult,
+{
+    let mut stdout_buf = BufferRedirect::stdout().unwrap();
+    let mut stderr_buf = BufferRedirect::stderr().unwrap();
+
+    let mut linter = build_linter(vec![rule_id]);
+    let res = lint(&mut linter);
+
+    let mut stdout_output = String::new();
+    let mut stderr_output = String::new();
+    stdout_buf.read_to_string(&mut stdout_output).unwrap();
+    stderr_buf.read_to_string(&mut stderr_output).unwrap();
+    drop(stdout_buf);
+    drop(stderr_buf);
+
+    let output = if !stderr_output.is_empty() {
+        stderr_output
+    } else {
+        stdout_output
+    };
+
+    if env::var("CI").is_err() {
+        eprintln!("Testing {format}");
+        eprintln!("----");
+        eprintln!("{}", output);
     }
+
+    assert_lint_result(res, assert_settings, output, *format);
 }
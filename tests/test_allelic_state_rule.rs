@@ -0,0 +1,87 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::paths::{assets_dir, hpo_dir};
+use crate::common::test_functions::run_raw_rule_test;
+use phenolint::LinterContext;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use rstest::rstest;
+use serde_json::{Value, json};
+use serial_test::serial;
+
+/// The VRS structs of the phenopackets crate do not match the schema,
+/// so the variation descriptor is added to the plain JSON of the phenopacket.
+fn phenopacket_with_allelic_state(id: &str, label: &str) -> Value {
+    let mut pp = serde_json::to_value(minimal_valid_phenopacket()).unwrap();
+
+    pp["interpretations"] = json!([{
+        "id": "interpretation_1",
+        "progressStatus": "SOLVED",
+        "diagnosis": {
+            "disease": {"id": "MONDO:0000252", "label": "inflammatory diarrhea"},
+            "genomicInterpretations": [{
+                "subjectOrBiosampleId": "cohort-1-patient-1",
+                "interpretationStatus": "CAUSATIVE",
+                "variantInterpretation": {
+                    "acmgPathogenicityClassification": "PATHOGENIC",
+                    "therapeuticActionability": "UNKNOWN_ACTIONABILITY",
+                    "variationDescriptor": {
+                        "id": "variant_1",
+                        "moleculeContext": "genomic",
+                        "allelicState": {"id": id, "label": label}
+                    }
+                }
+            }]
+        }
+    }]);
+
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_allelic_state_rule_invalid_state() {
+    let pp = phenopacket_with_allelic_state("GENO:0000536", "variant allele");
+
+    let rule_id = "VAR001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["variant allele", "GENO:0000133"],
+    };
+
+    run_raw_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_allelic_state_rule_valid_state() {
+    let pp = phenopacket_with_allelic_state("GENO:0000135", "heterozygous");
+
+    let rule_id = "VAR001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_raw_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_allelic_state_rule_skipped_without_geno() {
+    let pp = phenopacket_with_allelic_state("GENO:0000536", "variant allele");
+    let context = LinterContext::new(Some(hpo_dir(assets_dir())));
+    let mut linter = Phenolint::new(context, vec!["VAR001".to_string()]);
+
+    let res = linter.lint(pp.to_string().as_str(), false, true);
+
+    assert!(res.error.is_none());
+    assert!(res.report().findings().is_empty());
+}
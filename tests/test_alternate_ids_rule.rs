@@ -0,0 +1,79 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Remove;
+use phenolint::patches::patch::Patch;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Individual;
+use rstest::rstest;
+use serial_test::serial;
+
+fn with_alternate_ids(alternate_ids: &[&str]) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        alternate_ids: alternate_ids.iter().map(|id| id.to_string()).collect(),
+        ..Default::default()
+    });
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_alternate_ids_rule_empty() {
+    let pp = with_alternate_ids(&["PMID:1234-1", "", "PMID:1234-2"]);
+    let patched = with_alternate_ids(&["PMID:1234-1", "PMID:1234-2"]);
+
+    let rule_id = "SUBJ002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&patched).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Remove {
+            at: Pointer::new("/subject/alternateIds/1"),
+        }))],
+        message_snippets: vec!["empty alternate ID"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_alternate_ids_rule_duplicate() {
+    let pp = with_alternate_ids(&["PMID:1234-1", "PMID:1234-2", "PMID:1234-1 "]);
+
+    let rule_id = "SUBJ002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["PMID:1234-1", "more than once"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_alternate_ids_rule_clean() {
+    let pp = with_alternate_ids(&["PMID:1234-1", "PMID:1234-2"]);
+
+    let rule_id = "SUBJ002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
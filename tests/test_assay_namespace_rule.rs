@@ -0,0 +1,105 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::LinterContext;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Measurement;
+use rstest::rstest;
+use serde_json::json;
+use serial_test::serial;
+
+fn with_assay(id: &str, label: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+
+    let measurement: Measurement = serde_json::from_value(json!({
+        "assay": {"id": id, "label": label},
+        "value": {
+            "quantity": {
+                "unit": {"id": "UCUM:mg/dL", "label": "milligram per deciliter"},
+                "value": 180.0
+            }
+        }
+    }))
+    .unwrap();
+
+    pp.measurements.push(measurement);
+    pp
+}
+
+#[rstest]
+#[case(with_assay("HP:0003074", "Hyperglycemia"), 1, vec!["HP:0003074", "Unexpected namespace"])]
+#[case(with_assay("LOINC:2345-7", "Glucose [Mass/volume] in Serum or Plasma"), 0, vec![])]
+#[serial]
+fn test_assay_namespace_rule(
+    #[case] pp: Phenopacket,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let rule_id = "MEAS003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_assay_namespace_rule_configured_namespaces() {
+    let pp = with_assay("LOINC:2345-7", "Glucose [Mass/volume] in Serum or Plasma");
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let context = LinterContext::new(None).with_assay_namespaces(vec!["NCIT".to_string()]);
+    let mut linter = Phenolint::new(context, vec!["MEAS003".to_string()]);
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.error.is_none());
+    assert_eq!(res.report.violations().len(), 1);
+    assert_eq!(
+        res.report.violations()[0].first_at().position(),
+        "/measurements/0/assay"
+    );
+}
+
+/// Measurements with an incomplete assay must still reach all measurement rules.
+#[rstest]
+#[serial]
+fn test_assay_namespace_rule_next_to_missing_assay() {
+    let mut pp = serde_json::to_value(with_assay("HP:0003074", "Hyperglycemia")).unwrap();
+    let mut without_assay = pp["measurements"][0].clone();
+    without_assay.as_object_mut().unwrap().remove("assay");
+    pp["measurements"]
+        .as_array_mut()
+        .unwrap()
+        .insert(0, without_assay);
+
+    let mut linter = Phenolint::builder()
+        .with_rules(["MEAS002", "MEAS003"])
+        .with_schema_validation(false)
+        .build();
+    let res = linter.lint(pp.to_string().as_str(), false, true);
+
+    assert!(res.error.is_none());
+    let mut flagged: Vec<(&str, &str)> = res
+        .report
+        .violations()
+        .iter()
+        .map(|violation| (violation.rule_id(), violation.first_at().position()))
+        .collect();
+    flagged.sort();
+    assert_eq!(
+        flagged,
+        vec![
+            ("MEAS002", "/measurements/0"),
+            ("MEAS003", "/measurements/1/assay")
+        ]
+    );
+}
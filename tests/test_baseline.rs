@@ -0,0 +1,67 @@
+mod common;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::paths::{assets_dir, hpo_dir};
+use phenolint::LinterContext;
+use phenolint::diagnostics::Baseline;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+
+fn phenopacket_with_invalid_curies(n_features: usize) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = (0..n_features)
+        .map(|i| PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: format!("invalid:{i}"),
+                label: "some pf".to_string(),
+            }),
+            ..Default::default()
+        })
+        .collect();
+    pp
+}
+
+fn lint_with_baseline(pp: &Phenopacket, baseline: Baseline) -> phenolint::error::LintResult {
+    let context = LinterContext::new(Some(hpo_dir(assets_dir()))).with_baseline(baseline);
+    let mut linter = Phenolint::new(context, vec!["CURIE001".to_string()]);
+    let phenostr = serde_json::to_string_pretty(pp).unwrap();
+
+    linter.lint(phenostr.as_str(), false, true)
+}
+
+#[rstest]
+fn test_baseline() {
+    let pp = phenopacket_with_invalid_curies(3);
+    let res = lint_with_baseline(&pp, Baseline::default());
+    assert_eq!(res.report().findings().len(), 3);
+    let baseline = Baseline::from_report(res.report());
+
+    let res = lint_with_baseline(&pp, baseline.clone());
+    assert!(res.error.is_none());
+    assert!(res.report().findings().is_empty());
+    assert_eq!(res.report().baselined().len(), 3);
+
+    let pp = phenopacket_with_invalid_curies(4);
+    let res = lint_with_baseline(&pp, baseline.clone());
+    let findings = res.report().findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].violation().first_at().position(),
+        "/phenotypicFeatures/3/type/id"
+    );
+    assert_eq!(res.report().baselined().len(), 3);
+}
+
+#[rstest]
+fn test_new_findings_vs() {
+    let pp = phenopacket_with_invalid_curies(2);
+    let old_baseline = Baseline::from_report(lint_with_baseline(&pp, Baseline::default()).report());
+
+    let pp = phenopacket_with_invalid_curies(5);
+    let res = lint_with_baseline(&pp, Baseline::default());
+
+    assert_eq!(res.report().findings().len(), 5);
+    assert_eq!(res.report().new_findings_vs(&old_baseline).len(), 3);
+}
@@ -0,0 +1,95 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Remove;
+use phenolint::patches::patch::Patch;
+use phenolint::traits::Lint;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Resource;
+use rstest::rstest;
+use serial_test::serial;
+
+fn resource(namespace_prefix: &str) -> Resource {
+    Resource {
+        id: namespace_prefix.to_lowercase(),
+        name: format!("{namespace_prefix} ontology"),
+        url: format!(
+            "http://purl.obolibrary.org/obo/{}.owl",
+            namespace_prefix.to_lowercase()
+        ),
+        version: "2024-04-26".to_string(),
+        namespace_prefix: namespace_prefix.to_string(),
+        iri_prefix: format!("http://purl.obolibrary.org/obo/{namespace_prefix}_"),
+    }
+}
+
+fn with_resources(resources: Vec<Resource>) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.meta_data.as_mut().unwrap().resources = resources;
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_case_duplicate_resource_rule() {
+    let pp = with_resources(vec![resource("hp"), resource("HP")]);
+    let patched = with_resources(vec![resource("HP")]);
+
+    let rule_id = "RES002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&patched).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Remove {
+            at: Pointer::new("/metaData/resources/0"),
+        }))],
+        message_snippets: vec!["'hp'", "'HP'", "up to case"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_case_duplicate_resource_rule_distinct() {
+    let pp = with_resources(vec![resource("HP"), resource("MONDO")]);
+
+    let rule_id = "RES002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_case_duplicate_resource_rule_three_variants() {
+    let mut canonical = resource("HP");
+    canonical.version = String::default();
+    let pp = with_resources(vec![resource("hp"), resource("Hp"), canonical]);
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["RES002"]);
+    let res = linter.lint(phenostr.as_str(), true, true);
+
+    assert!(res.error.is_none(), "Unexpected error: {:?}", res.error);
+    assert_eq!(res.report().violations().len(), 2);
+    let Some(PhenopacketData::Text(patched)) = &res.report().patched_phenopacket else {
+        panic!("Expected a patched phenopacket");
+    };
+    assert_eq!(
+        serde_json::from_str::<Phenopacket>(patched).unwrap(),
+        with_resources(vec![resource("HP")])
+    );
+}
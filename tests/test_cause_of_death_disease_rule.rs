@@ -0,0 +1,69 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::vital_status::Status;
+use phenopackets::schema::v2::core::{Disease, Individual, OntologyClass, VitalStatus};
+use rstest::rstest;
+use serial_test::serial;
+
+fn term(id: &str, label: &str) -> OntologyClass {
+    OntologyClass {
+        id: id.to_string(),
+        label: label.to_string(),
+    }
+}
+
+fn deceased_with_disease(cause_of_death: OntologyClass, disease: OntologyClass) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        vital_status: Some(VitalStatus {
+            status: Status::Deceased.into(),
+            cause_of_death: Some(cause_of_death),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    pp.diseases = vec![Disease {
+        term: Some(disease),
+        ..Default::default()
+    }];
+    pp
+}
+
+#[rstest]
+#[case(
+    deceased_with_disease(
+        term("MONDO:0007254", "breast cancer"),
+        term("MONDO:0005027", "epilepsy"),
+    ),
+    1,
+    vec!["'MONDO:0007254' is not listed among the diseases", "Cause of death"],
+)]
+#[case(
+    deceased_with_disease(
+        term("MONDO:0007254", "breast cancer"),
+        term("MONDO:0007254", "breast cancer"),
+    ),
+    0,
+    vec![],
+)]
+#[serial]
+fn test_cause_of_death_disease_rule(
+    #[case] pp: Phenopacket,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let rule_id = "SUBJ004";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
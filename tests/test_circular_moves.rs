@@ -0,0 +1,194 @@
+use phenolint::patches::patch_registration::PatchRegistration;
+use phenolint::report::report_registration::ReportRegistration;
+use phenolint::rules::rule_registration::RuleRegistration;
+use phenolint::rules::traits::RuleMetaData;
+
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::LinterContext;
+use phenolint::diagnostics::LintViolation;
+use phenolint::error::{FromContextError, LinterError};
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction;
+use phenolint::patches::error::PatchingError;
+use phenolint::patches::patch::Patch;
+use phenolint::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch, RulePatch};
+use phenolint::report::enums::{LabelPriority, ViolationSeverity};
+use phenolint::report::specs::{LabelSpecs, ReportSpecs};
+use phenolint::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use phenolint::rules::traits::LintRule;
+use phenolint::rules::traits::{RuleCheck, RuleFromContext};
+use phenolint::traits::Lint;
+use phenolint::tree::node_repository::List;
+use phenolint::tree::pointer::Pointer;
+use phenolint::tree::traits::Node;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::core::{Individual, OntologyClass};
+use rstest::rstest;
+
+mod common;
+
+fn subject_id_ptr() -> Pointer {
+    Pointer::new("/subject/id")
+}
+
+/// ### MOVE001
+/// ## What it does
+/// Moves the phenopacket id into the subject.
+///
+/// ## Why is this bad?
+/// It is not. It only exists to produce a move.
+#[register_rule(id = "MOVE001")]
+struct MoveIdToSubjectRule;
+
+impl RuleFromContext for MoveIdToSubjectRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(MoveIdToSubjectRule))
+    }
+}
+
+impl RuleCheck for MoveIdToSubjectRule {
+    type Data<'a> = List<'a, OntologyClass>;
+
+    fn check(&self, _: Self::Data<'_>) -> Vec<LintViolation> {
+        vec![LintViolation::new(
+            ViolationSeverity::Info,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_single_entry(Pointer::at_root().down("id").clone()),
+        )]
+    }
+}
+
+#[register_patch(id = "MOVE001")]
+struct MoveIdToSubjectPatch;
+
+impl PatchFromContext for MoveIdToSubjectPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(MoveIdToSubjectPatch))
+    }
+}
+
+impl CompilePatches for MoveIdToSubjectPatch {
+    fn compile_patches(&self, _: &dyn Node, violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Move {
+                from: violation.first_at().clone(),
+                to: subject_id_ptr(),
+            },
+        ))]
+    }
+}
+
+#[register_report(id = "MOVE001")]
+struct MoveIdToSubjectReport;
+
+impl ReportFromContext for MoveIdToSubjectReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(MoveIdToSubjectReport))
+    }
+}
+
+impl CompileReport for MoveIdToSubjectReport {
+    fn compile_report(&self, full_node: &dyn Node, violation: &LintViolation) -> ReportSpecs {
+        ReportSpecs::from_violation(
+            violation,
+            "Move id to subject".to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(violation.first_at()).unwrap().clone(),
+                String::default(),
+            )],
+            vec![],
+        )
+    }
+}
+
+/// ### MOVE002
+/// ## What it does
+/// Moves the subject id to the phenopacket id.
+///
+/// ## Why is this bad?
+/// It is not. It only exists to produce a move.
+#[register_rule(id = "MOVE002")]
+struct MoveSubjectToIdRule;
+
+impl RuleFromContext for MoveSubjectToIdRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(MoveSubjectToIdRule))
+    }
+}
+
+impl RuleCheck for MoveSubjectToIdRule {
+    type Data<'a> = List<'a, OntologyClass>;
+
+    fn check(&self, _: Self::Data<'_>) -> Vec<LintViolation> {
+        vec![LintViolation::new(
+            ViolationSeverity::Info,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_single_entry(subject_id_ptr()),
+        )]
+    }
+}
+
+#[register_patch(id = "MOVE002")]
+struct MoveSubjectToIdPatch;
+
+impl PatchFromContext for MoveSubjectToIdPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(MoveSubjectToIdPatch))
+    }
+}
+
+impl CompilePatches for MoveSubjectToIdPatch {
+    fn compile_patches(&self, _: &dyn Node, violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Move {
+                from: violation.first_at().clone(),
+                to: Pointer::at_root().down("id").clone(),
+            },
+        ))]
+    }
+}
+
+#[register_report(id = "MOVE002")]
+struct MoveSubjectToIdReport;
+
+impl ReportFromContext for MoveSubjectToIdReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(MoveSubjectToIdReport))
+    }
+}
+
+impl CompileReport for MoveSubjectToIdReport {
+    fn compile_report(&self, full_node: &dyn Node, violation: &LintViolation) -> ReportSpecs {
+        ReportSpecs::from_violation(
+            violation,
+            "Move subject id to id".to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(violation.first_at()).unwrap().clone(),
+                String::default(),
+            )],
+            vec![],
+        )
+    }
+}
+
+#[rstest]
+fn test_circular_moves_abort_patching() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        ..Default::default()
+    });
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["MOVE001", "MOVE002"]);
+    let res = linter.lint(phenostr.as_str(), true, true);
+
+    assert_eq!(res.report().violations().len(), 2);
+    assert!(res.report().patched_phenopacket.is_none());
+    assert!(matches!(
+        res.error,
+        Some(LinterError::PatchingError(PatchingError::CircularMoves(_)))
+    ));
+}
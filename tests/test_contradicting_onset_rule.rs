@@ -0,0 +1,71 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::time_element::Element;
+use phenopackets::schema::v2::core::{Disease, OntologyClass, PhenotypicFeature, TimeElement};
+use rstest::rstest;
+use serial_test::serial;
+
+fn oc(id: &str, label: &str) -> OntologyClass {
+    OntologyClass {
+        id: id.to_string(),
+        label: label.to_string(),
+    }
+}
+
+fn onset(id: &str, label: &str) -> Option<TimeElement> {
+    Some(TimeElement {
+        element: Some(Element::OntologyClass(oc(id, label))),
+    })
+}
+
+fn with_feature_onset(id: &str, label: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(oc("HP:0001250", "Seizure")),
+        onset: onset(id, label),
+        ..Default::default()
+    }];
+    pp.diseases = vec![Disease {
+        term: Some(oc("MONDO:0005027", "epilepsy")),
+        onset: onset("HP:0003581", "Adult onset"),
+        ..Default::default()
+    }];
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_contradicting_onset_rule_sibling_branch() {
+    let pp = with_feature_onset("HP:0011463", "Childhood onset");
+
+    let rule_id = "PF013";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["Childhood onset", "Adult onset"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_contradicting_onset_rule_descendant() {
+    let pp = with_feature_onset("HP:0003596", "Middle age onset");
+
+    let rule_id = "PF013";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,47 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::traits::Lint;
+use rstest::rstest;
+use serde_json::Value;
+use serial_test::serial;
+
+fn with_created(created: &str) -> String {
+    let mut pp = serde_json::to_value(minimal_valid_phenopacket()).unwrap();
+    pp["metaData"]["created"] = Value::from(created);
+
+    serde_json::to_string_pretty(&pp).unwrap()
+}
+
+#[rstest]
+#[case("", 1, "Creation timestamp is empty")]
+#[case(
+    "14.05.2021 10:35",
+    1,
+    "'14.05.2021 10:35' is not a valid RFC 3339 timestamp"
+)]
+#[case("2021-05-14T10:35:00Z", 0, "")]
+#[case("2021-05-14T10:35:00.5+02:00", 0, "")]
+#[serial]
+fn test_created_timestamp_rule(
+    #[case] created: &str,
+    #[case] n_violations: usize,
+    #[case] message_snippet: &str,
+) {
+    let mut linter = build_linter(vec!["META002"]);
+
+    let res = linter.lint(with_created(created).as_str(), false, true);
+
+    assert!(res.error.is_none());
+    let findings = res.report.findings();
+    assert_eq!(findings.len(), n_violations);
+    for finding in findings {
+        assert_eq!(
+            finding.violation().first_at().position(),
+            "/metaData/created"
+        );
+        let report = finding.report().unwrap();
+        assert!(report.message().contains(message_snippet));
+        assert!(report.notes()[0].contains("RFC 3339"));
+        assert!(finding.patch().is_empty());
+    }
+}
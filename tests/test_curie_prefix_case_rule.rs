@@ -0,0 +1,84 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Replace;
+use phenolint::patches::patch::Patch;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature, Resource};
+use rstest::rstest;
+use serde_json::Value;
+use serial_test::serial;
+
+fn resource(namespace_prefix: &str) -> Resource {
+    Resource {
+        id: namespace_prefix.to_lowercase(),
+        name: format!("{namespace_prefix} ontology"),
+        url: format!(
+            "http://purl.obolibrary.org/obo/{}.owl",
+            namespace_prefix.to_lowercase()
+        ),
+        version: "2024-04-26".to_string(),
+        namespace_prefix: namespace_prefix.to_string(),
+        iri_prefix: format!("http://purl.obolibrary.org/obo/{namespace_prefix}_"),
+    }
+}
+
+fn with_feature(id: &str, namespace_prefix: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.meta_data.as_mut().unwrap().resources = vec![resource(namespace_prefix)];
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: "Seizure".to_string(),
+        }),
+        ..Default::default()
+    }];
+    pp
+}
+
+#[rstest]
+#[case("hp:0001250")]
+#[case("Hp:0001250")]
+#[case("hP:0001250")]
+#[serial]
+fn test_curie_prefix_case_rule(#[case] id: &str) {
+    let pp = with_feature(id, "HP");
+    let patched = with_feature("HP:0001250", "HP");
+
+    let rule_id = "CURIE005";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&patched).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Replace {
+            at: Pointer::new("/phenotypicFeatures/0/type/id"),
+            value: Value::String("HP:0001250".to_string()),
+        }))],
+        message_snippets: vec![id, "'HP'", "casing"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[case(with_feature("HP:0001250", "HP"))]
+#[case(with_feature("hp:0001250", "MONDO"))]
+#[serial]
+fn test_curie_prefix_case_rule_no_violation(#[case] pp: Phenopacket) {
+    let rule_id = "CURIE005";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,63 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Biosample;
+use rstest::rstest;
+use serial_test::serial;
+
+fn biosample(id: &str, derived_from_id: &str) -> Biosample {
+    Biosample {
+        id: id.to_string(),
+        individual_id: "patient-1".to_string(),
+        derived_from_id: derived_from_id.to_string(),
+        ..Default::default()
+    }
+}
+
+fn with_biosamples(biosamples: Vec<Biosample>) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.biosamples = biosamples;
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_derived_from_rule_dangling() {
+    let pp = with_biosamples(vec![
+        biosample("biopsy-1", ""),
+        biosample("cell-line-1", "biopsy-2"),
+    ]);
+
+    let rule_id = "BIO001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["biopsy-2", "No biosample has this id"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_derived_from_rule_valid_lineage() {
+    let pp = with_biosamples(vec![
+        biosample("biopsy-1", ""),
+        biosample("cell-line-1", "biopsy-1"),
+    ]);
+
+    let rule_id = "BIO001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,48 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Diagnosis, Interpretation, OntologyClass};
+use rstest::{fixture, rstest};
+
+#[fixture]
+fn phenopacket() -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.interpretations.push(Interpretation {
+        id: "interpretation_123".to_string(),
+        diagnosis: Some(Diagnosis {
+            disease: Some(OntologyClass {
+                id: "MONDO:0000252".to_string(),
+                label: "inflammatory diarrhea".to_string(),
+            }),
+            genomic_interpretations: vec![],
+        }),
+        ..Default::default()
+    });
+    pp
+}
+
+fn patch_text(phenostr: &str) -> String {
+    let mut linter = build_linter(vec!["INTER001"]);
+    let res = linter.lint(phenostr, true, true);
+
+    match res.report().patched_phenopacket.as_ref() {
+        Some(PhenopacketData::Text(text)) => text.clone(),
+        other => panic!("Expected patched text, got {:?}", other),
+    }
+}
+
+#[rstest]
+fn test_patched_json_is_byte_identical(phenopacket: Phenopacket) {
+    let phenostr = serde_json::to_string_pretty(&phenopacket).unwrap();
+
+    assert_eq!(patch_text(&phenostr), patch_text(&phenostr));
+}
+
+#[rstest]
+fn test_patched_yaml_is_byte_identical(phenopacket: Phenopacket) {
+    let phenostr = serde_yaml::to_string(&phenopacket).unwrap();
+
+    assert_eq!(patch_text(&phenostr), patch_text(&phenostr));
+}
@@ -0,0 +1,96 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn lint_violations(phenostr: &str) -> Vec<String> {
+    let mut linter = build_linter(vec!["CURIE004"]);
+    let res = linter.lint(phenostr, false, true);
+    assert!(res.error.is_none());
+
+    res.report
+        .violations()
+        .iter()
+        .map(|violation| format!("{violation:?}"))
+        .collect()
+}
+
+#[rstest]
+#[serial]
+fn test_violations_in_pointer_order() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = (0..12)
+        .map(|idx| PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: format!("HP:{:06}", idx + 1),
+                label: "Some phenotype".to_string(),
+            }),
+            ..Default::default()
+        })
+        .collect();
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let first_run = lint_violations(&phenostr);
+    let second_run = lint_violations(&phenostr);
+
+    assert_eq!(first_run.len(), 12);
+    assert_eq!(first_run, second_run);
+
+    let mut linter = build_linter(vec!["CURIE004"]);
+    let res = linter.lint(phenostr.as_str(), false, true);
+    let positions: Vec<String> = res
+        .report
+        .violations()
+        .iter()
+        .map(|violation| violation.first_at().position().to_string())
+        .collect();
+    let expected: Vec<String> = (0..12)
+        .map(|idx| format!("/phenotypicFeatures/{idx}/type/id"))
+        .collect();
+    assert_eq!(positions, expected);
+}
+
+#[rstest]
+#[serial]
+fn test_violations_across_rules_in_stable_order() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = [
+        ("invalid", "some pf"),
+        ("Seizure", "HP:0001250"),
+        ("Seizure", "HP:0001250"),
+    ]
+    .into_iter()
+    .map(|(id, label)| PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        ..Default::default()
+    })
+    .collect();
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let expected = vec![
+        ("CURIE001", "/phenotypicFeatures/0/type/id"),
+        ("CURIE001", "/phenotypicFeatures/1/type/id"),
+        ("CURIE003", "/phenotypicFeatures/1/type/id"),
+        ("CURIE001", "/phenotypicFeatures/2/type/id"),
+        ("CURIE003", "/phenotypicFeatures/2/type/id"),
+    ];
+
+    for _ in 0..5 {
+        let mut linter = build_linter(vec!["CURIE003", "CURIE001"]);
+        let res = linter.lint(phenostr.as_str(), false, true);
+        assert!(res.error.is_none());
+
+        let violations: Vec<(&str, &str)> = res
+            .report
+            .violations()
+            .into_iter()
+            .map(|violation| (violation.rule_id(), violation.first_at().position()))
+            .collect();
+        assert_eq!(violations, expected);
+    }
+}
@@ -148,4 +148,33 @@ mod tests {
 
         run_rule_test(rule_id, &pp, assert_settings);
     }
+
+    #[rstest]
+    #[serial]
+    fn test_disease_consistency_rule_diagnosis_without_id() {
+        let mut pp = minimal_valid_phenopacket();
+
+        pp.interpretations.push(Interpretation {
+            id: "interpretation_123".to_string(),
+            diagnosis: Some(Diagnosis {
+                disease: Some(OntologyClass {
+                    id: " ".to_string(),
+                    label: "inflammatory diarrhea".to_string(),
+                }),
+                genomic_interpretations: vec![],
+            }),
+            ..Default::default()
+        });
+
+        let rule_id = "INTER001";
+        let assert_settings = LintResultAssertSettings {
+            rule_id,
+            n_violations: 1,
+            patched_phenopacket: None,
+            patches: vec![],
+            message_snippets: vec!["has no ID"],
+        };
+
+        run_rule_test(rule_id, &pp, assert_settings);
+    }
 }
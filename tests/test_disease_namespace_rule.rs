@@ -0,0 +1,49 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::core::{Diagnosis, Disease, Interpretation, OntologyClass};
+use rstest::rstest;
+use serial_test::serial;
+
+fn oc(id: &str, label: &str) -> OntologyClass {
+    OntologyClass {
+        id: id.to_string(),
+        label: label.to_string(),
+    }
+}
+
+#[rstest]
+#[case(oc("OMIM:154700", "Marfan syndrome"), 1, vec!["OMIM", "MONDO", "same concept"])]
+#[case(oc("MONDO:0007947", "Marfan syndrome"), 0, vec![])]
+#[serial]
+fn test_disease_namespace_rule(
+    #[case] diagnosis_disease: OntologyClass,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.diseases.push(Disease {
+        term: Some(oc("MONDO:0007947", "Marfan syndrome")),
+        ..Default::default()
+    });
+    pp.interpretations.push(Interpretation {
+        id: "interpretation_123".to_string(),
+        diagnosis: Some(Diagnosis {
+            disease: Some(diagnosis_disease),
+            genomic_interpretations: vec![],
+        }),
+        ..Default::default()
+    });
+
+    let rule_id = "INTER005";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,78 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::LinterContext;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Replace;
+use phenolint::patches::patch::Patch;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serde_json::Value;
+use serial_test::serial;
+
+fn with_feature_label(label: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "HP:0001250".to_string(),
+            label: label.to_string(),
+        }),
+        ..Default::default()
+    }];
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_empty_label_rule_with_hpo() {
+    let rule_id = "PF009";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&with_feature_label("Seizure")).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Replace {
+            at: Pointer::new("/phenotypicFeatures/0/type/label"),
+            value: Value::String("Seizure".to_string()),
+        }))],
+        message_snippets: vec!["HP:0001250", "empty label"],
+    };
+
+    run_rule_test(rule_id, &with_feature_label("  "), assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_empty_label_rule_without_hpo() {
+    let phenostr = serde_json::to_string_pretty(&with_feature_label("  ")).unwrap();
+    let mut linter = Phenolint::new(LinterContext::new(None), vec!["PF009".to_string()]);
+
+    let res = linter.lint(phenostr.as_str(), true, true);
+
+    assert!(res.error.is_none());
+    assert_eq!(res.report.violations().len(), 1);
+    assert!(res.report.patches().is_empty());
+    assert!(res.report.patched_phenopacket.is_none());
+}
+
+#[rstest]
+#[serial]
+fn test_empty_label_rule_no_violation() {
+    let rule_id = "PF009";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &with_feature_label("Seizure"), assert_settings);
+}
@@ -0,0 +1,42 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::patches::enums::PatchInstruction;
+use phenolint::traits::Lint;
+use phenolint::tree::pointer::Pointer;
+use rstest::rstest;
+use serde_json::{Value, json};
+
+fn phenopacket_with_file(file_attributes: Value) -> String {
+    let mut pp = serde_json::to_value(minimal_valid_phenopacket()).unwrap();
+    pp["files"] =
+        json!([{"uri": "file://data/genomes/P000001C.vcf.gz", "fileAttributes": file_attributes}]);
+    serde_json::to_string_pretty(&pp).unwrap()
+}
+
+#[rstest]
+fn test_empty_object_rule() {
+    let phenostr = phenopacket_with_file(json!({}));
+
+    let mut linter = build_linter(vec!["STR001"]);
+    let res = linter.lint(phenostr.as_str(), true, true);
+
+    let findings = res.report().findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].patch()[0].instructions(),
+        &[PatchInstruction::Remove {
+            at: Pointer::new("/files/0/fileAttributes"),
+        }]
+    );
+    assert!(res.report().patched_phenopacket.is_some());
+}
+
+#[rstest]
+fn test_empty_object_rule_no_violation() {
+    let phenostr = phenopacket_with_file(json!({"fileFormat": "vcf"}));
+
+    let mut linter = build_linter(vec!["STR001"]);
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.report().findings().is_empty());
+}
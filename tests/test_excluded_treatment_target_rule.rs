@@ -0,0 +1,66 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Disease, MedicalAction, OntologyClass};
+use rstest::rstest;
+use serde_json::json;
+use serial_test::serial;
+
+fn treated_marfan_syndrome(excluded: bool) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.diseases = vec![Disease {
+        term: Some(OntologyClass {
+            id: "MONDO:0007947".to_string(),
+            label: "Marfan syndrome".to_string(),
+        }),
+        excluded,
+        ..Default::default()
+    }];
+
+    let medical_action: MedicalAction = serde_json::from_value(json!({
+        "treatment": {
+            "agent": {"id": "DrugCentral:1610", "label": "losartan"}
+        },
+        "treatmentTarget": {"id": "MONDO:0007947", "label": "Marfan syndrome"}
+    }))
+    .unwrap();
+    pp.medical_actions.push(medical_action);
+
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_excluded_treatment_target_rule() {
+    let pp = treated_marfan_syndrome(true);
+
+    let rule_id = "MA001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["Marfan syndrome", "Disease is excluded here"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_excluded_treatment_target_rule_observed_disease() {
+    let pp = treated_marfan_syndrome(false);
+
+    let rule_id = "MA001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,61 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::core::{Evidence, OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn excluded_seizure(evidence: Vec<Evidence>) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "HP:0001250".to_string(),
+            label: "Seizure".to_string(),
+        }),
+        excluded: true,
+        evidence,
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_excluded_with_evidence_rule() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![excluded_seizure(vec![Evidence {
+        evidence_code: Some(OntologyClass {
+            id: "ECO:0000033".to_string(),
+            label: "author statement supported by traceable reference".to_string(),
+        }),
+        ..Default::default()
+    }])];
+
+    let rule_id = "EVID002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["Seizure", "evidence"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_excluded_with_evidence_rule_without_evidence() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![excluded_seizure(vec![])];
+
+    let rule_id = "EVID002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,100 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Remove;
+use phenolint::patches::patch::Patch;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::time_element::Element;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature, TimeElement};
+use rstest::rstest;
+use serial_test::serial;
+
+fn oc(id: &str, label: &str) -> OntologyClass {
+    OntologyClass {
+        id: id.to_string(),
+        label: label.to_string(),
+    }
+}
+
+fn seizure(excluded: bool) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(oc("HP:0001250", "Seizure")),
+        excluded,
+        ..Default::default()
+    }
+}
+
+fn with_feature(feature: PhenotypicFeature) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![feature];
+    pp
+}
+
+#[rstest]
+#[case(
+    PhenotypicFeature {
+        onset: Some(TimeElement {
+            element: Some(Element::OntologyClass(oc("HP:0003577", "Congenital onset"))),
+        }),
+        ..seizure(true)
+    },
+    "onset"
+)]
+#[case(
+    PhenotypicFeature {
+        severity: Some(oc("HP:0012828", "Severe")),
+        ..seizure(true)
+    },
+    "severity"
+)]
+#[case(
+    PhenotypicFeature {
+        modifiers: vec![oc("HP:0012832", "Bilateral")],
+        ..seizure(true)
+    },
+    "modifiers"
+)]
+#[serial]
+fn test_excluded_with_qualifier_rule(#[case] feature: PhenotypicFeature, #[case] qualifier: &str) {
+    let rule_id = "PF017";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&with_feature(seizure(true))).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Remove {
+            at: Pointer::new(&format!("/phenotypicFeatures/0/{qualifier}")),
+        }))],
+        message_snippets: vec!["Seizure", qualifier],
+    };
+
+    run_rule_test(rule_id, &with_feature(feature), assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_excluded_with_qualifier_rule_observed() {
+    let feature = PhenotypicFeature {
+        onset: Some(TimeElement {
+            element: Some(Element::OntologyClass(oc("HP:0003577", "Congenital onset"))),
+        }),
+        severity: Some(oc("HP:0012828", "Severe")),
+        ..seizure(false)
+    };
+
+    let rule_id = "PF017";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &with_feature(feature), assert_settings);
+}
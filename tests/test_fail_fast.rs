@@ -0,0 +1,59 @@
+mod common;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::paths::{assets_dir, hpo_dir};
+use phenolint::LinterContext;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+
+fn invalid_feature(id: &str) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: "some pf".to_string(),
+        }),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[case(true, 1)]
+#[case(false, 2)]
+fn test_fail_fast(#[case] fail_fast: bool, #[case] expected_findings: usize) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![invalid_feature("invalid:1"), invalid_feature("invalid:2")];
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let context = LinterContext::new(Some(hpo_dir(assets_dir()))).with_fail_fast(fail_fast);
+    let mut linter = Phenolint::new(context, vec!["CURIE001".to_string()]);
+
+    let res = linter.lint(phenostr.as_str(), true, true);
+
+    assert!(res.error.is_none());
+    assert_eq!(res.report().findings().len(), expected_findings);
+}
+
+#[rstest]
+fn test_fail_fast_keeps_first_rule_in_id_order() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![invalid_feature("Seizure")];
+    pp.phenotypic_features[0].r#type.as_mut().unwrap().label = "HP:0001250".to_string();
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    for _ in 0..5 {
+        let context = LinterContext::new(Some(hpo_dir(assets_dir()))).with_fail_fast(true);
+        let mut linter = Phenolint::new(
+            context,
+            vec!["CURIE003".to_string(), "CURIE001".to_string()],
+        );
+
+        let res = linter.lint(phenostr.as_str(), false, true);
+
+        assert!(res.error.is_none());
+        let findings = res.report().findings();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].violation().rule_id(), "CURIE001");
+        assert!(findings[0].report().is_some());
+    }
+}
@@ -0,0 +1,45 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::core::File;
+use rstest::rstest;
+use serial_test::serial;
+use std::collections::HashMap;
+
+fn vcf(attributes: &[(&str, &str)]) -> File {
+    File {
+        uri: "file://data/genomes/P000001C.vcf.gz".to_string(),
+        file_attributes: attributes
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect::<HashMap<String, String>>(),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[case(vcf(&[("genomeAssembly", "GRCh38.p13"), ("fileFormat", "")]), 1, vec!["fileFormat", "empty value"])]
+#[case(vcf(&[("genomeAssembly", "GRCh38.p13"), ("genome_assembly", "GRCh38")]), 1, vec!["genome_assembly", "same attribute"])]
+#[case(vcf(&[("genomeAssembly", "GRCh38.p13"), ("fileFormat", "vcf")]), 0, vec![])]
+#[case(vcf(&[("genomeAssembly", "GRCh38.p13"), ("format/version", "")]), 1, vec!["'format/version'", "empty value"])]
+#[serial]
+fn test_file_attributes_rule(
+    #[case] file: File,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.files = vec![file];
+
+    let rule_id = "FILE001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,114 @@
+mod common;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::paths::{assets_dir, hpo_dir};
+use phenolint::LinterContext;
+use phenolint::diagnostics::{Baseline, LintFinding, LintReport};
+use phenolint::phenolint::{IGNORE_FINDINGS_REFERENCE_ID, Phenolint};
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::ExternalReference;
+use rstest::rstest;
+use serial_test::serial;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+fn lint_file_with(file_name: &str, pp: &Phenopacket, context: LinterContext) -> LintReport {
+    let tmp_dir = TempDir::new().unwrap();
+    let phenopath: PathBuf = tmp_dir.path().join(file_name);
+    std::fs::write(&phenopath, serde_json::to_string_pretty(pp).unwrap()).unwrap();
+
+    let mut linter = Phenolint::new(context, vec![]);
+
+    let res = linter.lint(&phenopath, false, true);
+    assert!(res.error.is_none());
+    res.report
+}
+
+fn count_name001(findings: &[LintFinding]) -> usize {
+    findings
+        .iter()
+        .filter(|finding| finding.violation().rule_id() == "NAME001")
+        .count()
+}
+
+fn brca1_phenopacket() -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.id = "PMID_30808312_BRCA1_proband".to_string();
+    pp
+}
+
+fn context(filename_id_check: bool) -> LinterContext {
+    LinterContext::new(Some(hpo_dir(assets_dir()))).with_filename_id_check(filename_id_check)
+}
+
+fn lint_file(file_name: &str, filename_id_check: bool) -> usize {
+    let report = lint_file_with(file_name, &brca1_phenopacket(), context(filename_id_check));
+    count_name001(report.findings())
+}
+
+#[rstest]
+#[serial]
+fn test_filename_matches_id() {
+    assert_eq!(lint_file("BRCA1_case.json", true), 0);
+}
+
+#[rstest]
+#[serial]
+fn test_filename_mismatches_id() {
+    assert_eq!(lint_file("TP53_case.json", true), 1);
+}
+
+#[rstest]
+#[serial]
+fn test_filename_id_check_is_opt_in() {
+    assert_eq!(lint_file("TP53_case.json", false), 0);
+}
+
+#[rstest]
+#[serial]
+fn test_filename_id_check_is_ignored() {
+    let mut pp = brca1_phenopacket();
+    pp.meta_data
+        .as_mut()
+        .unwrap()
+        .external_references
+        .push(ExternalReference {
+            id: IGNORE_FINDINGS_REFERENCE_ID.to_string(),
+            reference: String::new(),
+            description: "NAME001".to_string(),
+        });
+
+    let report = lint_file_with("TP53_case.json", &pp, context(true));
+
+    assert_eq!(count_name001(report.findings()), 0);
+    assert_eq!(count_name001(report.suppressed()), 1);
+}
+
+#[rstest]
+#[serial]
+fn test_filename_id_check_is_baselined() {
+    let report = lint_file_with("TP53_case.json", &brca1_phenopacket(), context(true));
+    let baseline = Baseline::from_report(&report);
+
+    let report = lint_file_with(
+        "TP53_case.json",
+        &brca1_phenopacket(),
+        context(true).with_baseline(baseline),
+    );
+
+    assert_eq!(count_name001(report.findings()), 0);
+    assert_eq!(count_name001(report.baselined()), 1);
+}
+
+#[rstest]
+#[serial]
+fn test_filename_id_check_respects_max_findings() {
+    let report = lint_file_with(
+        "TP53_case.json",
+        &brca1_phenopacket(),
+        context(true).with_max_findings(Some(0)),
+    );
+
+    assert!(report.findings().is_empty());
+    assert!(report.is_truncated());
+}
@@ -0,0 +1,56 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+const DESCRIPTION: &str = "Recurrent generalized tonic-clonic seizures since early childhood";
+
+fn feature(id: &str, label: &str) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        description: DESCRIPTION.to_string(),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_generic_type_with_description_rule() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![feature("HP:0000707", "Abnormality of the nervous system")];
+
+    let rule_id = "PF011";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["general term", "specific HPO term"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_generic_type_with_description_rule_specific_type() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![feature("HP:0001250", "Seizure")];
+
+    let rule_id = "PF011";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
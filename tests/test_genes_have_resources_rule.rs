@@ -0,0 +1,76 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Interpretation, Resource};
+use rstest::rstest;
+use serde_json::json;
+use serial_test::serial;
+
+fn phenopacket_with_gene() -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+
+    let interpretation: Interpretation = serde_json::from_value(json!({
+        "id": "interpretation_1",
+        "progressStatus": "SOLVED",
+        "diagnosis": {
+            "disease": {"id": "MONDO:0011450", "label": "breast-ovarian cancer, familial, susceptibility to, 1"},
+            "genomicInterpretations": [{
+                "subjectOrBiosampleId": "cohort-1-patient-1",
+                "interpretationStatus": "CAUSATIVE",
+                "gene": {"valueId": "HGNC:1100", "symbol": "BRCA1"}
+            }]
+        }
+    }))
+    .unwrap();
+
+    pp.interpretations.push(interpretation);
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_genes_have_resources_rule_unresourced_gene() {
+    let pp = phenopacket_with_gene();
+
+    let rule_id = "INTER006";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![
+            "HGNC:1100",
+            "This gene id ...",
+            "... should have a resource here",
+        ],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_genes_have_resources_rule_resourced_gene() {
+    let mut pp = phenopacket_with_gene();
+    pp.meta_data.as_mut().unwrap().resources.push(Resource {
+        id: "hgnc".to_string(),
+        name: "HUGO Gene Nomenclature Committee".to_string(),
+        url: "https://www.genenames.org".to_string(),
+        version: "2024-08-23".to_string(),
+        namespace_prefix: "HGNC".to_string(),
+        iri_prefix: "https://www.genenames.org/data/gene-symbol-report/#!/hgnc_id/".to_string(),
+    });
+
+    let rule_id = "INTER006";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,63 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Biosample, Individual, Interpretation};
+use rstest::rstest;
+use serde_json::json;
+use serial_test::serial;
+
+fn with_genomic_interpretation(subject_or_biosample_id: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        ..Default::default()
+    });
+    pp.biosamples = vec![Biosample {
+        id: "tumor-1".to_string(),
+        individual_id: "patient-1".to_string(),
+        ..Default::default()
+    }];
+
+    let interpretation: Interpretation = serde_json::from_value(json!({
+        "id": "interpretation-1",
+        "progressStatus": "SOLVED",
+        "diagnosis": {
+            "disease": {"id": "MONDO:0007254", "label": "breast cancer"},
+            "genomicInterpretations": [{
+                "subjectOrBiosampleId": subject_or_biosample_id,
+                "interpretationStatus": "CAUSATIVE",
+                "gene": {"valueId": "HGNC:1100", "symbol": "BRCA1"}
+            }]
+        }
+    }))
+    .unwrap();
+
+    pp.interpretations.push(interpretation);
+    pp
+}
+
+#[rstest]
+#[case("tumor-2", 1, vec!["tumor-2", "Neither the subject nor a biosample has this id"])]
+#[case("tumor-1", 0, vec![])]
+#[case("patient-1", 0, vec![])]
+#[serial]
+fn test_genomic_interpretation_biosample_rule(
+    #[case] subject_or_biosample_id: &str,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let pp = with_genomic_interpretation(subject_or_biosample_id);
+
+    let rule_id = "INTER007";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
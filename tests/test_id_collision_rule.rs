@@ -0,0 +1,62 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::LinterContext;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Biosample, Individual};
+use rstest::rstest;
+use serial_test::serial;
+
+fn with_ids(subject_id: &str, biosample_id: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: subject_id.to_string(),
+        ..Default::default()
+    });
+    pp.biosamples = vec![Biosample {
+        id: biosample_id.to_string(),
+        individual_id: subject_id.to_string(),
+        ..Default::default()
+    }];
+    pp
+}
+
+#[rstest]
+#[case(with_ids("patient-1", "cohort-1-patient-1"), 1, vec!["cohort-1-patient-1", "biosample", "phenopacket"])]
+#[case(with_ids("patient-1", "sample-1"), 0, vec![])]
+#[serial]
+fn test_id_collision_rule(
+    #[case] pp: Phenopacket,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let rule_id = "STR002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_id_collision_rule_shared_kinds() {
+    let pp = with_ids("cohort-1-patient-1", "sample-1");
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let context = LinterContext::new(None)
+        .with_shared_id_kinds(vec![vec!["phenopacket".to_string(), "subject".to_string()]]);
+    let mut linter = Phenolint::new(context, vec!["STR002".to_string()]);
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.error.is_none());
+    assert!(res.report.violations().is_empty());
+}
@@ -0,0 +1,25 @@
+mod common;
+use crate::common::construction::build_linter;
+use crate::common::paths::{
+    json_phenopacket_path, protobufphenopacket_path, yaml_phenopacket_path,
+};
+use phenolint::enums::InputTypes;
+use phenolint::traits::Lint;
+use rstest::rstest;
+use std::path::PathBuf;
+
+#[rstest]
+#[case(json_phenopacket_path(common::paths::assets_dir()), InputTypes::Json)]
+#[case(yaml_phenopacket_path(common::paths::assets_dir()), InputTypes::Yaml)]
+#[case(
+    protobufphenopacket_path(common::paths::assets_dir()),
+    InputTypes::Protobuf
+)]
+fn test_detected_input_type(#[case] phenopacket_path: PathBuf, #[case] expected: InputTypes) {
+    let mut linter = build_linter(vec![]);
+
+    let res = linter.lint(&phenopacket_path, false, true);
+
+    assert_eq!(res.input_type(), Some(expected));
+    assert_eq!(res.report().input_type(), Some(expected));
+}
@@ -0,0 +1,43 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::{Individual, OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serde_json::Value;
+use serial_test::serial;
+
+#[rstest]
+#[serial]
+fn test_exported_json_patch_matches_autofix() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        alternate_ids: vec!["".to_string(), "PMID:1234-1".to_string(), " ".to_string()],
+        ..Default::default()
+    });
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "Seizure".to_string(),
+            label: "HP:0001250".to_string(),
+        }),
+        ..Default::default()
+    }];
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["CURIE003", "SUBJ002"]);
+    let res = linter.lint(phenostr.as_str(), true, true);
+    assert!(res.error.is_none());
+
+    let Some(PhenopacketData::Text(patched)) = res.report.patched_phenopacket.as_ref() else {
+        panic!("Expected a patched phenopacket");
+    };
+    let autofixed: Value = serde_json::from_str(patched).unwrap();
+
+    let mut exported: Value = serde_json::from_str(&phenostr).unwrap();
+    let json_patch = res.report.to_json_patch(&exported).unwrap();
+    json_patch::patch(&mut exported, &json_patch).unwrap();
+
+    assert_eq!(json_patch.0.len(), 4);
+    assert_eq!(exported, autofixed);
+}
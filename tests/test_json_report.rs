@@ -0,0 +1,99 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::diagnostics::report::JSON_REPORT_VERSION;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::{Individual, OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serde_json::Value;
+use serial_test::serial;
+
+#[rstest]
+#[serial]
+fn test_json_report_round_trip() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        alternate_ids: vec!["".to_string(), "PMID:1234-1".to_string()],
+        ..Default::default()
+    });
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "Seizure".to_string(),
+            label: "HP:0001250".to_string(),
+        }),
+        ..Default::default()
+    }];
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["CURIE003", "SUBJ002"]);
+    let res = linter.lint(phenostr.as_str(), false, true);
+    assert!(res.error.is_none());
+
+    let json_report = res.report.to_json();
+    let round_tripped: Value =
+        serde_json::from_str(&serde_json::to_string(&json_report).unwrap()).unwrap();
+    assert_eq!(round_tripped, json_report);
+
+    assert_eq!(round_tripped["version"], JSON_REPORT_VERSION);
+    let findings = round_tripped["findings"].as_array().unwrap();
+    assert_eq!(findings.len(), res.report.violations().len());
+
+    for (finding, violation) in findings.iter().zip(res.report.violations()) {
+        assert_eq!(finding["rule_id"], violation.rule_id());
+
+        let severity = finding["severity"].as_str().unwrap();
+        assert!(["error", "warning", "info"].contains(&severity));
+
+        let pointers: Vec<&str> = finding["at"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|ptr| ptr.as_str().unwrap())
+            .collect();
+        let expected: Vec<&str> = violation.at().iter().map(|ptr| ptr.position()).collect();
+        assert_eq!(pointers, expected);
+        assert!(pointers.iter().all(|ptr| ptr.starts_with('/')));
+
+        for label in finding["labels"].as_array().unwrap() {
+            assert!(["primary", "secondary"].contains(&label["priority"].as_str().unwrap()));
+            let (start, end) = (
+                label["start"].as_u64().unwrap() as usize,
+                label["end"].as_u64().unwrap() as usize,
+            );
+            assert!(start <= end && end <= phenostr.len());
+        }
+    }
+
+    let swapped = findings
+        .iter()
+        .find(|finding| finding["rule_id"] == "CURIE003")
+        .unwrap();
+    let instructions = swapped["patches"][0]["instructions"].as_array().unwrap();
+    assert!(instructions.iter().all(|instruction| {
+        instruction["op"] == "replace" && instruction["at"].as_str().unwrap().starts_with('/')
+    }));
+}
+
+#[rstest]
+#[serial]
+fn test_json_report_quiet_keeps_reports() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "Seizure".to_string(),
+            label: "HP:0001250".to_string(),
+        }),
+        ..Default::default()
+    }];
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["CURIE003"]);
+    let res = linter.lint(phenostr.as_str(), false, true);
+    assert!(res.error.is_none());
+
+    let findings = res.report.to_json()["findings"].as_array().unwrap().clone();
+    assert_eq!(findings.len(), 1);
+    assert!(findings[0]["message"].is_string());
+    assert!(!findings[0]["labels"].as_array().unwrap().is_empty());
+    assert!(!findings[0]["patches"].as_array().unwrap().is_empty());
+}
@@ -0,0 +1,78 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::time_element::Element;
+use phenopackets::schema::v2::core::{Age, Individual, TimeElement};
+use prost_types::Timestamp;
+use rstest::rstest;
+use serial_test::serial;
+
+// 2000-01-01T00:00:00Z
+const DATE_OF_BIRTH: i64 = 946684800;
+
+fn age(iso8601duration: &str) -> TimeElement {
+    TimeElement {
+        element: Some(Element::Age(Age {
+            iso8601duration: iso8601duration.to_string(),
+        })),
+    }
+}
+
+fn timestamp(seconds: i64) -> TimeElement {
+    TimeElement {
+        element: Some(Element::Timestamp(Timestamp { seconds, nanos: 0 })),
+    }
+}
+
+fn subject(time_at_last_encounter: Option<TimeElement>, date_of_birth: Option<i64>) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        time_at_last_encounter,
+        date_of_birth: date_of_birth.map(|seconds| Timestamp { seconds, nanos: 0 }),
+        ..Default::default()
+    });
+    pp
+}
+
+#[rstest]
+#[case(age("-P5Y"))]
+#[case(timestamp(DATE_OF_BIRTH - 86400))]
+#[serial]
+fn test_last_encounter_before_birth_rule(#[case] time_at_last_encounter: TimeElement) {
+    let rule_id = "SUBJ006";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["last encounter", "date of birth"],
+    };
+
+    run_rule_test(
+        rule_id,
+        &subject(Some(time_at_last_encounter), Some(DATE_OF_BIRTH)),
+        assert_settings,
+    );
+}
+
+#[rstest]
+#[case(subject(Some(age("P5Y")), Some(DATE_OF_BIRTH)))]
+#[case(subject(Some(timestamp(DATE_OF_BIRTH + 86400)), Some(DATE_OF_BIRTH)))]
+#[case(subject(Some(timestamp(DATE_OF_BIRTH - 86400)), None))]
+#[case(subject(None, Some(DATE_OF_BIRTH)))]
+#[serial]
+fn test_last_encounter_before_birth_rule_no_violation(#[case] pp: Phenopacket) {
+    let rule_id = "SUBJ006";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,59 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::core::{Disease, OntologyClass};
+use rstest::rstest;
+use serial_test::serial;
+
+fn oc(id: &str, label: &str) -> OntologyClass {
+    OntologyClass {
+        id: id.to_string(),
+        label: label.to_string(),
+    }
+}
+
+fn breast_carcinoma(primary_site: Option<OntologyClass>) -> Disease {
+    Disease {
+        term: Some(oc("MONDO:0004989", "breast carcinoma")),
+        laterality: Some(oc("HP:0012835", "Left")),
+        primary_site,
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_laterality_without_site_rule() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.diseases = vec![breast_carcinoma(None)];
+
+    let rule_id = "DIS001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["breast carcinoma", "primary site"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_laterality_without_site_rule_with_site() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.diseases = vec![breast_carcinoma(Some(oc("UBERON:0000310", "breast")))];
+
+    let rule_id = "DIS001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
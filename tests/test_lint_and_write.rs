@@ -0,0 +1,126 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::error::LinterError;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{
+    Diagnosis, Disease, Interpretation, OntologyClass, PhenotypicFeature,
+};
+use rstest::rstest;
+use serial_test::serial;
+use tempfile::TempDir;
+
+fn with_feature(id: &str, label: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        ..Default::default()
+    }];
+    pp
+}
+
+/// Violates INTER001 unless `listed`, because the diagnosed disease is missing from the diseases.
+fn with_diagnosis(listed: bool) -> Phenopacket {
+    let disease = OntologyClass {
+        id: "MONDO:0000252".to_string(),
+        label: "inflammatory diarrhea".to_string(),
+    };
+
+    let mut pp = minimal_valid_phenopacket();
+    pp.interpretations = vec![Interpretation {
+        id: "interpretation-1".to_string(),
+        diagnosis: Some(Diagnosis {
+            disease: Some(disease.clone()),
+            genomic_interpretations: vec![],
+        }),
+        ..Default::default()
+    }];
+    if listed {
+        pp.diseases = vec![Disease {
+            term: Some(disease),
+            ..Default::default()
+        }];
+    }
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_lint_and_write_patched() {
+    let tmp_dir = TempDir::new().unwrap();
+    let input_path = tmp_dir.path().join("input.yaml");
+    let output_path = tmp_dir.path().join("output.yaml");
+
+    let input = serde_yaml::to_string(&with_diagnosis(false)).unwrap();
+    std::fs::write(&input_path, &input).unwrap();
+
+    let mut linter = build_linter(vec!["INTER001"]);
+    let res = linter.lint_and_write(&input_path, &output_path, true);
+    assert!(res.error.is_none());
+
+    let output = std::fs::read_to_string(&output_path).unwrap();
+    assert!(serde_json::from_str::<serde_json::Value>(&output).is_err());
+    assert_eq!(
+        serde_yaml::from_str::<Phenopacket>(&output).unwrap(),
+        with_diagnosis(true)
+    );
+    assert_eq!(std::fs::read_to_string(&input_path).unwrap(), input);
+}
+
+#[rstest]
+#[serial]
+fn test_lint_and_write_skips_lossy_patches() {
+    let tmp_dir = TempDir::new().unwrap();
+    let input_path = tmp_dir.path().join("input.json");
+    let output_path = tmp_dir.path().join("output.json");
+
+    let input = serde_json::to_string_pretty(&with_feature("Seizure", "HP:0001250")).unwrap();
+    std::fs::write(&input_path, &input).unwrap();
+
+    let mut linter = build_linter(vec!["CURIE003"]);
+    let res = linter.lint_and_write(&input_path, &output_path, true);
+
+    assert!(res.error.is_none());
+    assert_eq!(res.report.skipped_patches().len(), 1);
+    let output = std::fs::read_to_string(&output_path).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Phenopacket>(&output).unwrap(),
+        with_feature("Seizure", "HP:0001250")
+    );
+}
+
+#[rstest]
+#[serial]
+fn test_lint_and_write_refuses_to_overwrite_input() {
+    let tmp_dir = TempDir::new().unwrap();
+    let input_path = tmp_dir.path().join("input.json");
+
+    let input = serde_json::to_string_pretty(&with_diagnosis(false)).unwrap();
+    std::fs::write(&input_path, &input).unwrap();
+
+    let mut linter = build_linter(vec!["INTER001"]);
+    let res = linter.lint_and_write(&input_path, &tmp_dir.path().join("./input.json"), true);
+
+    assert!(matches!(res.error, Some(LinterError::InitError(_))));
+    assert_eq!(std::fs::read_to_string(&input_path).unwrap(), input);
+}
+
+#[rstest]
+#[serial]
+fn test_lint_and_write_without_patch() {
+    let tmp_dir = TempDir::new().unwrap();
+    let input_path = tmp_dir.path().join("input.json");
+    let output_path = tmp_dir.path().join("output.json");
+
+    let input = serde_json::to_string_pretty(&with_feature("Seizure", "HP:0001250")).unwrap();
+    std::fs::write(&input_path, &input).unwrap();
+
+    let mut linter = build_linter(vec!["CURIE003"]);
+    let res = linter.lint_and_write(&input_path, &output_path, false);
+
+    assert!(res.error.is_none());
+    assert!(res.report.has_patches());
+    assert_eq!(std::fs::read_to_string(&output_path).unwrap(), input);
+}
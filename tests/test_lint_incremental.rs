@@ -0,0 +1,45 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::core::{Diagnosis, Interpretation, OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+
+#[rstest]
+#[case("/phenotypicFeatures/0/type", vec!["CURIE001"])]
+#[case("/phenotypicFeatures", vec!["CURIE001"])]
+#[case("/interpretations/0", vec!["CURIE001", "INTER001"])]
+fn test_lint_incremental(#[case] changed: &str, #[case] expected_rule_ids: Vec<&str>) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features.push(PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "invalid".to_string(),
+            label: "some pf".to_string(),
+        }),
+        ..Default::default()
+    });
+    pp.interpretations.push(Interpretation {
+        id: "interpretation_123".to_string(),
+        diagnosis: Some(Diagnosis {
+            disease: Some(OntologyClass {
+                id: "MONDO:0000252".to_string(),
+                label: "inflammatory diarrhea".to_string(),
+            }),
+            genomic_interpretations: vec![],
+        }),
+        ..Default::default()
+    });
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["CURIE001", "INTER001"]);
+    let res = linter.lint_incremental(phenostr.as_str(), &[Pointer::new(changed)]);
+    assert!(res.error.is_none());
+
+    let mut rule_ids: Vec<&str> = res
+        .report()
+        .findings()
+        .iter()
+        .map(|finding| finding.violation().rule_id())
+        .collect();
+    rule_ids.sort();
+    assert_eq!(rule_ids, expected_rule_ids);
+}
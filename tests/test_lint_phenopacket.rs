@@ -0,0 +1,43 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Diagnosis, Interpretation, OntologyClass};
+use rstest::rstest;
+
+fn phenopacket_with_undiagnosed_disease() -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.interpretations.push(Interpretation {
+        id: "interpretation_123".to_string(),
+        diagnosis: Some(Diagnosis {
+            disease: Some(OntologyClass {
+                id: "MONDO:0000252".to_string(),
+                label: "inflammatory diarrhea".to_string(),
+            }),
+            genomic_interpretations: vec![],
+        }),
+        ..Default::default()
+    });
+    pp
+}
+
+#[rstest]
+fn test_lint_phenopacket_matches_json() {
+    let pp = phenopacket_with_undiagnosed_disease();
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let from_struct = build_linter(vec!["INTER001"]).lint(&pp, true, true);
+    let from_str = build_linter(vec!["INTER001"]).lint(phenostr.as_str(), true, true);
+
+    assert!(from_struct.error.is_none());
+    assert_eq!(from_struct.report().violations().len(), 1);
+    assert_eq!(
+        from_struct.report().violations(),
+        from_str.report().violations()
+    );
+    assert_eq!(from_struct.report().patches(), from_str.report().patches());
+    assert_eq!(
+        from_struct.report().patched_phenopacket,
+        from_str.report().patched_phenopacket
+    );
+}
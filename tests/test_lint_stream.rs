@@ -0,0 +1,53 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+use std::io::Cursor;
+
+fn with_feature(id: &str, label: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        ..Default::default()
+    }];
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_lint_stream_continues_after_invalid_line() {
+    let input = format!(
+        "{}\n{{\"id\": \"broken\"\n\n{}\n",
+        serde_json::to_string(&with_feature("Seizure", "HP:0001250")).unwrap(),
+        serde_json::to_string(&with_feature("HP:0001250", "Seizure")).unwrap(),
+    );
+
+    let mut linter = build_linter(vec!["CURIE003"]);
+    let results: Vec<_> = linter
+        .lint_stream(Cursor::new(input), false, true)
+        .collect();
+
+    assert_eq!(
+        results
+            .iter()
+            .map(|(line_number, _)| *line_number)
+            .collect::<Vec<_>>(),
+        vec![1, 2, 4]
+    );
+
+    let (_, first) = &results[0];
+    assert!(first.error.is_none());
+    assert_eq!(first.report.violations().len(), 1);
+
+    let (_, second) = &results[1];
+    assert!(second.error.is_some());
+
+    let (_, third) = &results[2];
+    assert!(third.error.is_none());
+    assert!(third.report.violations().is_empty());
+}
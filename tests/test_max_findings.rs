@@ -0,0 +1,118 @@
+mod common;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::paths::{assets_dir, hpo_dir};
+use phenolint::LinterContext;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+
+#[rstest]
+#[case(Some(1), 1, true)]
+#[case(Some(3), 3, true)]
+#[case(Some(20), 10, false)]
+#[case(None, 10, false)]
+fn test_max_findings(
+    #[case] max_findings: Option<usize>,
+    #[case] expected_findings: usize,
+    #[case] expected_truncated: bool,
+) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = (0..10)
+        .map(|i| PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: format!("invalid:{i}"),
+                label: "some pf".to_string(),
+            }),
+            ..Default::default()
+        })
+        .collect();
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let context = LinterContext::new(Some(hpo_dir(assets_dir()))).with_max_findings(max_findings);
+    let mut linter = Phenolint::new(context, vec!["CURIE001".to_string()]);
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert_eq!(res.report().findings().len(), expected_findings);
+    assert_eq!(res.report().is_truncated(), expected_truncated);
+}
+
+#[rstest]
+#[case(Some(4), vec!["CURIE001"; 4], true)]
+#[case(Some(5), vec!["CURIE001", "CURIE001", "CURIE001", "CURIE001", "CURIE003"], false)]
+fn test_max_findings_keeps_findings_in_rule_id_order(
+    #[case] max_findings: Option<usize>,
+    #[case] expected_rule_ids: Vec<&str>,
+    #[case] expected_truncated: bool,
+) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = (0..4)
+        .map(|i| PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: format!("invalid:{i}"),
+                label: "some pf".to_string(),
+            }),
+            ..Default::default()
+        })
+        .collect();
+    pp.phenotypic_features[0].r#type = Some(OntologyClass {
+        id: "Seizure".to_string(),
+        label: "HP:0001250".to_string(),
+    });
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    for _ in 0..5 {
+        let context =
+            LinterContext::new(Some(hpo_dir(assets_dir()))).with_max_findings(max_findings);
+        let mut linter = Phenolint::new(
+            context,
+            vec!["CURIE003".to_string(), "CURIE001".to_string()],
+        );
+
+        let res = linter.lint(phenostr.as_str(), false, true);
+
+        let mut rule_ids: Vec<&str> = res
+            .report()
+            .findings()
+            .iter()
+            .map(|finding| finding.violation().rule_id())
+            .collect();
+        rule_ids.sort();
+        assert_eq!(rule_ids, expected_rule_ids);
+        assert_eq!(res.report().is_truncated(), expected_truncated);
+    }
+}
+
+#[rstest]
+fn test_max_findings_incremental_keeps_first_rule_in_id_order() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "Seizure".to_string(),
+            label: "HP:0001250".to_string(),
+        }),
+        ..Default::default()
+    }];
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    for _ in 0..5 {
+        let context = LinterContext::new(Some(hpo_dir(assets_dir()))).with_max_findings(Some(1));
+        let mut linter = Phenolint::new(
+            context,
+            vec!["CURIE003".to_string(), "CURIE001".to_string()],
+        );
+
+        let res = linter.lint_incremental(
+            phenostr.as_str(),
+            &[Pointer::new("/phenotypicFeatures/0/type")],
+        );
+
+        assert!(res.error.is_none());
+        let findings = res.report().findings();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].violation().rule_id(), "CURIE001");
+        assert!(res.report().is_truncated());
+    }
+}
@@ -0,0 +1,81 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Measurement;
+use rstest::{fixture, rstest};
+use serde_json::{Value, json};
+use serial_test::serial;
+
+fn measurement(assay: Option<Value>) -> Value {
+    let mut measurement = json!({
+        "value": {
+            "quantity": {
+                "unit": {"id": "UCUM:mg/dL", "label": "milligram per deciliter"},
+                "value": 180.0
+            }
+        }
+    });
+    if let Some(assay) = assay {
+        measurement["assay"] = assay;
+    }
+    measurement
+}
+
+fn with_assay(id: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    let measurement: Measurement = serde_json::from_value(measurement(Some(json!({
+        "id": id,
+        "label": "Glucose [Mass/volume] in Serum or Plasma"
+    }))))
+    .unwrap();
+
+    pp.measurements.push(measurement);
+    pp
+}
+
+#[rstest]
+#[case(with_assay("LOINC:2345-7"), 0, vec![])]
+#[case(with_assay(" "), 1, vec!["no assay"])]
+#[serial]
+fn test_measurement_assay_rule(
+    #[case] pp: Phenopacket,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let rule_id = "MEAS002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+/// The schema requires an assay, so measurements without one are only linted without validation.
+#[fixture]
+fn without_assay() -> Value {
+    let mut pp = serde_json::to_value(minimal_valid_phenopacket()).unwrap();
+    pp["measurements"] = json!([measurement(None)]);
+    pp
+}
+
+#[rstest]
+fn test_measurement_assay_rule_missing_assay(without_assay: Value) {
+    let mut linter = Phenolint::builder()
+        .with_rule("MEAS002")
+        .with_schema_validation(false)
+        .build();
+    let res = linter.lint(without_assay.to_string().as_str(), false, true);
+
+    assert!(res.error.is_none());
+    let violations = res.report().violations();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].first_at().position(), "/measurements/0");
+}
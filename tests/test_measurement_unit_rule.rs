@@ -0,0 +1,49 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Measurement;
+use rstest::rstest;
+use serde_json::json;
+use serial_test::serial;
+
+fn with_unit(id: &str, label: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+
+    let measurement: Measurement = serde_json::from_value(json!({
+        "assay": {"id": "LOINC:2345-7", "label": "Glucose [Mass/volume] in Serum or Plasma"},
+        "value": {
+            "quantity": {
+                "unit": {"id": id, "label": label},
+                "value": 180.0
+            }
+        }
+    }))
+    .unwrap();
+
+    pp.measurements.push(measurement);
+    pp
+}
+
+#[rstest]
+#[case(with_unit("mg/dL", "milligram per deciliter"), 1, vec!["'mg/dL'", "UO, UCUM, NCIT"])]
+#[case(with_unit(" ", "milligram per deciliter"), 1, vec!["has no id"])]
+#[case(with_unit("UCUM:mg/dL", "milligram per deciliter"), 0, vec![])]
+#[serial]
+fn test_measurement_unit_rule(
+    #[case] pp: Phenopacket,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let rule_id = "MEAS001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
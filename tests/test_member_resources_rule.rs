@@ -0,0 +1,125 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::LinterContext;
+use phenolint::phenolint::Phenolint;
+use phenolint::report::enums::ViolationSeverity;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Disease, OntologyClass, PhenotypicFeature, Resource};
+use rstest::rstest;
+use serde_json::json;
+use serial_test::serial;
+use std::collections::HashMap;
+
+fn resource(namespace_prefix: &str) -> Resource {
+    Resource {
+        id: namespace_prefix.to_lowercase(),
+        name: format!("{namespace_prefix} ontology"),
+        url: format!(
+            "http://purl.obolibrary.org/obo/{}.owl",
+            namespace_prefix.to_lowercase()
+        ),
+        version: "2024-04-26".to_string(),
+        namespace_prefix: namespace_prefix.to_string(),
+        iri_prefix: format!("http://purl.obolibrary.org/obo/{namespace_prefix}_"),
+    }
+}
+
+fn member() -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "HP:0001166".to_string(),
+            label: "Arachnodactyly".to_string(),
+        }),
+        ..Default::default()
+    }];
+    pp.diseases = vec![Disease {
+        term: Some(OntologyClass {
+            id: "MONDO:0007947".to_string(),
+            label: "Marfan syndrome".to_string(),
+        }),
+        ..Default::default()
+    }];
+    pp
+}
+
+fn cohort(resources: Vec<Resource>) -> String {
+    let mut meta_data = minimal_valid_phenopacket().meta_data.unwrap();
+    meta_data.resources = resources;
+
+    serde_json::to_string_pretty(&json!({
+        "id": "cohort-1",
+        "members": [member()],
+        "metaData": meta_data,
+    }))
+    .unwrap()
+}
+
+#[rstest]
+#[case(vec![resource("HP"), resource("MONDO")], 0)]
+#[case(vec![resource("HP")], 1)]
+#[case(vec![], 2)]
+#[serial]
+fn test_member_resources_rule(#[case] resources: Vec<Resource>, #[case] n_violations: usize) {
+    let mut linter = build_linter(vec!["COH001"]);
+
+    let res = linter.lint_cohort(cohort(resources).as_str(), true);
+
+    assert!(res.error.is_none(), "{:?}", res.error);
+    let findings = res.report.findings();
+    assert_eq!(findings.len(), n_violations);
+    assert!(
+        findings
+            .iter()
+            .all(|finding| finding.violation().first_at().position() == "/metaData")
+    );
+}
+
+#[rstest]
+#[serial]
+fn test_member_resources_rule_message() {
+    let mut linter = build_linter(vec!["COH001"]);
+
+    let res = linter.lint_cohort(cohort(vec![resource("HP")]).as_str(), true);
+
+    let findings = res.report.findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].violation().at()[1].position(),
+        "/members/0/diseases/0/term"
+    );
+    assert!(findings[0].report().unwrap().message().contains("'MONDO'"));
+}
+
+#[rstest]
+#[serial]
+fn test_member_resources_rule_severity_override() {
+    let context = LinterContext::new(None).with_severity_overrides(HashMap::from([(
+        "COH001".to_string(),
+        ViolationSeverity::Info,
+    )]));
+    let mut linter = Phenolint::new(context, vec!["COH001".to_string()]);
+
+    let res = linter.lint_cohort(cohort(vec![resource("HP")]).as_str(), true);
+
+    let findings = res.report.findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].violation().severity(), &ViolationSeverity::Info);
+    assert_eq!(
+        findings[0].report().unwrap().severity(),
+        &ViolationSeverity::Info
+    );
+}
+
+#[rstest]
+#[serial]
+fn test_member_resources_rule_skips_phenopackets() {
+    let mut linter = build_linter(vec!["COH001"]);
+    let phenostr = serde_json::to_string_pretty(&member()).unwrap();
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.error.is_none());
+    assert!(res.report.findings().is_empty());
+}
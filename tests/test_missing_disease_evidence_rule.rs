@@ -0,0 +1,62 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::core::{Disease, ExternalReference, OntologyClass};
+use rstest::rstest;
+use serial_test::serial;
+
+fn marfan_syndrome() -> Disease {
+    Disease {
+        term: Some(OntologyClass {
+            id: "MONDO:0007947".to_string(),
+            label: "Marfan syndrome".to_string(),
+        }),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_missing_disease_evidence_rule() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.diseases = vec![marfan_syndrome()];
+
+    let rule_id = "EVID003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["Marfan syndrome", "evidence"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_missing_disease_evidence_rule_with_reference() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.diseases = vec![marfan_syndrome()];
+    pp.meta_data
+        .as_mut()
+        .unwrap()
+        .external_references
+        .push(ExternalReference {
+            id: "PMID:30808312".to_string(),
+            reference: "https://pubmed.ncbi.nlm.nih.gov/30808312".to_string(),
+            description: "Case report".to_string(),
+        });
+
+    let rule_id = "EVID003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
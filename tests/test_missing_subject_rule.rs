@@ -0,0 +1,72 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::ga4gh::vrsatile::v1::GeneDescriptor;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::genomic_interpretation::Call;
+use phenopackets::schema::v2::core::{
+    Diagnosis, GenomicInterpretation, Individual, Interpretation, OntologyClass,
+};
+use rstest::{fixture, rstest};
+use serial_test::serial;
+
+#[fixture]
+fn phenopacket_with_interpretation() -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.interpretations.push(Interpretation {
+        id: "interpretation_1".to_string(),
+        diagnosis: Some(Diagnosis {
+            disease: Some(OntologyClass {
+                id: "MONDO:0000252".to_string(),
+                label: "inflammatory diarrhea".to_string(),
+            }),
+            genomic_interpretations: vec![GenomicInterpretation {
+                subject_or_biosample_id: "patient-1".to_string(),
+                call: Some(Call::Gene(GeneDescriptor {
+                    value_id: "HGNC:3603".to_string(),
+                    symbol: "FBN1".to_string(),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }],
+        }),
+        ..Default::default()
+    });
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_missing_subject_rule(phenopacket_with_interpretation: Phenopacket) {
+    let rule_id = "SUBJ001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["no subject"],
+    };
+
+    run_rule_test(rule_id, &phenopacket_with_interpretation, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_missing_subject_rule_with_subject(mut phenopacket_with_interpretation: Phenopacket) {
+    phenopacket_with_interpretation.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        ..Default::default()
+    });
+
+    let rule_id = "SUBJ001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &phenopacket_with_interpretation, assert_settings);
+}
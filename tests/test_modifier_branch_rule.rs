@@ -0,0 +1,49 @@
+mod common;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::paths::{assets_dir, hpo_dir};
+use phenolint::LinterContext;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+use std::collections::HashMap;
+
+fn oc(id: &str, label: &str) -> OntologyClass {
+    OntologyClass {
+        id: id.to_string(),
+        label: label.to_string(),
+    }
+}
+
+#[rstest]
+#[case(oc("HP:0001250", "Seizure"), 1)]
+#[case(oc("HP:0002817", "Abnormality of the upper limb"), 0)]
+#[serial]
+fn test_modifier_branch_rule(#[case] feature_type: OntologyClass, #[case] n_violations: usize) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(feature_type),
+        modifiers: vec![oc("HP:0012832", "Bilateral")],
+        ..Default::default()
+    }];
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let context = LinterContext::new(Some(hpo_dir(assets_dir()))).with_modifier_branches(
+        HashMap::from([("HP:0012832".to_string(), vec!["HP:0040064".to_string()])]),
+    );
+    let mut linter = Phenolint::new(context, vec!["PF019".to_string()]);
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.error.is_none());
+    let violations = res.report.violations();
+    assert_eq!(violations.len(), n_violations);
+    for violation in violations {
+        assert_eq!(violation.rule_id(), "PF019");
+        assert_eq!(
+            violation.first_at().position(),
+            "/phenotypicFeatures/0/modifiers/0"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn pancreatitis_with_modifier(id: &str, label: &str) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "HP:0001733".to_string(),
+            label: "Pancreatitis".to_string(),
+        }),
+        modifiers: vec![OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }],
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_modifier_ontology_child_rule() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![pancreatitis_with_modifier("HP:0001250", "Seizure")];
+
+    let rule_id = "PF002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["Seizure", "clinical modifier"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_modifier_ontology_child_rule_valid_modifier() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![pancreatitis_with_modifier("HP:0031796", "Recurrent")];
+
+    let rule_id = "PF002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,134 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::paths::{assets_dir, hpo_dir};
+use crate::common::test_functions::run_rule_test;
+use phenolint::LinterContext;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn feature(id: &str, label: &str, excluded: bool) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        excluded,
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[case(vec![
+    feature("HP:0040064", "Abnormality of limbs", true),
+    feature("HP:0002817", "Abnormality of the upper limb", false),
+], 1, vec!["Abnormality of limbs", "Observed descendant"])]
+#[case(vec![
+    feature("HP:0040064", "Abnormality of limbs", false),
+    feature("HP:0002817", "Abnormality of the upper limb", true),
+], 0, vec![])]
+#[serial]
+fn test_observed_excluded_contradiction_rule(
+    #[case] features: Vec<PhenotypicFeature>,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = features;
+
+    let rule_id = "PF008";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[case(vec!["exact"], vec!["/phenotypicFeatures/1"])]
+#[case(vec!["ancestor"], vec!["/phenotypicFeatures/3"])]
+#[case(vec!["descendant"], vec!["/phenotypicFeatures/4"])]
+#[case(vec!["exact", "ancestor", "descendant"], vec!["/phenotypicFeatures/1", "/phenotypicFeatures/3", "/phenotypicFeatures/4"])]
+#[case(vec![], vec![])]
+#[serial]
+fn test_observed_excluded_contradiction_rule_modes(
+    #[case] modes: Vec<&str>,
+    #[case] expected: Vec<&str>,
+) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![
+        feature("HP:0001250", "Seizure", false),
+        feature("HP:0001250", "Seizure", true),
+        feature("HP:0040064", "Abnormality of limbs", false),
+        feature("HP:0002817", "Abnormality of the upper limb", true),
+        feature("HP:0012831", "Laterality", true),
+        feature("HP:0012832", "Bilateral", false),
+    ];
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let context = LinterContext::new(Some(hpo_dir(assets_dir())))
+        .with_contradiction_modes(modes.iter().map(|mode| mode.to_string()).collect());
+    let mut linter = Phenolint::new(context, vec!["PF008".to_string()]);
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.error.is_none());
+    let mut flagged: Vec<&str> = res
+        .report
+        .violations()
+        .iter()
+        .map(|violation| violation.first_at().position())
+        .collect();
+    flagged.sort();
+    assert_eq!(flagged, expected);
+}
+
+#[rstest]
+#[case("exact", "is observed and excluded")]
+#[case("ancestor", "double negative")]
+#[case("descendant", "is an ancestor of observed")]
+#[serial]
+fn test_observed_excluded_contradiction_rule_mode_messages(
+    #[case] mode: &str,
+    #[case] message_snippet: &str,
+) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = match mode {
+        "exact" => vec![
+            feature("HP:0001250", "Seizure", false),
+            feature("HP:0001250", "Seizure", true),
+        ],
+        "ancestor" => vec![
+            feature("HP:0040064", "Abnormality of limbs", false),
+            feature("HP:0002817", "Abnormality of the upper limb", true),
+        ],
+        _ => vec![
+            feature("HP:0040064", "Abnormality of limbs", true),
+            feature("HP:0002817", "Abnormality of the upper limb", false),
+        ],
+    };
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let context = LinterContext::new(Some(hpo_dir(assets_dir())))
+        .with_contradiction_modes(vec![mode.to_string()]);
+    let mut linter = Phenolint::new(context, vec!["PF008".to_string()]);
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.error.is_none());
+    let findings = res.report.findings();
+    assert_eq!(findings.len(), 1);
+    let report = findings[0].report().unwrap();
+    let rendered = format!("{} {}", report.message(), report.notes().join(" "));
+    assert!(
+        rendered.contains(message_snippet),
+        "'{message_snippet}' not found in '{rendered}'"
+    );
+}
@@ -0,0 +1,65 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::time_element::Element;
+use phenopackets::schema::v2::core::{Age, Disease, OntologyClass, TimeElement};
+use rstest::rstest;
+use serial_test::serial;
+
+fn age(iso8601duration: &str) -> Option<TimeElement> {
+    Some(TimeElement {
+        element: Some(Element::Age(Age {
+            iso8601duration: iso8601duration.to_string(),
+        })),
+    })
+}
+
+fn with_disease_course(onset: &str, resolution: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.diseases = vec![Disease {
+        term: Some(OntologyClass {
+            id: "MONDO:0005027".to_string(),
+            label: "epilepsy".to_string(),
+        }),
+        onset: age(onset),
+        resolution: age(resolution),
+        ..Default::default()
+    }];
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_onset_after_resolution_rule_impossible() {
+    let pp = with_disease_course("P12Y", "P8Y6M");
+
+    let rule_id = "DIS003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["epilepsy", "resolved before its onset"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_onset_after_resolution_rule_consistent() {
+    let pp = with_disease_course("P8Y6M", "P12Y");
+
+    let rule_id = "DIS003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,55 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::core::time_element::Element;
+use phenopackets::schema::v2::core::{Age, OntologyClass, PhenotypicFeature, TimeElement};
+use rstest::rstest;
+use serial_test::serial;
+
+fn age(duration: &str) -> Element {
+    Element::Age(Age {
+        iso8601duration: duration.to_string(),
+    })
+}
+
+#[rstest]
+#[case(age("P30Y"), 0, vec![])]
+#[case(age("30years"), 1, vec!["30years", "ISO8601"])]
+#[case(
+    Element::OntologyClass(OntologyClass {
+        id: "HP:0003581".to_string(),
+        label: "Adult onset".to_string(),
+    }),
+    0,
+    vec![]
+)]
+#[serial]
+fn test_onset_age_format_rule(
+    #[case] onset: Element,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&'static str>,
+) {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "HP:0001250".to_string(),
+            label: "Seizure".to_string(),
+        }),
+        onset: Some(TimeElement {
+            element: Some(onset),
+        }),
+        ..Default::default()
+    }];
+
+    let rule_id = "PF020";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
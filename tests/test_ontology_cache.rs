@@ -0,0 +1,32 @@
+mod common;
+use crate::common::paths::{assets_dir, hpo_dir};
+use phenolint::LinterContext;
+use rstest::rstest;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+#[rstest]
+fn test_same_path_shares_ontology() {
+    let first = LinterContext::new(Some(hpo_dir(assets_dir())))
+        .hpo()
+        .unwrap();
+    let second = LinterContext::new(Some(hpo_dir(assets_dir())))
+        .hpo()
+        .unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+}
+
+#[rstest]
+fn test_different_path_loads_distinct_ontology() {
+    let tmp_dir = TempDir::new().unwrap();
+    let copied_hpo = tmp_dir.path().join("hp.copy.json");
+    std::fs::copy(hpo_dir(assets_dir()), &copied_hpo).unwrap();
+
+    let original = LinterContext::new(Some(hpo_dir(assets_dir())))
+        .hpo()
+        .unwrap();
+    let copy = LinterContext::new(Some(copied_hpo)).hpo().unwrap();
+
+    assert!(!Arc::ptr_eq(&original, &copy));
+}
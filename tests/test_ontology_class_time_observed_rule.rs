@@ -0,0 +1,63 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Measurement;
+use rstest::rstest;
+use serde_json::{Value, json};
+use serial_test::serial;
+
+fn with_time_observed(time_observed: Value) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+
+    let measurement: Measurement = serde_json::from_value(json!({
+        "assay": {"id": "LOINC:2345-7", "label": "Glucose [Mass/volume] in Serum or Plasma"},
+        "value": {
+            "quantity": {
+                "unit": {"id": "UCUM:mg/dL", "label": "milligram per deciliter"},
+                "value": 90.0
+            }
+        },
+        "timeObserved": time_observed
+    }))
+    .unwrap();
+
+    pp.measurements.push(measurement);
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_ontology_class_time_observed_rule() {
+    let pp =
+        with_time_observed(json!({"ontologyClass": {"id": "HP:0003581", "label": "Adult onset"}}));
+
+    let rule_id = "TIME001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["Adult onset", "Expected a timestamp"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_ontology_class_time_observed_rule_timestamp() {
+    let pp = with_time_observed(json!({"timestamp": "2021-03-01T08:30:00Z"}));
+
+    let rule_id = "TIME001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,83 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::error::LintResult;
+use phenolint::phenolint::IGNORE_FINDINGS_REFERENCE_ID;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{ExternalReference, OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn swapped_feature() -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "Seizure".to_string(),
+            label: "HP:0001250".to_string(),
+        }),
+        ..Default::default()
+    }
+}
+
+fn with_ignore_directive(description: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![swapped_feature(), swapped_feature()];
+    pp.meta_data
+        .as_mut()
+        .unwrap()
+        .external_references
+        .push(ExternalReference {
+            id: IGNORE_FINDINGS_REFERENCE_ID.to_string(),
+            reference: String::new(),
+            description: description.to_string(),
+        });
+    pp
+}
+
+fn lint(pp: &Phenopacket) -> LintResult {
+    let phenostr = serde_json::to_string_pretty(pp).unwrap();
+    let mut linter = build_linter(vec!["CURIE003"]);
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+    assert!(res.error.is_none());
+    res
+}
+
+#[rstest]
+#[serial]
+fn test_packet_ignore_directive() {
+    let res = lint(&with_ignore_directive("CURIE003"));
+
+    assert!(res.report.violations().is_empty());
+    assert_eq!(res.report.suppressed().len(), 2);
+    assert!(
+        res.report
+            .suppressed()
+            .iter()
+            .all(|finding| finding.violation().rule_id() == "CURIE003")
+    );
+}
+
+#[rstest]
+#[serial]
+fn test_packet_ignore_directive_scoped_to_pointer() {
+    let res = lint(&with_ignore_directive("CURIE003@/phenotypicFeatures/1"));
+
+    let violations = res.report.violations();
+    assert_eq!(violations.len(), 1);
+    assert!(
+        violations[0]
+            .at()
+            .iter()
+            .all(|ptr| ptr.position().starts_with("/phenotypicFeatures/0"))
+    );
+    assert_eq!(res.report.suppressed().len(), 1);
+}
+
+#[rstest]
+#[serial]
+fn test_packet_ignore_directive_other_rule() {
+    let res = lint(&with_ignore_directive("PF015"));
+
+    assert_eq!(res.report.violations().len(), 2);
+    assert!(res.report.suppressed().is_empty());
+}
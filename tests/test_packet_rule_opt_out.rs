@@ -0,0 +1,68 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::phenolint::DISABLE_RULES_REFERENCE_ID;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{ExternalReference, OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn with_swapped_feature() -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "Seizure".to_string(),
+            label: "HP:0001250".to_string(),
+        }),
+        ..Default::default()
+    }];
+    pp
+}
+
+fn rule_ids_found(pp: &Phenopacket) -> Vec<String> {
+    let phenostr = serde_json::to_string_pretty(pp).unwrap();
+    let mut linter = build_linter(vec!["CURIE003", "PF015"]);
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+    assert!(res.error.is_none());
+
+    res.report
+        .violations()
+        .iter()
+        .map(|violation| violation.rule_id().to_string())
+        .collect()
+}
+
+#[rstest]
+#[serial]
+fn test_packet_rule_opt_out() {
+    let mut pp = with_swapped_feature();
+    pp.meta_data
+        .as_mut()
+        .unwrap()
+        .external_references
+        .push(ExternalReference {
+            id: DISABLE_RULES_REFERENCE_ID.to_string(),
+            reference: String::new(),
+            description: "CURIE003".to_string(),
+        });
+
+    assert!(rule_ids_found(&pp).is_empty());
+}
+
+#[rstest]
+#[serial]
+fn test_packet_rule_opt_out_other_rule() {
+    let mut pp = with_swapped_feature();
+    pp.meta_data
+        .as_mut()
+        .unwrap()
+        .external_references
+        .push(ExternalReference {
+            id: DISABLE_RULES_REFERENCE_ID.to_string(),
+            reference: String::new(),
+            description: "PF015".to_string(),
+        });
+
+    assert_eq!(rule_ids_found(&pp), vec!["CURIE003"]);
+}
@@ -0,0 +1,194 @@
+use phenolint::patches::patch_registration::PatchRegistration;
+use phenolint::report::report_registration::ReportRegistration;
+use phenolint::rules::rule_registration::RuleRegistration;
+use phenolint::rules::traits::RuleMetaData;
+
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::LinterContext;
+use phenolint::diagnostics::LintViolation;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::error::FromContextError;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction;
+use phenolint::patches::patch::Patch;
+use phenolint::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch, RulePatch};
+use phenolint::report::enums::{LabelPriority, ViolationSeverity};
+use phenolint::report::specs::{LabelSpecs, ReportSpecs};
+use phenolint::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use phenolint::rules::traits::LintRule;
+use phenolint::rules::traits::{RuleCheck, RuleFromContext};
+use phenolint::traits::Lint;
+use phenolint::tree::node_repository::Single;
+use phenolint::tree::pointer::Pointer;
+use phenolint::tree::traits::Node;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use rstest::rstest;
+use serde_json::Value;
+
+mod common;
+
+fn id_ptr() -> Pointer {
+    Pointer::at_root().down("id").clone()
+}
+
+fn rename_id(value: &str) -> Vec<Patch> {
+    vec![Patch::new(NonEmptyVec::with_single_entry(
+        PatchInstruction::Add {
+            at: id_ptr(),
+            value: Value::String(value.to_string()),
+        },
+    ))]
+}
+
+fn id_report(full_node: &dyn Node, violation: &LintViolation) -> ReportSpecs {
+    ReportSpecs::from_violation(
+        violation,
+        "Rename id".to_string(),
+        vec![LabelSpecs::new(
+            LabelPriority::Primary,
+            full_node.span_at(violation.first_at()).unwrap().clone(),
+            String::default(),
+        )],
+        vec![],
+    )
+}
+
+/// ### CONF001
+/// ## What it does
+/// Renames the phenopacket id to `warning-id`.
+///
+/// ## Why is this bad?
+/// It is not. It only exists to produce a patch.
+#[register_rule(id = "CONF001")]
+struct WarningRenameRule;
+
+impl RuleFromContext for WarningRenameRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(WarningRenameRule))
+    }
+}
+
+impl RuleCheck for WarningRenameRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, _: Self::Data<'_>) -> Vec<LintViolation> {
+        vec![LintViolation::new(
+            ViolationSeverity::Warning,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_single_entry(id_ptr()),
+        )]
+    }
+}
+
+#[register_patch(id = "CONF001")]
+struct WarningRenamePatch;
+
+impl PatchFromContext for WarningRenamePatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(WarningRenamePatch))
+    }
+}
+
+impl CompilePatches for WarningRenamePatch {
+    fn compile_patches(&self, _: &dyn Node, _: &LintViolation) -> Vec<Patch> {
+        rename_id("warning-id")
+    }
+}
+
+#[register_report(id = "CONF001")]
+struct WarningRenameReport;
+
+impl ReportFromContext for WarningRenameReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(WarningRenameReport))
+    }
+}
+
+impl CompileReport for WarningRenameReport {
+    fn compile_report(&self, full_node: &dyn Node, violation: &LintViolation) -> ReportSpecs {
+        id_report(full_node, violation)
+    }
+}
+
+/// ### CONF002
+/// ## What it does
+/// Renames the phenopacket id to `error-id`.
+///
+/// ## Why is this bad?
+/// It is not. It only exists to produce a patch.
+#[register_rule(id = "CONF002")]
+struct ErrorRenameRule;
+
+impl RuleFromContext for ErrorRenameRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(ErrorRenameRule))
+    }
+}
+
+impl RuleCheck for ErrorRenameRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, _: Self::Data<'_>) -> Vec<LintViolation> {
+        vec![LintViolation::new(
+            ViolationSeverity::Error,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_single_entry(id_ptr()),
+        )]
+    }
+}
+
+#[register_patch(id = "CONF002")]
+struct ErrorRenamePatch;
+
+impl PatchFromContext for ErrorRenamePatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(ErrorRenamePatch))
+    }
+}
+
+impl CompilePatches for ErrorRenamePatch {
+    fn compile_patches(&self, _: &dyn Node, _: &LintViolation) -> Vec<Patch> {
+        rename_id("error-id")
+    }
+}
+
+#[register_report(id = "CONF002")]
+struct ErrorRenameReport;
+
+impl ReportFromContext for ErrorRenameReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(ErrorRenameReport))
+    }
+}
+
+impl CompileReport for ErrorRenameReport {
+    fn compile_report(&self, full_node: &dyn Node, violation: &LintViolation) -> ReportSpecs {
+        id_report(full_node, violation)
+    }
+}
+
+#[rstest]
+fn test_conflicting_patches_are_resolved_deterministically() {
+    let phenostr = serde_json::to_string_pretty(&minimal_valid_phenopacket()).unwrap();
+
+    for _ in 0..5 {
+        let mut linter = build_linter(vec!["CONF001", "CONF002"]);
+        let res = linter.lint(phenostr.as_str(), true, true);
+
+        assert!(res.error.is_none());
+        assert_eq!(res.report().violations().len(), 2);
+
+        let suppressed = res.report().suppressed_patches();
+        assert_eq!(suppressed.len(), 1);
+        assert_eq!(suppressed[0].rule_id(), "CONF001");
+        assert_eq!(suppressed[0].suppressed_by(), "CONF002");
+        assert_eq!(suppressed[0].patch(), &rename_id("warning-id")[0]);
+
+        let Some(PhenopacketData::Text(patched)) = &res.report().patched_phenopacket else {
+            panic!("Phenopacket should have been patched");
+        };
+        let patched: Value = serde_json::from_str(patched).unwrap();
+        assert_eq!(patched["id"], "error-id");
+    }
+}
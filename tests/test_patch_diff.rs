@@ -0,0 +1,98 @@
+use phenolint::diagnostics::{LintFinding, LintReport, LintViolation};
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction;
+use phenolint::patches::patch::Patch;
+use phenolint::report::enums::ViolationSeverity;
+use phenolint::tree::pointer::Pointer;
+use rstest::rstest;
+use serde_json::{Value, json};
+
+fn phenopacket() -> Value {
+    json!({
+        "id": "cohort-1-patient-1",
+        "subject": {"id": "patient-1", "alternateIds": ["PMID:1234-1", ""]},
+        "diseases": [{"term": {"id": "MONDO:0005027", "label": "epilepsy"}}]
+    })
+}
+
+fn report_with(instruction: PatchInstruction) -> LintReport {
+    let mut report = LintReport::new();
+    report.push_finding(LintFinding::new(
+        LintViolation::new(
+            ViolationSeverity::Warning,
+            "CUST001",
+            NonEmptyVec::with_single_entry(Pointer::at_root()),
+        ),
+        vec![Patch::new(NonEmptyVec::with_single_entry(instruction))],
+        None,
+    ));
+    report
+}
+
+#[rstest]
+fn test_patch_diff_add() {
+    let report = report_with(PatchInstruction::Add {
+        at: Pointer::new("/diseases/0"),
+        value: json!({"term": {"id": "MONDO:0007254", "label": "breast cancer"}}),
+    });
+
+    let diff = report.patch_diff(&phenopacket());
+
+    assert_eq!(
+        diff,
+        vec![(
+            Pointer::new("/diseases/0"),
+            None,
+            Some(json!({"term": {"id": "MONDO:0007254", "label": "breast cancer"}})),
+        )]
+    );
+}
+
+#[rstest]
+fn test_patch_diff_remove() {
+    let report = report_with(PatchInstruction::Remove {
+        at: Pointer::new("/subject/alternateIds/1"),
+    });
+
+    let diff = report.patch_diff(&phenopacket());
+
+    assert_eq!(
+        diff,
+        vec![(
+            Pointer::new("/subject/alternateIds/1"),
+            Some(json!("")),
+            None
+        )]
+    );
+}
+
+#[rstest]
+fn test_patch_diff_move() {
+    let report = report_with(PatchInstruction::Move {
+        from: Pointer::new("/subject/alternateIds/0"),
+        to: Pointer::new("/subject/description"),
+    });
+
+    let diff = report.patch_diff(&phenopacket());
+
+    assert_eq!(
+        diff,
+        vec![
+            (
+                Pointer::new("/subject/description"),
+                None,
+                Some(json!("PMID:1234-1")),
+            ),
+            (
+                Pointer::new("/subject/alternateIds/0"),
+                Some(json!("PMID:1234-1")),
+                None,
+            ),
+        ]
+    );
+}
+
+#[rstest]
+fn test_patch_diff_without_patches() {
+    assert!(LintReport::new().patch_diff(&phenopacket()).is_empty());
+}
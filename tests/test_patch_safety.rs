@@ -0,0 +1,232 @@
+use phenolint::patches::patch_registration::PatchRegistration;
+use phenolint::report::report_registration::ReportRegistration;
+use phenolint::rules::rule_registration::RuleRegistration;
+use phenolint::rules::traits::RuleMetaData;
+
+use crate::common::construction::minimal_valid_phenopacket;
+use phenolint::LinterContext;
+use phenolint::diagnostics::LintViolation;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::error::FromContextError;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::{PatchInstruction, PatchSafety};
+use phenolint::patches::patch::Patch;
+use phenolint::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch, RulePatch};
+use phenolint::phenolint::Phenolint;
+use phenolint::report::enums::{LabelPriority, ViolationSeverity};
+use phenolint::report::specs::{LabelSpecs, ReportSpecs};
+use phenolint::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use phenolint::rules::traits::LintRule;
+use phenolint::rules::traits::{RuleCheck, RuleFromContext};
+use phenolint::traits::Lint;
+use phenolint::tree::node_repository::List;
+use phenolint::tree::pointer::Pointer;
+use phenolint::tree::traits::Node;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Individual, OntologyClass, PhenotypicFeature, Sex};
+use rstest::rstest;
+use serial_test::serial;
+
+mod common;
+
+/// ### ORDER001
+/// ## What it does
+/// Moves the second phenotypic feature to the front.
+///
+/// ## Why is this bad?
+/// It is not. It only exists to produce a move.
+#[register_rule(id = "ORDER001")]
+struct SwapFeaturesRule;
+
+impl RuleFromContext for SwapFeaturesRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(SwapFeaturesRule))
+    }
+}
+
+impl RuleCheck for SwapFeaturesRule {
+    type Data<'a> = List<'a, PhenotypicFeature>;
+
+    fn check(&self, data: Self::Data<'_>) -> Vec<LintViolation> {
+        if data.len() != 2 {
+            return vec![];
+        }
+
+        vec![LintViolation::new(
+            ViolationSeverity::Info,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_single_entry(Pointer::new("/phenotypicFeatures/1")),
+        )]
+    }
+}
+
+#[register_patch(id = "ORDER001")]
+struct SwapFeaturesPatch;
+
+impl PatchFromContext for SwapFeaturesPatch {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(SwapFeaturesPatch))
+    }
+}
+
+impl CompilePatches for SwapFeaturesPatch {
+    fn compile_patches(&self, _: &dyn Node, violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Move {
+                from: violation.first_at().clone(),
+                to: Pointer::new("/phenotypicFeatures/0"),
+            },
+        ))]
+    }
+}
+
+#[register_report(id = "ORDER001")]
+struct SwapFeaturesReport;
+
+impl ReportFromContext for SwapFeaturesReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(SwapFeaturesReport))
+    }
+}
+
+impl CompileReport for SwapFeaturesReport {
+    fn compile_report(&self, full_node: &dyn Node, violation: &LintViolation) -> ReportSpecs {
+        ReportSpecs::from_violation(
+            violation,
+            "Move feature to the front".to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(violation.first_at()).unwrap().clone(),
+                String::default(),
+            )],
+            vec![],
+        )
+    }
+}
+
+fn feature(id: &str, label: &str) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        ..Default::default()
+    }
+}
+
+fn female_with_features(features: Vec<PhenotypicFeature>) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        sex: Sex::Female.into(),
+        ..Default::default()
+    });
+    pp.phenotypic_features = features;
+    pp
+}
+
+fn lint_safe_only(rule_id: &str, pp: &Phenopacket) -> Phenopacket {
+    let context = LinterContext::new(None).with_patch_safety(PatchSafety::SafeOnly);
+    let mut linter = Phenolint::new(context, vec![rule_id.to_string()]);
+
+    let res = linter.lint(
+        serde_json::to_string_pretty(pp).unwrap().as_str(),
+        true,
+        true,
+    );
+    assert!(res.error.is_none());
+    assert_eq!(res.report.violations().len(), 1);
+
+    let Some(PhenopacketData::Text(patched)) = &res.report.patched_phenopacket else {
+        panic!("Expected a patched phenopacket");
+    };
+    serde_json::from_str(patched).unwrap()
+}
+
+#[rstest]
+fn test_patch_is_lossy() {
+    let values = serde_json::to_value(female_with_features(vec![
+        feature("HP:0001250", "Seizure"),
+        feature("HP:0001263", "Global developmental delay"),
+    ]))
+    .unwrap();
+    let patch = |instruction| Patch::new(NonEmptyVec::with_single_entry(instruction));
+
+    let remove = patch(PatchInstruction::Remove {
+        at: Pointer::new("/phenotypicFeatures/0"),
+    });
+    let mv = patch(PatchInstruction::Move {
+        from: Pointer::new("/phenotypicFeatures/1"),
+        to: Pointer::new("/phenotypicFeatures/0"),
+    });
+    let replace = patch(PatchInstruction::Replace {
+        at: Pointer::new("/phenotypicFeatures/0/type/label"),
+        value: "Seizures".into(),
+    });
+    let overwriting_add = patch(PatchInstruction::Add {
+        at: Pointer::new("/phenotypicFeatures/0/type/label"),
+        value: "Seizures".into(),
+    });
+    let new_field_add = patch(PatchInstruction::Add {
+        at: Pointer::new("/phenotypicFeatures/0/description"),
+        value: "Seizures".into(),
+    });
+
+    assert!(remove.is_lossy(&values));
+    assert!(!mv.is_lossy(&values));
+    assert!(replace.is_lossy(&values));
+    assert!(overwriting_add.is_lossy(&values));
+    assert!(!new_field_add.is_lossy(&values));
+    assert!(PatchSafety::All.allows(&remove, &values));
+    assert!(!PatchSafety::SafeOnly.allows(&remove, &values));
+    assert!(PatchSafety::SafeOnly.allows(&mv, &values));
+}
+
+#[rstest]
+#[serial]
+fn test_safe_only_excludes_skipped_patches_from_export() {
+    let pp = female_with_features(vec![
+        feature("HP:0001250", "Seizure"),
+        feature("PATO:0000383", "female"),
+    ]);
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let context = LinterContext::new(None).with_patch_safety(PatchSafety::SafeOnly);
+    let mut linter = Phenolint::new(context, vec!["SUBJ003".to_string()]);
+    let res = linter.lint(phenostr.as_str(), true, true);
+    assert!(res.error.is_none());
+
+    assert_eq!(res.report.skipped_patches().len(), 1);
+    assert_eq!(res.report.skipped_patches()[0].rule_id(), "SUBJ003");
+
+    let original = serde_json::to_value(&pp).unwrap();
+    assert!(res.report.to_json_patch(&original).unwrap().0.is_empty());
+    assert!(res.report.patch_diff(&original).is_empty());
+}
+
+#[rstest]
+#[serial]
+fn test_safe_only_skips_remove() {
+    let pp = female_with_features(vec![
+        feature("HP:0001250", "Seizure"),
+        feature("PATO:0000383", "female"),
+    ]);
+
+    assert_eq!(lint_safe_only("SUBJ003", &pp), pp);
+}
+
+#[rstest]
+#[serial]
+fn test_safe_only_applies_move() {
+    let pp = female_with_features(vec![
+        feature("HP:0001250", "Seizure"),
+        feature("HP:0001263", "Global developmental delay"),
+    ]);
+    let expected = female_with_features(vec![
+        feature("HP:0001263", "Global developmental delay"),
+        feature("HP:0001250", "Seizure"),
+    ]);
+
+    assert_eq!(lint_safe_only("ORDER001", &pp), expected);
+}
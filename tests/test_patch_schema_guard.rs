@@ -0,0 +1,175 @@
+use phenolint::LinterContext;
+use phenolint::diagnostics::LintViolation;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::error::FromContextError;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction;
+use phenolint::patches::patch::Patch;
+use phenolint::patches::patch_registration::PatchRegistration;
+use phenolint::patches::traits::{CompilePatches, PatchFromContext, RegisterablePatch, RulePatch};
+use phenolint::report::enums::{LabelPriority, ViolationSeverity};
+use phenolint::report::report_registration::ReportRegistration;
+use phenolint::report::specs::{LabelSpecs, ReportSpecs};
+use phenolint::report::traits::{CompileReport, RegisterableReport, ReportFromContext, RuleReport};
+use phenolint::rules::rule_registration::RuleRegistration;
+use phenolint::rules::traits::RuleMetaData;
+use phenolint::rules::traits::{LintRule, RuleCheck, RuleFromContext};
+use phenolint::traits::Lint;
+use phenolint::tree::node_repository::Single;
+use phenolint::tree::pointer::Pointer;
+use phenolint::tree::traits::Node;
+use phenolint_macros::{register_patch, register_report, register_rule};
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Individual;
+use rstest::rstest;
+use serde_json::Value;
+use serial_test::serial;
+
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+
+mod common;
+
+/// ### CUST002
+/// ## What it does
+/// Proposes to remove the required `id` of every phenopacket.
+///
+/// ## Why is this bad?
+/// It is not. The patch is deliberately broken to test that autofix keeps phenopackets valid.
+#[register_rule(id = "CUST002")]
+struct RemoveIdRule;
+
+impl RuleFromContext for RemoveIdRule {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn LintRule>, FromContextError> {
+        Ok(Box::new(RemoveIdRule))
+    }
+}
+
+impl RuleCheck for RemoveIdRule {
+    type Data<'a> = Single<'a, Phenopacket>;
+
+    fn check(&self, _: Self::Data<'_>) -> Vec<LintViolation> {
+        vec![LintViolation::new(
+            ViolationSeverity::Info,
+            LintRule::rule_id(self),
+            NonEmptyVec::with_single_entry(Pointer::new("/id")),
+        )]
+    }
+}
+
+#[register_report(id = "CUST002")]
+struct RemoveIdReport;
+
+impl ReportFromContext for RemoveIdReport {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterableReport>, FromContextError> {
+        Ok(Box::new(RemoveIdReport))
+    }
+}
+
+impl CompileReport for RemoveIdReport {
+    fn compile_report(&self, full_node: &dyn Node, violation: &LintViolation) -> ReportSpecs {
+        ReportSpecs::from_violation(
+            violation,
+            "Remove the phenopacket id".to_string(),
+            vec![LabelSpecs::new(
+                LabelPriority::Primary,
+                full_node.span_at(violation.first_at()).unwrap().clone(),
+                String::default(),
+            )],
+            vec![],
+        )
+    }
+}
+
+#[register_patch(id = "CUST002")]
+struct RemoveIdPatchCompiler;
+
+impl PatchFromContext for RemoveIdPatchCompiler {
+    fn from_context(_: &LinterContext) -> Result<Box<dyn RegisterablePatch>, FromContextError> {
+        Ok(Box::new(RemoveIdPatchCompiler))
+    }
+}
+
+impl CompilePatches for RemoveIdPatchCompiler {
+    fn compile_patches(&self, _: &dyn Node, violation: &LintViolation) -> Vec<Patch> {
+        vec![Patch::new(NonEmptyVec::with_single_entry(
+            PatchInstruction::Remove {
+                at: violation.first_at().clone(),
+            },
+        ))]
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_schema_breaking_patch_is_reverted() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        alternate_ids: vec!["".to_string(), "PMID:1234-1".to_string()],
+        ..Default::default()
+    });
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["CUST002", "SUBJ002"]);
+    let res = linter.lint(phenostr.as_str(), true, true);
+    assert!(res.error.is_none());
+
+    let reverted = res.report.reverted_patches();
+    assert_eq!(reverted.len(), 1);
+    assert_eq!(reverted[0].rule_id(), "CUST002");
+    assert!(reverted[0].reason().contains("id"));
+
+    let Some(PhenopacketData::Text(patched)) = res.report.patched_phenopacket.as_ref() else {
+        panic!("Expected a patched phenopacket");
+    };
+    let patched: Value = serde_json::from_str(patched).unwrap();
+    assert_eq!(patched["id"], "cohort-1-patient-1");
+    assert_eq!(
+        patched["subject"]["alternateIds"],
+        serde_json::json!(["PMID:1234-1"])
+    );
+
+    let original: Value = serde_json::from_str(&phenostr).unwrap();
+    let json_patch = res.report.to_json_patch(&original).unwrap();
+    let operations = serde_json::to_value(&json_patch).unwrap();
+    assert!(
+        operations
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|operation| operation["path"] != "/id")
+    );
+}
+
+#[rstest]
+#[serial]
+fn test_stringified_booleans_survive_reverted_patch() {
+    let mut pp = serde_json::to_value(minimal_valid_phenopacket()).unwrap();
+    pp["phenotypicFeatures"] = serde_json::json!([
+        {"type": {"id": "HP:0001250", "label": "Seizure"}, "excluded": "true"},
+        {"type": {"id": "HP:0001263", "label": "Global developmental delay"}, "excluded": "false"}
+    ]);
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["CUST002", "STR003"]);
+    let res = linter.lint(phenostr.as_str(), true, true);
+    assert!(res.error.is_none());
+
+    let reverted = res.report.reverted_patches();
+    assert_eq!(reverted.len(), 1);
+    assert_eq!(reverted[0].rule_id(), "CUST002");
+
+    let Some(PhenopacketData::Text(patched)) = res.report.patched_phenopacket.as_ref() else {
+        panic!("Expected a patched phenopacket");
+    };
+    let patched: Value = serde_json::from_str(patched).unwrap();
+    assert_eq!(patched["id"], "cohort-1-patient-1");
+    assert_eq!(
+        patched["phenotypicFeatures"][0]["excluded"],
+        Value::Bool(true)
+    );
+    assert_eq!(
+        patched["phenotypicFeatures"][1]["excluded"],
+        Value::Bool(false)
+    );
+}
@@ -0,0 +1,99 @@
+mod common;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::paths::{assets_dir, hpo_dir};
+use phenolint::error::LinterError;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Diagnosis, Interpretation, OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+/// Violates INTER001, because the diagnosed disease is missing from the diseases,
+/// and CURIE003, because id and label of the feature are swapped.
+fn phenopacket() -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.interpretations.push(Interpretation {
+        id: "interpretation-1".to_string(),
+        diagnosis: Some(Diagnosis {
+            disease: Some(OntologyClass {
+                id: "MONDO:0000252".to_string(),
+                label: "inflammatory diarrhea".to_string(),
+            }),
+            genomic_interpretations: vec![],
+        }),
+        ..Default::default()
+    });
+    pp.phenotypic_features.push(PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "Seizure".to_string(),
+            label: "HP:0001250".to_string(),
+        }),
+        ..Default::default()
+    });
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_builder_only_runs_enabled_rules() {
+    let phenostr = serde_json::to_string_pretty(&phenopacket()).unwrap();
+
+    let mut linter = Phenolint::builder()
+        .with_rule("INTER001")
+        .with_hpo_path(hpo_dir(assets_dir()))
+        .build();
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.error.is_none());
+    let violations = res.report.violations();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].rule_id(), "INTER001");
+}
+
+#[rstest]
+#[serial]
+fn test_builder_without_schema_validation() {
+    let mut pp = phenopacket();
+    pp.meta_data = None;
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut validating = Phenolint::builder().with_rules(["INTER001"]).build();
+    let res = validating.lint(phenostr.as_str(), false, true);
+    assert!(matches!(
+        res.error,
+        Some(LinterError::InvalidPhenopacket { .. })
+    ));
+
+    let mut lenient = Phenolint::builder()
+        .with_rules(["INTER001"])
+        .with_schema_validation(false)
+        .build();
+    let res = lenient.lint(phenostr.as_str(), false, true);
+    assert!(res.error.is_none());
+    assert_eq!(res.report.violations().len(), 1);
+}
+
+#[rstest]
+#[serial]
+fn test_builder_without_schema_validation_renders_incomplete_phenopackets() {
+    let mut pp = phenopacket();
+    pp.meta_data = None;
+    let mut phenopacket = serde_json::to_value(&pp).unwrap();
+    phenopacket.as_object_mut().unwrap().remove("id");
+    let phenostr = serde_json::to_string_pretty(&phenopacket).unwrap();
+
+    let mut lenient = Phenolint::builder()
+        .with_rules(["INTER001", "INTER002"])
+        .with_schema_validation(false)
+        .build();
+    let res = lenient.lint(phenostr.as_str(), false, false);
+
+    assert!(res.error.is_none());
+    assert!(
+        res.report
+            .violations()
+            .iter()
+            .any(|violation| violation.rule_id() == "INTER002")
+    );
+}
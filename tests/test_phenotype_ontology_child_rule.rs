@@ -0,0 +1,71 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn feature(id: &str, label: &str) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_phenotype_ontology_child_rule_non_phenotype() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![feature("HP:0012823", "Clinical modifier")];
+
+    let rule_id = "PF001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["HP:0012823", "not a phenotypic abnormality"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_phenotype_ontology_child_rule_mondo_id() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![feature("MONDO:0000252", "inflammatory diarrhea")];
+
+    let rule_id = "PF001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["MONDO:0000252", "no ontology to validate against"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_phenotype_ontology_child_rule_valid() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![feature("HP:0001250", "Seizure")];
+
+    let rule_id = "PF001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,61 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::interpretation::ProgressStatus;
+use phenopackets::schema::v2::core::{Diagnosis, Interpretation, OntologyClass};
+use rstest::rstest;
+use serial_test::serial;
+
+fn with_interpretation(progress_status: ProgressStatus, with_diagnosis: bool) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.interpretations.push(Interpretation {
+        id: "interpretation-1".to_string(),
+        progress_status: progress_status.into(),
+        diagnosis: with_diagnosis.then(|| Diagnosis {
+            disease: Some(OntologyClass {
+                id: "MONDO:0000252".to_string(),
+                label: "inflammatory diarrhea".to_string(),
+            }),
+            genomic_interpretations: vec![],
+        }),
+        ..Default::default()
+    });
+    pp
+}
+
+#[rstest]
+#[case(with_interpretation(ProgressStatus::Solved, false), vec!["SOLVED", "no diagnosis"])]
+#[case(with_interpretation(ProgressStatus::Unsolved, true), vec!["UNSOLVED", "has a diagnosis"])]
+#[serial]
+fn test_progress_status_rule(#[case] pp: Phenopacket, #[case] message_snippets: Vec<&str>) {
+    let rule_id = "INTER004";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[case(with_interpretation(ProgressStatus::Solved, true))]
+#[case(with_interpretation(ProgressStatus::Unsolved, false))]
+#[case(with_interpretation(ProgressStatus::InProgress, false))]
+#[serial]
+fn test_progress_status_rule_consistent(#[case] pp: Phenopacket) {
+    let rule_id = "INTER004";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
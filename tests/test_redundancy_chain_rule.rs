@@ -0,0 +1,99 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Remove;
+use phenolint::patches::patch::Patch;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn feature(id: &str, label: &str, excluded: bool) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        excluded,
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_redundancy_chain_rule_deep_chain() {
+    let humeral_metaphysis = feature(
+        "HP:0003907",
+        "Abnormal humeral metaphysis morphology",
+        false,
+    );
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![
+        feature("HP:0040064", "Abnormality of limbs", false),
+        feature("HP:0001250", "Seizure", false),
+        feature("HP:0002817", "Abnormality of the upper limb", true),
+        humeral_metaphysis.clone(),
+        feature(
+            "HP:0009809",
+            "Abnormal upper limb metaphysis morphology",
+            false,
+        ),
+    ];
+
+    let mut patched = pp.clone();
+    patched.phenotypic_features = vec![feature("HP:0001250", "Seizure", false), humeral_metaphysis];
+
+    let rule_id = "PF016";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&patched).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_rest(
+            Remove {
+                at: Pointer::new("/phenotypicFeatures/0"),
+            },
+            vec![
+                Remove {
+                    at: Pointer::new("/phenotypicFeatures/2"),
+                },
+                Remove {
+                    at: Pointer::new("/phenotypicFeatures/4"),
+                },
+            ],
+        ))],
+        message_snippets: vec!["Abnormal humeral metaphysis morphology", "interleave"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_redundancy_chain_rule_no_interleaving() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![
+        feature("HP:0040064", "Abnormality of limbs", false),
+        feature("HP:0002817", "Abnormality of the upper limb", false),
+        feature(
+            "HP:0003907",
+            "Abnormal humeral metaphysis morphology",
+            false,
+        ),
+    ];
+
+    let rule_id = "PF016";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
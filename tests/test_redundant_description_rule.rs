@@ -0,0 +1,66 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Remove;
+use phenolint::patches::patch::Patch;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn seizure(description: &str) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "HP:0001250".to_string(),
+            label: "Seizure".to_string(),
+        }),
+        description: description.to_string(),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_redundant_description_rule() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![seizure(" seizure ")];
+
+    let mut patched = pp.clone();
+    patched.phenotypic_features = vec![seizure("")];
+
+    let rule_id = "PF015";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&patched).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Remove {
+            at: Pointer::new("/phenotypicFeatures/0/description"),
+        }))],
+        message_snippets: vec!["Seizure", "repeats the label"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_redundant_description_rule_informative() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![seizure("Generalized tonic-clonic seizures at night")];
+
+    let rule_id = "PF015";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
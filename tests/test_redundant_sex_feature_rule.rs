@@ -0,0 +1,76 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Remove;
+use phenolint::patches::patch::Patch;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Individual, OntologyClass, PhenotypicFeature, Sex};
+use rstest::rstest;
+use serial_test::serial;
+
+fn feature(id: &str, label: &str) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        ..Default::default()
+    }
+}
+
+fn female_with_features(features: Vec<PhenotypicFeature>) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        sex: Sex::Female.into(),
+        ..Default::default()
+    });
+    pp.phenotypic_features = features;
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_redundant_sex_feature_rule() {
+    let pp = female_with_features(vec![
+        feature("HP:0001250", "Seizure"),
+        feature("PATO:0000383", "female"),
+    ]);
+    let patched = female_with_features(vec![feature("HP:0001250", "Seizure")]);
+
+    let rule_id = "SUBJ003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&patched).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Remove {
+            at: Pointer::new("/phenotypicFeatures/1"),
+        }))],
+        message_snippets: vec!["female", "restates the sex"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_redundant_sex_feature_rule_not_redundant() {
+    let pp = female_with_features(vec![feature("HP:0001250", "Seizure")]);
+
+    let rule_id = "SUBJ003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
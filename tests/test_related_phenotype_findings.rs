@@ -0,0 +1,68 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+
+fn feature(id: &str, label: &str) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+fn test_duplicated_observed_ancestor_is_reported_together() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![
+        feature("HP:0040064", "Abnormality of limbs"),
+        feature("HP:0001250", "Seizure"),
+        feature("HP:0002817", "Abnormality of the upper limb"),
+        feature("HP:0040064", "Abnormality of limbs"),
+    ];
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["PF006", "PF007"]);
+    let res = linter.lint(phenostr.as_str(), false, true);
+    let findings = res.report().findings();
+
+    let rule_ids: Vec<&str> = findings
+        .iter()
+        .map(|finding| finding.violation().rule_id())
+        .collect();
+    assert_eq!(rule_ids.len(), 3);
+    assert_eq!(rule_ids.iter().filter(|id| **id == "PF006").count(), 1);
+    assert_eq!(rule_ids.iter().filter(|id| **id == "PF007").count(), 2);
+
+    let duplicate_idx = rule_ids.iter().position(|id| *id == "PF006").unwrap();
+    let duplicate_ptrs = findings[duplicate_idx].violation().at();
+    let neighbours = [duplicate_idx.checked_sub(1), Some(duplicate_idx + 1)];
+    assert!(neighbours.into_iter().flatten().any(|idx| {
+        findings.get(idx).is_some_and(|finding| {
+            finding.violation().rule_id() == "PF007"
+                && finding
+                    .violation()
+                    .at()
+                    .iter()
+                    .any(|ptr| duplicate_ptrs.contains(ptr))
+        })
+    }));
+
+    for finding in findings
+        .iter()
+        .filter(|finding| finding.violation().rule_id() == "PF007")
+    {
+        let report = finding.report().unwrap();
+        assert!(report.message().contains("Abnormality of limbs"));
+        assert!(report.message().contains("Abnormality of the upper limb"));
+        assert!(
+            report
+                .notes()
+                .iter()
+                .any(|note| note.contains("annotated 2 times") && note.contains("PF006"))
+        );
+    }
+}
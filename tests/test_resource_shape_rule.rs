@@ -0,0 +1,52 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use crate::common::test_functions::run_rule_test;
+use phenolint::error::LinterError;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::Resource;
+use rstest::rstest;
+use serde_json::json;
+use serial_test::serial;
+
+#[rstest]
+#[serial]
+fn test_resource_shape_rule_proper_resource() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.meta_data.as_mut().unwrap().resources.push(Resource {
+        id: "hp".to_string(),
+        name: "human phenotype ontology".to_string(),
+        url: "http://purl.obolibrary.org/obo/hp.owl".to_string(),
+        version: "2024-04-26".to_string(),
+        namespace_prefix: "HP".to_string(),
+        iri_prefix: "http://purl.obolibrary.org/obo/HP_".to_string(),
+    });
+
+    let rule_id = "RES001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_resource_shape_rule_misplaced_ontology_class() {
+    let mut pp = serde_json::to_value(minimal_valid_phenopacket()).unwrap();
+    pp["metaData"]["resources"] = json!([{"id": "HP:0001250", "label": "Seizure"}]);
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["RES001"]);
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    // The schema validation already rejects the entry before any rule runs.
+    assert!(matches!(
+        res.error,
+        Some(LinterError::InvalidPhenopacket { ref path, .. }) if path.starts_with("/metaData/resources/0")
+    ));
+}
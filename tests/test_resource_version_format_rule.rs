@@ -0,0 +1,68 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::LinterContext;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::Resource;
+use rstest::rstest;
+use serial_test::serial;
+use std::collections::HashMap;
+
+fn with_resource(namespace_prefix: &str, version: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.meta_data.as_mut().unwrap().resources = vec![Resource {
+        id: namespace_prefix.to_lowercase(),
+        name: format!("{namespace_prefix} ontology"),
+        url: format!(
+            "http://purl.obolibrary.org/obo/{}.owl",
+            namespace_prefix.to_lowercase()
+        ),
+        version: version.to_string(),
+        namespace_prefix: namespace_prefix.to_string(),
+        iri_prefix: format!("http://purl.obolibrary.org/obo/{namespace_prefix}_"),
+    }];
+    pp
+}
+
+#[rstest]
+#[case(with_resource("HP", "latest"), 1, vec!["'latest'", "'HP'", "Expected a release date"])]
+#[case(with_resource("HP", "2023-10-09"), 0, vec![])]
+#[case(with_resource("HP", "v2023-10-09"), 0, vec![])]
+#[serial]
+fn test_resource_version_format_rule(
+    #[case] pp: Phenopacket,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let rule_id = "RES003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_resource_version_format_rule_configured_formats() {
+    let pp = with_resource("HP", "release-2023");
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let context = LinterContext::new(None).with_resource_version_formats(HashMap::from([(
+        "HP".to_string(),
+        vec![r"^release-\d{4}$".to_string()],
+    )]));
+    let mut linter = Phenolint::new(context, vec!["RES003".to_string()]);
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.error.is_none());
+    assert!(res.report.violations().is_empty());
+}
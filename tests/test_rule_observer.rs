@@ -0,0 +1,61 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::traits::{Lint, RuleObserver};
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Default)]
+struct RecordingObserver {
+    started: Arc<Mutex<Vec<String>>>,
+    ended: Arc<Mutex<Vec<(String, usize)>>>,
+}
+
+impl RuleObserver for RecordingObserver {
+    fn on_rule_start(&self, rule_id: &str) {
+        self.started.lock().unwrap().push(rule_id.to_string());
+    }
+
+    fn on_rule_end(&self, rule_id: &str, n_violations: usize, _: Duration) {
+        self.ended
+            .lock()
+            .unwrap()
+            .push((rule_id.to_string(), n_violations));
+    }
+}
+
+#[rstest]
+fn test_rule_observer() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = (0..2)
+        .map(|i| PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: format!("invalid:{i}"),
+                label: "some pf".to_string(),
+            }),
+            ..Default::default()
+        })
+        .collect();
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let observer = RecordingObserver::default();
+    let started = observer.started.clone();
+    let ended = observer.ended.clone();
+
+    let mut linter = build_linter(vec!["CURIE001", "INTER001"]);
+    linter.set_observer(Box::new(observer));
+    let res = linter.lint(phenostr.as_str(), false, true);
+    assert!(res.error.is_none());
+
+    let mut started = started.lock().unwrap().clone();
+    started.sort();
+    assert_eq!(started, vec!["CURIE001", "INTER001"]);
+
+    let mut ended = ended.lock().unwrap().clone();
+    ended.sort();
+    assert_eq!(
+        ended,
+        vec![("CURIE001".to_string(), 2), ("INTER001".to_string(), 0)]
+    );
+}
@@ -0,0 +1,41 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::core::{Disease, OntologyClass};
+use rstest::rstest;
+use serial_test::serial;
+
+#[rstest]
+#[serial]
+fn test_rules_run_on_ontology_class() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.diseases = vec![Disease {
+        term: Some(OntologyClass {
+            id: "MONDO:0007947".to_string(),
+            label: "Marfan syndrome".to_string(),
+        }),
+        ..Default::default()
+    }];
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["CURIE001", "INTER002", "PF006", "SUBJ002", "DIS001"]);
+
+    assert_eq!(
+        linter
+            .rules_run_on(&phenostr, &Pointer::new("/diseases/0/term"))
+            .unwrap(),
+        vec!["CURIE001", "INTER002"]
+    );
+    assert_eq!(
+        linter
+            .rules_run_on(&phenostr, &Pointer::new("/diseases/0"))
+            .unwrap(),
+        vec!["DIS001"]
+    );
+    assert!(
+        linter
+            .rules_run_on(&phenostr, &Pointer::new("/diseases/1"))
+            .unwrap()
+            .is_empty()
+    );
+}
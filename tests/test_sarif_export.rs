@@ -0,0 +1,186 @@
+#![recursion_limit = "256"]
+
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::{Individual, OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serde_json::{Value, json};
+use serial_test::serial;
+use std::collections::HashSet;
+
+/// The parts of the SARIF 2.1.0 schema, that the export has to fulfil.
+fn sarif_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["version", "runs"],
+        "properties": {
+            "version": {"const": "2.1.0"},
+            "runs": {
+                "type": "array",
+                "minItems": 1,
+                "items": {
+                    "type": "object",
+                    "required": ["tool"],
+                    "properties": {
+                        "tool": {
+                            "type": "object",
+                            "required": ["driver"],
+                            "properties": {
+                                "driver": {
+                                    "type": "object",
+                                    "required": ["name"],
+                                    "properties": {
+                                        "name": {"type": "string"},
+                                        "rules": {
+                                            "type": "array",
+                                            "items": {
+                                                "type": "object",
+                                                "required": ["id"],
+                                                "properties": {"id": {"type": "string"}}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        "results": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "required": ["message"],
+                                "properties": {
+                                    "ruleId": {"type": "string"},
+                                    "ruleIndex": {"type": "integer", "minimum": 0},
+                                    "level": {"enum": ["none", "note", "warning", "error"]},
+                                    "message": {
+                                        "type": "object",
+                                        "required": ["text"],
+                                        "properties": {"text": {"type": "string"}}
+                                    },
+                                    "locations": {
+                                        "type": "array",
+                                        "items": {
+                                            "type": "object",
+                                            "properties": {
+                                                "physicalLocation": {
+                                                    "type": "object",
+                                                    "properties": {
+                                                        "region": {
+                                                            "type": "object",
+                                                            "properties": {
+                                                                "byteOffset": {"type": "integer", "minimum": 0},
+                                                                "byteLength": {"type": "integer", "minimum": 0},
+                                                                "startLine": {"type": "integer", "minimum": 1},
+                                                                "startColumn": {"type": "integer", "minimum": 1}
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn assert_valid_sarif(sarif: &Value) {
+    let validator = jsonschema::validator_for(&sarif_schema()).unwrap();
+    let errors: Vec<String> = validator
+        .iter_errors(sarif)
+        .map(|error| error.to_string())
+        .collect();
+
+    assert!(errors.is_empty(), "Invalid SARIF: {errors:?}");
+}
+
+#[rstest]
+#[serial]
+fn test_sarif_export_empty_report() {
+    let phenostr = serde_json::to_string_pretty(&minimal_valid_phenopacket()).unwrap();
+
+    let mut linter = build_linter(vec!["CURIE003", "SUBJ002"]);
+    let res = linter.lint(phenostr.as_str(), false, true);
+    assert!(res.error.is_none());
+
+    let sarif = res.report.to_sarif(&phenostr);
+
+    assert_valid_sarif(&sarif);
+    assert_eq!(sarif["runs"][0]["results"], json!([]));
+    assert_eq!(sarif["runs"][0]["tool"]["driver"]["rules"], json!([]));
+}
+
+#[rstest]
+#[serial]
+fn test_sarif_export_multiple_violations() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        alternate_ids: vec!["".to_string(), "PMID:1234-1".to_string()],
+        ..Default::default()
+    });
+    pp.phenotypic_features = vec![
+        PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: "Seizure".to_string(),
+                label: "HP:0001250".to_string(),
+            }),
+            ..Default::default()
+        },
+        PhenotypicFeature {
+            r#type: Some(OntologyClass {
+                id: "Ataxia".to_string(),
+                label: "HP:0001251".to_string(),
+            }),
+            ..Default::default()
+        },
+    ];
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["CURIE003", "SUBJ002"]);
+    let res = linter.lint(phenostr.as_str(), false, true);
+    assert!(res.error.is_none());
+
+    let sarif = res.report.to_sarif(&phenostr);
+    assert_valid_sarif(&sarif);
+
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    let rules = sarif["runs"][0]["tool"]["driver"]["rules"]
+        .as_array()
+        .unwrap();
+    assert_eq!(results.len(), res.report.violations().len());
+    assert!(results.len() >= 3);
+
+    let rule_ids: Vec<&str> = rules
+        .iter()
+        .map(|rule| rule["id"].as_str().unwrap())
+        .collect();
+    let unique_rule_ids: HashSet<&str> = rule_ids.iter().copied().collect();
+    assert_eq!(rule_ids.len(), unique_rule_ids.len());
+    assert_eq!(unique_rule_ids, HashSet::from(["CURIE003", "SUBJ002"]));
+
+    for result in results {
+        let rule_index = result["ruleIndex"].as_u64().unwrap() as usize;
+        assert_eq!(result["ruleId"], rules[rule_index]["id"]);
+
+        let region = &result["locations"][0]["physicalLocation"]["region"];
+        let start = region["byteOffset"].as_u64().unwrap() as usize;
+        let length = region["byteLength"].as_u64().unwrap() as usize;
+        assert!(start + length <= phenostr.len());
+    }
+
+    let swapped = results
+        .iter()
+        .find(|result| result["ruleId"] == "CURIE003")
+        .unwrap();
+    let region = &swapped["locations"][0]["physicalLocation"]["region"];
+    let start = region["byteOffset"].as_u64().unwrap() as usize;
+    let length = region["byteLength"].as_u64().unwrap() as usize;
+    assert!(phenostr[start..start + length].contains("Seizure"));
+}
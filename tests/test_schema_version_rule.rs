@@ -0,0 +1,89 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::LinterContext;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Replace;
+use phenolint::patches::patch::Patch;
+use phenolint::phenolint::Phenolint;
+use phenolint::traits::Lint;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::Phenopacket;
+use rstest::rstest;
+use serde_json::Value;
+use serial_test::serial;
+
+fn with_schema_version(version: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.meta_data.as_mut().unwrap().phenopacket_schema_version = version.to_string();
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_schema_version_rule_v1() {
+    let rule_id = "META003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&with_schema_version("2.0.2")).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Replace {
+            at: Pointer::new("/metaData/phenopacketSchemaVersion"),
+            value: Value::String("2.0.2".to_string()),
+        }))],
+        message_snippets: vec!["'1.0.0'", "2.x"],
+    };
+
+    run_rule_test(rule_id, &with_schema_version("1.0.0"), assert_settings);
+}
+
+#[rstest]
+#[case("2.0.2")]
+#[case("2")]
+#[serial]
+fn test_schema_version_rule_v2(#[case] version: &str) {
+    let rule_id = "META003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &with_schema_version(version), assert_settings);
+}
+
+/// The schema requires a version, so phenopackets without one are only linted without validation.
+#[rstest]
+fn test_schema_version_rule_missing() {
+    let mut pp = serde_json::to_value(minimal_valid_phenopacket()).unwrap();
+    pp["metaData"]
+        .as_object_mut()
+        .unwrap()
+        .remove("phenopacketSchemaVersion");
+
+    let context = LinterContext::default()
+        .with_schema_validation(false)
+        .with_default_schema_version("2.0.0");
+    let mut linter = Phenolint::new(context, vec!["META003".to_string()]);
+    let res = linter.lint(pp.to_string().as_str(), true, true);
+
+    assert!(res.error.is_none());
+    let violations = res.report().violations();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].first_at().position(), "/metaData");
+
+    let Some(PhenopacketData::Text(patched)) = &res.report().patched_phenopacket else {
+        panic!("Expected a patched phenopacket");
+    };
+    let patched: Value = serde_json::from_str(patched).unwrap();
+    assert_eq!(
+        patched.pointer("/metaData/phenopacketSchemaVersion"),
+        Some(&Value::String("2.0.0".to_string()))
+    );
+}
@@ -0,0 +1,73 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Remove;
+use phenolint::patches::patch::Patch;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn oc(id: &str, label: &str) -> OntologyClass {
+    OntologyClass {
+        id: id.to_string(),
+        label: label.to_string(),
+    }
+}
+
+fn seizure(modifiers: Vec<OntologyClass>) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(oc("HP:0001250", "Seizure")),
+        modifiers,
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_self_modifier_rule() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![seizure(vec![
+        oc("HP:0031796", "Recurrent"),
+        oc("HP:0001250", "Seizure"),
+    ])];
+
+    let mut patched = pp.clone();
+    patched.phenotypic_features = vec![seizure(vec![oc("HP:0031796", "Recurrent")])];
+
+    let rule_id = "PF010";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&patched).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Remove {
+            at: Pointer::new("/phenotypicFeatures/0/modifiers/1"),
+        }))],
+        message_snippets: vec!["HP:0001250", "modified by itself"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_self_modifier_rule_no_violation() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![seizure(vec![oc("HP:0031796", "Recurrent")])];
+
+    let rule_id = "PF010";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
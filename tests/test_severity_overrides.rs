@@ -0,0 +1,56 @@
+mod common;
+use crate::common::construction::minimal_valid_phenopacket;
+use phenolint::LinterContext;
+use phenolint::phenolint::Phenolint;
+use phenolint::report::enums::ViolationSeverity;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::{Diagnosis, Interpretation, OntologyClass};
+use rstest::rstest;
+use serial_test::serial;
+use std::collections::HashMap;
+
+fn phenostr_with_unlisted_diagnosis() -> String {
+    let mut pp = minimal_valid_phenopacket();
+    pp.interpretations.push(Interpretation {
+        id: "interpretation-1".to_string(),
+        diagnosis: Some(Diagnosis {
+            disease: Some(OntologyClass {
+                id: "MONDO:0000252".to_string(),
+                label: "inflammatory diarrhea".to_string(),
+            }),
+            genomic_interpretations: vec![],
+        }),
+        ..Default::default()
+    });
+    serde_json::to_string_pretty(&pp).unwrap()
+}
+
+#[rstest]
+#[case(HashMap::new(), ViolationSeverity::Warning)]
+#[case(HashMap::from([("INTER001".to_string(), ViolationSeverity::Error)]), ViolationSeverity::Error)]
+#[case(HashMap::from([("inter001".to_string(), ViolationSeverity::Info)]), ViolationSeverity::Info)]
+#[serial]
+fn test_severity_override(
+    #[case] severity_overrides: HashMap<String, ViolationSeverity>,
+    #[case] expected: ViolationSeverity,
+) {
+    let context = LinterContext::new(None).with_severity_overrides(severity_overrides);
+    let mut linter = Phenolint::new(context, vec!["INTER001".to_string()]);
+
+    let res = linter.lint(phenostr_with_unlisted_diagnosis().as_str(), true, true);
+
+    assert!(res.error.is_none());
+    let findings = res.report.findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].violation().severity(), &expected);
+    assert_eq!(findings[0].report().unwrap().severity(), &expected);
+    // The rule still compiles its patch, as for its own severity.
+    assert!(!findings[0].patch().is_empty());
+    assert!(
+        findings[0]
+            .report()
+            .unwrap()
+            .message()
+            .contains("not present in diseases section")
+    );
+}
@@ -0,0 +1,67 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Individual, KaryotypicSex, Sex};
+use rstest::rstest;
+use serial_test::serial;
+
+fn subject(sex: Sex, karyotypic_sex: KaryotypicSex) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        sex: sex.into(),
+        karyotypic_sex: karyotypic_sex.into(),
+        ..Default::default()
+    });
+    pp
+}
+
+#[rstest]
+#[case(Sex::Female, KaryotypicSex::Xy, vec!["FEMALE", "XY"])]
+#[case(Sex::Female, KaryotypicSex::Xxy, vec!["FEMALE", "XXY"])]
+#[case(Sex::Female, KaryotypicSex::Xyy, vec!["FEMALE", "XYY"])]
+#[case(Sex::Male, KaryotypicSex::Xx, vec!["MALE", "XX"])]
+#[case(Sex::Male, KaryotypicSex::Xo, vec!["MALE", "XO"])]
+#[case(Sex::Male, KaryotypicSex::Xxx, vec!["MALE", "XXX"])]
+#[serial]
+fn test_sex_karyotype_rule(
+    #[case] sex: Sex,
+    #[case] karyotypic_sex: KaryotypicSex,
+    #[case] message_snippets: Vec<&str>,
+) {
+    let rule_id = "SUBJ005";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &subject(sex, karyotypic_sex), assert_settings);
+}
+
+#[rstest]
+#[case(Sex::Female, KaryotypicSex::Xx)]
+#[case(Sex::Female, KaryotypicSex::Xo)]
+#[case(Sex::Male, KaryotypicSex::Xy)]
+#[case(Sex::Male, KaryotypicSex::Xxy)]
+#[case(Sex::Male, KaryotypicSex::UnknownKaryotype)]
+#[case(Sex::Female, KaryotypicSex::OtherKaryotype)]
+#[case(Sex::UnknownSex, KaryotypicSex::Xy)]
+#[case(Sex::OtherSex, KaryotypicSex::Xx)]
+#[serial]
+fn test_sex_karyotype_rule_compatible(#[case] sex: Sex, #[case] karyotypic_sex: KaryotypicSex) {
+    let rule_id = "SUBJ005";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &subject(sex, karyotypic_sex), assert_settings);
+}
@@ -0,0 +1,82 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::time_element::Element;
+use phenopackets::schema::v2::core::{Age, Disease, OntologyClass, TimeElement};
+use rstest::rstest;
+use serial_test::serial;
+
+fn oc(id: &str, label: &str) -> OntologyClass {
+    OntologyClass {
+        id: id.to_string(),
+        label: label.to_string(),
+    }
+}
+
+fn onset() -> Option<TimeElement> {
+    Some(TimeElement {
+        element: Some(Element::Age(Age {
+            iso8601duration: "P52Y".to_string(),
+        })),
+    })
+}
+
+fn with_disease(disease: Disease) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.diseases = vec![Disease {
+        term: Some(oc("MONDO:0004989", "breast carcinoma")),
+        ..disease
+    }];
+    pp
+}
+
+#[rstest]
+#[case(Disease {
+    disease_stage: vec![oc("NCIT:C27971", "Stage IV")],
+    ..Default::default()
+})]
+#[case(Disease {
+    clinical_tnm_finding: vec![oc("NCIT:C48724", "T2 Stage Finding")],
+    ..Default::default()
+})]
+#[serial]
+fn test_staged_without_onset_rule(#[case] disease: Disease) {
+    let rule_id = "DIS002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["breast carcinoma", "no onset"],
+    };
+
+    run_rule_test(rule_id, &with_disease(disease), assert_settings);
+}
+
+#[rstest]
+#[case(Disease {
+    disease_stage: vec![oc("NCIT:C27971", "Stage IV")],
+    onset: onset(),
+    ..Default::default()
+})]
+#[case(Disease {
+    clinical_tnm_finding: vec![oc("NCIT:C48724", "T2 Stage Finding")],
+    onset: onset(),
+    ..Default::default()
+})]
+#[case(Disease::default())]
+#[serial]
+fn test_staged_without_onset_rule_no_violation(#[case] disease: Disease) {
+    let rule_id = "DIS002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &with_disease(disease), assert_settings);
+}
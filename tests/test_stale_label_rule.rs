@@ -0,0 +1,68 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Replace;
+use phenolint::patches::patch::Patch;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serde_json::json;
+use serial_test::serial;
+
+fn with_feature(id: &str, label: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        ..Default::default()
+    }];
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_stale_label_rule() {
+    let rule_id = "PF018";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&with_feature("HP:0001250", "Seizure")).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Replace {
+            at: Pointer::new("/phenotypicFeatures/0/type"),
+            value: json!({"id": "HP:0001250", "label": "Seizure"}),
+        }))],
+        message_snippets: vec!["Epileptic fits", "HP:0001250"],
+    };
+
+    run_rule_test(
+        rule_id,
+        &with_feature("HP:0001250", "Epileptic fits"),
+        assert_settings,
+    );
+}
+
+#[rstest]
+#[case(with_feature("HP:0001250", "Seizure"))]
+#[case(with_feature("HP:0001250", "SEIZURE "))]
+#[case(with_feature("HP:9999999", "Unknown term"))]
+#[serial]
+fn test_stale_label_rule_no_violation(#[case] pp: Phenopacket) {
+    let rule_id = "PF018";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
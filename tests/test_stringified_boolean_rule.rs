@@ -0,0 +1,112 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Replace;
+use phenolint::patches::patch::Patch;
+use phenolint::traits::Lint;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serde_json::{Value, json};
+use serial_test::serial;
+
+fn with_excluded(excluded: Value) -> String {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: "HP:0001250".to_string(),
+            label: "Seizure".to_string(),
+        }),
+        ..Default::default()
+    }];
+
+    let mut pp = serde_json::to_value(pp).unwrap();
+    pp["phenotypicFeatures"][0]["excluded"] = excluded;
+    serde_json::to_string_pretty(&pp).unwrap()
+}
+
+#[rstest]
+#[serial]
+fn test_stringified_boolean_rule() {
+    let mut linter = build_linter(vec!["STR003"]);
+
+    let res = linter.lint(with_excluded(Value::from("true")).as_str(), true, true);
+
+    assert!(res.error.is_none());
+    let findings = res.report.findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].report().unwrap().message(),
+        "Found a string instead of a boolean for 'excluded'"
+    );
+    assert_eq!(
+        findings[0].patch(),
+        [Patch::new(NonEmptyVec::with_single_entry(Replace {
+            at: Pointer::new("/phenotypicFeatures/0/excluded"),
+            value: Value::Bool(true),
+        }))]
+    );
+
+    let Some(PhenopacketData::Text(patched)) = res.report.patched_phenopacket.as_ref() else {
+        panic!("Expected a patched phenopacket");
+    };
+    let patched: Value = serde_json::from_str(patched).unwrap();
+    assert_eq!(
+        patched["phenotypicFeatures"][0]["excluded"],
+        Value::Bool(true)
+    );
+}
+
+#[rstest]
+#[serial]
+fn test_stringified_boolean_rule_real_boolean() {
+    let mut linter = build_linter(vec!["STR003"]);
+
+    let res = linter.lint(with_excluded(Value::Bool(true)).as_str(), false, true);
+
+    assert!(res.error.is_none());
+    assert!(res.report.findings().is_empty());
+}
+
+#[rstest]
+#[serial]
+fn test_stringified_boolean_features_reach_other_rules() {
+    let mut pp: Value = serde_json::from_str(&with_excluded(Value::from("true"))).unwrap();
+    let features = pp["phenotypicFeatures"].as_array_mut().unwrap();
+    features.push(features[0].clone());
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["PF006"]);
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.error.is_none());
+    assert_eq!(res.report.findings().len(), 1);
+}
+
+#[rstest]
+#[serial]
+fn test_multiple_stringified_booleans_with_other_fix() {
+    let mut pp: Value = serde_json::from_str(&with_excluded(Value::from("true"))).unwrap();
+    pp["phenotypicFeatures"][0]["onset"] = json!({});
+    pp["phenotypicFeatures"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!({"type": {"id": "HP:0001263", "label": "Global developmental delay"}, "excluded": "FALSE"}));
+    let phenostr = serde_json::to_string_pretty(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["STR003", "PF012"]);
+    let res = linter.lint(phenostr.as_str(), true, true);
+
+    assert!(res.error.is_none());
+    assert_eq!(res.report.findings().len(), 3);
+    assert!(res.report.reverted_patches().is_empty());
+
+    let Some(PhenopacketData::Text(patched)) = res.report.patched_phenopacket.as_ref() else {
+        panic!("Expected a patched phenopacket");
+    };
+    let patched: Value = serde_json::from_str(patched).unwrap();
+    assert_eq!(patched["phenotypicFeatures"][0]["excluded"], json!(true));
+    assert_eq!(patched["phenotypicFeatures"][1]["excluded"], json!(false));
+    assert!(patched["phenotypicFeatures"][0].get("onset").is_none());
+}
@@ -0,0 +1,73 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Replace;
+use phenolint::patches::patch::Patch;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serde_json::Value;
+use serial_test::serial;
+
+fn feature(id: &str, label: &str) -> PhenotypicFeature {
+    PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: id.to_string(),
+            label: label.to_string(),
+        }),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_swapped_id_label_rule() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![feature("Seizure", "HP:0001250")];
+
+    let mut patched = pp.clone();
+    patched.phenotypic_features = vec![feature("HP:0001250", "Seizure")];
+
+    let rule_id = "CURIE003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&patched).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_rest(
+            Replace {
+                at: Pointer::new("/phenotypicFeatures/0/type/id"),
+                value: Value::String("HP:0001250".to_string()),
+            },
+            vec![Replace {
+                at: Pointer::new("/phenotypicFeatures/0/type/label"),
+                value: Value::String("Seizure".to_string()),
+            }],
+        ))],
+        message_snippets: vec!["HP:0001250", "swapped"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_swapped_id_label_rule_no_violation() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.phenotypic_features = vec![feature("HP:0001250", "Seizure")];
+
+    let rule_id = "CURIE003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,69 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Biosample, Individual, OntologyClass};
+use rstest::rstest;
+use serial_test::serial;
+
+fn taxonomy(id: &str, label: &str) -> Option<OntologyClass> {
+    Some(OntologyClass {
+        id: id.to_string(),
+        label: label.to_string(),
+    })
+}
+
+fn with_taxonomies(
+    subject_taxonomy: Option<OntologyClass>,
+    biosample_taxonomy: Option<OntologyClass>,
+) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.subject = Some(Individual {
+        id: "patient-1".to_string(),
+        taxonomy: subject_taxonomy,
+        ..Default::default()
+    });
+    pp.biosamples = vec![Biosample {
+        id: "biopsy-1".to_string(),
+        individual_id: "patient-1".to_string(),
+        taxonomy: biosample_taxonomy,
+        ..Default::default()
+    }];
+    pp
+}
+
+#[rstest]
+#[case(
+    taxonomy("NCBITaxon:9606", "Homo sapiens"),
+    taxonomy("NCBITaxon:9606", "Homo sapiens"),
+    0,
+    vec![]
+)]
+#[case(
+    taxonomy("NCBITaxon:9606", "Homo sapiens"),
+    taxonomy("NCBITaxon:10090", "Mus musculus"),
+    1,
+    vec!["NCBITaxon:10090", "NCBITaxon:9606", "Taxonomy of the subject"]
+)]
+#[case(None, taxonomy("NCBITaxon:10090", "Mus musculus"), 0, vec![])]
+#[serial]
+fn test_taxonomy_mismatch_rule(
+    #[case] subject_taxonomy: Option<OntologyClass>,
+    #[case] biosample_taxonomy: Option<OntologyClass>,
+    #[case] n_violations: usize,
+    #[case] message_snippets: Vec<&'static str>,
+) {
+    let pp = with_taxonomies(subject_taxonomy, biosample_taxonomy);
+
+    let rule_id = "BIO002";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets,
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,62 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{OntologyClass, PhenotypicFeature, Resource};
+use rstest::rstest;
+use serial_test::serial;
+
+fn with_hp_resource(feature_id: &str, feature_label: &str) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.meta_data.as_mut().unwrap().resources.push(Resource {
+        id: "hp".to_string(),
+        name: "human phenotype ontology".to_string(),
+        url: "http://purl.obolibrary.org/obo/hp.owl".to_string(),
+        version: "2024-04-26".to_string(),
+        namespace_prefix: "HP".to_string(),
+        iri_prefix: "http://purl.obolibrary.org/obo/HP_".to_string(),
+    });
+    pp.phenotypic_features = vec![PhenotypicFeature {
+        r#type: Some(OntologyClass {
+            id: feature_id.to_string(),
+            label: feature_label.to_string(),
+        }),
+        ..Default::default()
+    }];
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_undeclared_type_namespace_rule() {
+    let pp = with_hp_resource("MP:0001262", "decreased body weight");
+
+    let rule_id = "PF014";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["MP:0001262", "No resource declares 'MP'"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_undeclared_type_namespace_rule_declared() {
+    let pp = with_hp_resource("HP:0001250", "Seizure");
+
+    let rule_id = "PF014";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,94 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenolint::diagnostics::enums::PhenopacketData;
+use phenolint::helper::NonEmptyVec;
+use phenolint::patches::enums::PatchInstruction::Remove;
+use phenolint::patches::patch::Patch;
+use phenolint::tree::pointer::Pointer;
+use phenopackets::schema::v2::Phenopacket;
+use phenopackets::schema::v2::core::{Disease, OntologyClass, PhenotypicFeature, Resource};
+use rstest::rstest;
+use serial_test::serial;
+
+fn resource(namespace_prefix: &str) -> Resource {
+    Resource {
+        id: namespace_prefix.to_lowercase(),
+        name: format!("{namespace_prefix} ontology"),
+        url: format!(
+            "http://purl.obolibrary.org/obo/{}.owl",
+            namespace_prefix.to_lowercase()
+        ),
+        version: "2024-04-26".to_string(),
+        namespace_prefix: namespace_prefix.to_string(),
+        iri_prefix: format!("http://purl.obolibrary.org/obo/{namespace_prefix}_"),
+    }
+}
+
+fn oc(id: &str, label: &str) -> Option<OntologyClass> {
+    Some(OntologyClass {
+        id: id.to_string(),
+        label: label.to_string(),
+    })
+}
+
+fn phenopacket(resources: Vec<Resource>, with_feature: bool, with_disease: bool) -> Phenopacket {
+    let mut pp = minimal_valid_phenopacket();
+    pp.meta_data.as_mut().unwrap().resources = resources;
+    if with_feature {
+        pp.phenotypic_features.push(PhenotypicFeature {
+            r#type: oc("HP:0001250", "Seizure"),
+            ..Default::default()
+        });
+    }
+    if with_disease {
+        pp.diseases.push(Disease {
+            term: oc("MONDO:0005027", "epilepsy"),
+            ..Default::default()
+        });
+    }
+    pp
+}
+
+#[rstest]
+#[case(phenopacket(vec![resource("HP"), resource("MONDO")], true, true))]
+#[case(phenopacket(vec![resource("MONDO")], false, true))]
+#[serial]
+fn test_unused_resources_rule_all_used(#[case] pp: Phenopacket) {
+    let rule_id = "INTER003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_unused_resources_rule_unused_resource() {
+    let pp = phenopacket(vec![resource("HP"), resource("MONDO")], true, false);
+    let patched = phenopacket(vec![resource("HP")], true, false);
+
+    let rule_id = "INTER003";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: Some(PhenopacketData::Text(
+            serde_json::to_string_pretty(&patched).unwrap(),
+        )),
+        patches: vec![Patch::new(NonEmptyVec::with_single_entry(Remove {
+            at: Pointer::new("/metaData/resources/1"),
+        }))],
+        message_snippets: vec![
+            "Resource 'MONDO' is not used by any CURIE",
+            "Unused resource",
+        ],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
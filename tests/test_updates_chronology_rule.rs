@@ -0,0 +1,69 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::minimal_valid_phenopacket;
+use crate::common::test_functions::run_rule_test;
+use phenopackets::schema::v2::core::Update;
+use rstest::rstest;
+use serial_test::serial;
+
+fn update(seconds: i64) -> Update {
+    Update {
+        timestamp: Some(prost_types::Timestamp { seconds, nanos: 0 }),
+        updated_by: "Test-Suite".to_string(),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[serial]
+fn test_updates_chronology_rule_out_of_order() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.meta_data.as_mut().unwrap().updates = vec![update(1000), update(500), update(2000)];
+
+    let rule_id = "META001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["previous update"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_updates_chronology_rule_before_creation() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.meta_data.as_mut().unwrap().updates = vec![update(10)];
+
+    let rule_id = "META001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["creation"],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_updates_chronology_rule_valid() {
+    let mut pp = minimal_valid_phenopacket();
+    pp.meta_data.as_mut().unwrap().updates = vec![update(100), update(100), update(2000)];
+
+    let rule_id = "META001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_rule_test(rule_id, &pp, assert_settings);
+}
@@ -0,0 +1,104 @@
+mod common;
+use crate::common::asserts::LintResultAssertSettings;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use crate::common::test_functions::run_raw_rule_test;
+use phenolint::traits::Lint;
+use rstest::rstest;
+use serde_json::{Value, json};
+use serial_test::serial;
+
+/// The VRS structs of the phenopackets crate require an `id`, which the schema forbids,
+/// so the allele is added to the plain JSON of the phenopacket.
+fn phenopacket_with_allele(start: u64, end: u64) -> Value {
+    let mut pp = serde_json::to_value(minimal_valid_phenopacket()).unwrap();
+
+    pp["interpretations"] = json!([{
+        "id": "interpretation_1",
+        "progressStatus": "SOLVED",
+        "diagnosis": {
+            "disease": {"id": "MONDO:0000252", "label": "inflammatory diarrhea"},
+            "genomicInterpretations": [{
+                "subjectOrBiosampleId": "cohort-1-patient-1",
+                "interpretationStatus": "CAUSATIVE",
+                "variantInterpretation": {
+                    "acmgPathogenicityClassification": "PATHOGENIC",
+                    "therapeuticActionability": "UNKNOWN_ACTIONABILITY",
+                    "variationDescriptor": {
+                        "id": "variant_1",
+                        "moleculeContext": "genomic",
+                        "variation": {
+                            "allele": {
+                                "_id": "allele_1",
+                                "sequenceLocation": {
+                                    "_id": "location_1",
+                                    "sequenceId": "refseq:NC_000010.11",
+                                    "sequenceInterval": {
+                                        "startNumber": {"value": start.to_string()},
+                                        "endNumber": {"value": end.to_string()}
+                                    }
+                                },
+                                "literalSequenceExpression": {"sequence": "T"}
+                            }
+                        }
+                    }
+                }
+            }]
+        }
+    }]);
+
+    pp
+}
+
+#[rstest]
+#[serial]
+fn test_vrs_allele_interval_rule_inverted_interval() {
+    let pp = phenopacket_with_allele(87894109, 87894077);
+
+    let rule_id = "VRS001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 1,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec!["VRS allele", "87894109"],
+    };
+
+    run_raw_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_vrs_allele_interval_rule_valid_allele() {
+    let pp = phenopacket_with_allele(87894076, 87894077);
+
+    let rule_id = "VRS001";
+    let assert_settings = LintResultAssertSettings {
+        rule_id,
+        n_violations: 0,
+        patched_phenopacket: None,
+        patches: vec![],
+        message_snippets: vec![],
+    };
+
+    run_raw_rule_test(rule_id, &pp, assert_settings);
+}
+
+#[rstest]
+#[serial]
+fn test_vrs_allele_interval_rule_points_at_allele() {
+    let phenostr =
+        serde_json::to_string_pretty(&phenopacket_with_allele(87894109, 87894077)).unwrap();
+    let mut linter = build_linter(vec!["VRS001"]);
+
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    let findings = res.report.findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].violation().first_at().position(),
+        "/interpretations/0/diagnosis/genomicInterpretations/0/variantInterpretation/variationDescriptor/variation/allele"
+    );
+    let span = findings[0].report().unwrap().labels()[0].range().clone();
+    assert!(phenostr[span.clone()].contains("\"sequenceLocation\""));
+    assert!(!phenostr[span].contains("\"moleculeContext\""));
+}
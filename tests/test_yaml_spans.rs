@@ -0,0 +1,58 @@
+mod common;
+use crate::common::construction::{build_linter, minimal_valid_phenopacket};
+use phenolint::report::renderer::ReportRenderer;
+use phenolint::traits::Lint;
+use phenopackets::schema::v2::core::{Diagnosis, Interpretation, OntologyClass, PhenotypicFeature};
+use rstest::rstest;
+use serial_test::serial;
+
+fn line_of(phenostr: &str, byte_idx: usize) -> usize {
+    phenostr[..byte_idx].matches('\n').count() + 1
+}
+
+#[rstest]
+#[serial]
+fn test_yaml_report_highlights_right_line() {
+    let mut pp = minimal_valid_phenopacket();
+    // Multibyte chars before the finding shift char indices away from byte offsets.
+    pp.phenotypic_features.push(PhenotypicFeature {
+        description: "Anfälle überwiegend nächtlich, häufig während Ruhephasen, äußerst ängstlich"
+            .to_string(),
+        r#type: Some(OntologyClass {
+            id: "HP:0001250".to_string(),
+            label: "Seizure".to_string(),
+        }),
+        ..Default::default()
+    });
+    pp.interpretations.push(Interpretation {
+        id: "interpretation-1".to_string(),
+        diagnosis: Some(Diagnosis {
+            disease: Some(OntologyClass {
+                id: "MONDO:0000252".to_string(),
+                label: "inflammatory diarrhea".to_string(),
+            }),
+            genomic_interpretations: vec![],
+        }),
+        ..Default::default()
+    });
+    let phenostr = serde_yaml::to_string(&pp).unwrap();
+
+    let mut linter = build_linter(vec!["INTER001"]);
+    let res = linter.lint(phenostr.as_str(), false, true);
+
+    assert!(res.error.is_none());
+    let findings = res.report.findings();
+    assert_eq!(findings.len(), 1);
+
+    let report = findings[0].report().unwrap();
+    let range = report.labels()[0].range();
+    assert!(phenostr.is_char_boundary(range.start));
+    assert!(phenostr.is_char_boundary(range.end));
+
+    let expected_line = line_of(&phenostr, phenostr.find("id: MONDO:0000252").unwrap());
+    assert_eq!(line_of(&phenostr, range.start), expected_line);
+
+    let rendered =
+        ReportRenderer::render_into_string(report, &phenostr, "cohort-1-patient-1").unwrap();
+    assert!(rendered.contains(&format!("cohort-1-patient-1:{expected_line}:")));
+}